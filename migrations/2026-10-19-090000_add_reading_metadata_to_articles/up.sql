@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TABLE articles
+  ADD COLUMN word_count INTEGER NOT NULL DEFAULT 0,
+  ADD COLUMN reading_minutes INTEGER NOT NULL DEFAULT 0,
+  ADD COLUMN table_of_contents JSONB NOT NULL DEFAULT '[]';
+
+CREATE INDEX articles_reading_minutes_idx ON articles (reading_minutes);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE article_daily_views;
+DROP TABLE article_views;
@@ -0,0 +1,18 @@
+-- Your SQL goes here
+CREATE TABLE article_views (
+  id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+  article_id UUID NOT NULL REFERENCES articles (id) ON DELETE CASCADE,
+  viewer_id UUID REFERENCES users (id) ON DELETE SET NULL,
+  reader_key TEXT NOT NULL,
+  viewed_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE INDEX article_views_article_id_reader_key_idx ON article_views (article_id, reader_key, viewed_at);
+
+CREATE TABLE article_daily_views (
+  article_id UUID NOT NULL REFERENCES articles (id) ON DELETE CASCADE,
+  day DATE NOT NULL,
+  views INTEGER NOT NULL DEFAULT 0,
+  unique_readers INTEGER NOT NULL DEFAULT 0,
+  PRIMARY KEY (article_id, day)
+);
@@ -0,0 +1,22 @@
+-- Your SQL goes here
+CREATE TABLE series (
+  id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+  author_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+  slug TEXT UNIQUE NOT NULL,
+  title TEXT NOT NULL,
+  description TEXT NOT NULL,
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+  updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE INDEX series_author_id_idx ON series (author_id);
+
+SELECT diesel_manage_updated_at('series');
+
+CREATE TABLE series_articles (
+  series_id UUID NOT NULL REFERENCES series (id) ON DELETE CASCADE,
+  article_id UUID NOT NULL UNIQUE REFERENCES articles (id) ON DELETE CASCADE,
+  position INTEGER NOT NULL,
+  PRIMARY KEY (series_id, article_id),
+  UNIQUE (series_id, position)
+);
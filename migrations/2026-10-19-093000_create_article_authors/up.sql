@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE article_authors (
+  article_id UUID NOT NULL REFERENCES articles (id) ON DELETE CASCADE,
+  user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+  role TEXT NOT NULL CHECK (role IN ('owner', 'editor')),
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+  updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+  PRIMARY KEY (article_id, user_id)
+);
+
+CREATE INDEX article_authors_user_id_idx ON article_authors (user_id);
+
+INSERT INTO article_authors (article_id, user_id, role)
+  SELECT id, author_id, 'owner' FROM articles;
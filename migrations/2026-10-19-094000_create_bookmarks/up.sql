@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE bookmarks (
+  id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+  user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+  article_id UUID NOT NULL REFERENCES articles (id) ON DELETE CASCADE,
+  folder TEXT,
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+  updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+  UNIQUE (user_id, article_id)
+);
+
+CREATE INDEX bookmarks_user_id_folder_idx ON bookmarks (user_id, folder);
+
+SELECT diesel_manage_updated_at('bookmarks');
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX comments_deleted_at_idx;
+DROP INDEX articles_deleted_at_idx;
+
+ALTER TABLE comments DROP COLUMN deleted_at;
+ALTER TABLE articles DROP COLUMN deleted_at;
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE articles
+  DROP COLUMN meta_title,
+  DROP COLUMN meta_description,
+  DROP COLUMN canonical_url,
+  DROP COLUMN cover_image;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE articles
+  ADD COLUMN meta_title TEXT,
+  ADD COLUMN meta_description TEXT,
+  ADD COLUMN canonical_url TEXT,
+  ADD COLUMN cover_image TEXT;
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX comments_article_id_created_at_id_idx;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX comments_article_id_created_at_id_idx ON comments (article_id, created_at, id);
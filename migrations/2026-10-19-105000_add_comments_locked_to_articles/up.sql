@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE articles ADD COLUMN comments_locked BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX comments_parent_id_idx;
+
+ALTER TABLE comments
+  DROP COLUMN parent_id,
+  DROP COLUMN depth;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE comments
+  ADD COLUMN parent_id UUID REFERENCES comments (id) ON DELETE CASCADE,
+  ADD COLUMN depth INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX comments_parent_id_idx ON comments (parent_id);
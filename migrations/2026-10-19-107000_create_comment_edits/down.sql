@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE comment_edits;
+
+ALTER TABLE comments DROP COLUMN edited_at;
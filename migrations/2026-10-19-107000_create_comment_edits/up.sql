@@ -0,0 +1,11 @@
+-- Your SQL goes here
+ALTER TABLE comments ADD COLUMN edited_at TIMESTAMP;
+
+CREATE TABLE comment_edits (
+  id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+  comment_id UUID NOT NULL REFERENCES comments (id) ON DELETE CASCADE,
+  body TEXT NOT NULL,
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE INDEX comment_edits_comment_id_idx ON comment_edits (comment_id);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE moderation_actions;
+DROP TABLE reports;
+ALTER TABLE users DROP COLUMN is_moderator;
@@ -0,0 +1,36 @@
+-- Your SQL goes here
+ALTER TABLE users ADD COLUMN is_moderator BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE reports (
+  id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+  reporter_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+  target_type TEXT NOT NULL CHECK (target_type IN ('article', 'comment', 'user')),
+  target_id UUID NOT NULL,
+  reason TEXT NOT NULL,
+  details TEXT,
+  status TEXT NOT NULL DEFAULT 'open' CHECK (status IN ('open', 'claimed', 'resolved', 'dismissed')),
+  moderator_id UUID REFERENCES users (id) ON DELETE SET NULL,
+  resolution TEXT,
+  resolved_at TIMESTAMP,
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+  updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+SELECT diesel_manage_updated_at('reports');
+
+CREATE INDEX reports_status_created_at_idx ON reports (status, created_at);
+CREATE INDEX reports_target_idx ON reports (target_type, target_id);
+-- A reader has at most one pending report on the same content.
+CREATE UNIQUE INDEX reports_pending_reporter_target_idx ON reports (reporter_id, target_type, target_id)
+  WHERE status IN ('open', 'claimed');
+
+CREATE TABLE moderation_actions (
+  id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+  report_id UUID NOT NULL REFERENCES reports (id) ON DELETE CASCADE,
+  moderator_id UUID REFERENCES users (id) ON DELETE SET NULL,
+  action TEXT NOT NULL,
+  note TEXT,
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE INDEX moderation_actions_report_id_idx ON moderation_actions (report_id);
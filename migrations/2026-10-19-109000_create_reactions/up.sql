@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TABLE article_reactions (
+  article_id UUID NOT NULL REFERENCES articles (id) ON DELETE CASCADE,
+  user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+  emoji TEXT NOT NULL,
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+  PRIMARY KEY (article_id, user_id, emoji)
+);
+
+CREATE INDEX article_reactions_user_id_idx ON article_reactions (user_id);
+
+CREATE TABLE comment_reactions (
+  comment_id UUID NOT NULL REFERENCES comments (id) ON DELETE CASCADE,
+  user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+  emoji TEXT NOT NULL,
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+  PRIMARY KEY (comment_id, user_id, emoji)
+);
+
+CREATE INDEX comment_reactions_user_id_idx ON comment_reactions (user_id);
@@ -0,0 +1,26 @@
+-- Your SQL goes here
+CREATE TABLE mentions (
+  id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+  user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+  article_id UUID NOT NULL REFERENCES articles (id) ON DELETE CASCADE,
+  comment_id UUID REFERENCES comments (id) ON DELETE CASCADE,
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+-- A user is mentioned at most once by the body of an article or of a comment.
+CREATE UNIQUE INDEX mentions_article_user_idx ON mentions (article_id, user_id) WHERE comment_id IS NULL;
+CREATE UNIQUE INDEX mentions_comment_user_idx ON mentions (comment_id, user_id) WHERE comment_id IS NOT NULL;
+CREATE INDEX mentions_user_id_idx ON mentions (user_id);
+
+CREATE TABLE notifications (
+  id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+  user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+  actor_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+  kind TEXT NOT NULL,
+  article_id UUID REFERENCES articles (id) ON DELETE CASCADE,
+  comment_id UUID REFERENCES comments (id) ON DELETE CASCADE,
+  read_at TIMESTAMP,
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE INDEX notifications_user_id_created_at_idx ON notifications (user_id, created_at);
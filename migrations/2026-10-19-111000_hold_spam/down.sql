@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM reports WHERE reporter_id IS NULL;
+ALTER TABLE reports ALTER COLUMN reporter_id SET NOT NULL;
+
+ALTER TABLE comments DROP COLUMN held_at;
+ALTER TABLE users DROP COLUMN held_at;
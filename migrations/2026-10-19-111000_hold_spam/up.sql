@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE users ADD COLUMN held_at TIMESTAMP;
+ALTER TABLE comments ADD COLUMN held_at TIMESTAMP;
+
+-- Reports filed by the spam filter have no reporter.
+ALTER TABLE reports ALTER COLUMN reporter_id DROP NOT NULL;
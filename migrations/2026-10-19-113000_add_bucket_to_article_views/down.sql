@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX article_views_article_id_reader_key_bucket_idx;
+
+ALTER TABLE article_views DROP COLUMN bucket;
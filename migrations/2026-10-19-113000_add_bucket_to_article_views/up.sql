@@ -0,0 +1,16 @@
+-- Your SQL goes here
+-- Views are deduplicated per reader within fixed 30 minute buckets.
+ALTER TABLE article_views ADD COLUMN bucket BIGINT;
+
+UPDATE article_views SET bucket = FLOOR(EXTRACT(EPOCH FROM viewed_at) / 1800);
+
+DELETE FROM article_views a
+  USING article_views b
+  WHERE a.article_id = b.article_id
+    AND a.reader_key = b.reader_key
+    AND a.bucket = b.bucket
+    AND (a.viewed_at, a.id) > (b.viewed_at, b.id);
+
+ALTER TABLE article_views ALTER COLUMN bucket SET NOT NULL;
+
+CREATE UNIQUE INDEX article_views_article_id_reader_key_bucket_idx ON article_views (article_id, reader_key, bucket);
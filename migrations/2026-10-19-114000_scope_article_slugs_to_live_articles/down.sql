@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX articles_slug_live_idx;
+
+ALTER TABLE articles ADD CONSTRAINT articles_slug_key UNIQUE (slug);
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+-- Trashed articles give their slug back, so an article with the same title can be written again.
+ALTER TABLE articles DROP CONSTRAINT articles_slug_key;
+
+CREATE UNIQUE INDEX articles_slug_live_idx ON articles (slug) WHERE deleted_at IS NULL;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM mentions WHERE removed_at IS NOT NULL;
+
+ALTER TABLE mentions DROP COLUMN removed_at;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+-- Mentions edited out are kept, so mentioning the same user again doesn't notify them twice.
+ALTER TABLE mentions ADD COLUMN removed_at TIMESTAMP;
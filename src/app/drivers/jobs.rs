@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+use actix_web::{rt, web};
+
+use crate::constants::TRASH_PURGE_INTERVAL_SECS;
+
+use super::middlewares::state::AppState;
+
+// Periodically purges trashed articles and comments whose retention period has passed.
+pub fn spawn_trash_purge(state: AppState) {
+    rt::spawn(async move {
+        let mut interval = rt::time::interval(Duration::from_secs(TRASH_PURGE_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            let trash_usecase = state.di_container.trash_usecase.clone();
+            match web::block(move || trash_usecase.purge_expired()).await {
+                Ok(Ok(purged)) => log::info!(
+                    "purged {} articles and {} comments from trash",
+                    purged.articles,
+                    purged.comments
+                ),
+                Ok(Err(err)) => log::warn!("failed to purge trash: {}", err),
+                Err(err) => log::warn!("failed to run trash purge: {}", err),
+            }
+        }
+    });
+}
@@ -1,5 +1,8 @@
 use crate::app;
-use actix_web::web::{self, delete, get, post, put, ServiceConfig};
+use actix_web::{
+    web::{self, delete, get, post, put, ServiceConfig},
+    Scope,
+};
 
 pub fn api(cfg: &mut ServiceConfig) {
     cfg.service(
@@ -8,6 +11,10 @@ pub fn api(cfg: &mut ServiceConfig) {
                 web::scope("/healthcheck")
                     .route("", get().to(app::features::healthcheck::controllers::index)),
             )
+            .service(
+                web::scope("/reactions")
+                    .route("", get().to(app::features::reaction::controllers::index)),
+            )
             .service(
                 web::scope("/tags").route("", get().to(app::features::tag::controllers::index)),
             )
@@ -34,21 +41,198 @@ pub fn api(cfg: &mut ServiceConfig) {
                         "/{username}/follow",
                         post().to(app::features::profile::controllers::follow),
                     )
+                    .route(
+                        "/{username}/block",
+                        post().to(app::features::profile::controllers::block),
+                    )
+                    .route(
+                        "/{username}/block",
+                        delete().to(app::features::profile::controllers::unblock),
+                    )
+                    .route(
+                        "/{username}/mute",
+                        post().to(app::features::profile::controllers::mute),
+                    )
+                    .route(
+                        "/{username}/mute",
+                        delete().to(app::features::profile::controllers::unmute),
+                    )
+                    .route(
+                        "/{username}/followers",
+                        get().to(app::features::profile::controllers::followers),
+                    )
+                    .route(
+                        "/{username}/following",
+                        get().to(app::features::profile::controllers::following),
+                    )
+                    .route(
+                        "/{username}/export",
+                        get().to(app::features::export::controllers::profile),
+                    )
+                    .route(
+                        "/{username}/report",
+                        post().to(app::features::report::controllers::report_user),
+                    )
                     .route(
                         "/{username/follow}",
                         delete().to(app::features::profile::controllers::unfollow),
                     ),
             )
+            .service(
+                web::scope("/uploads")
+                    .route("", post().to(app::features::upload::controllers::create))
+                    .route(
+                        "/{upload_id}",
+                        get().to(app::features::upload::controllers::show),
+                    )
+                    .route(
+                        "/{upload_id}/{variant}",
+                        get().to(app::features::upload::controllers::variant),
+                    ),
+            )
+            .service(
+                web::scope("/bookmarks")
+                    .route("", get().to(app::features::bookmark::controllers::index))
+                    .route(
+                        "/folders",
+                        get().to(app::features::bookmark::controllers::folders),
+                    ),
+            )
+            .service(
+                web::scope("/moderation/reports")
+                    .route("", get().to(app::features::report::controllers::index))
+                    .route(
+                        "/{report_id}",
+                        get().to(app::features::report::controllers::show),
+                    )
+                    .route(
+                        "/{report_id}/claim",
+                        post().to(app::features::report::controllers::claim),
+                    )
+                    .route(
+                        "/{report_id}/resolve",
+                        post().to(app::features::report::controllers::resolve),
+                    )
+                    .route(
+                        "/{report_id}/dismiss",
+                        post().to(app::features::report::controllers::dismiss),
+                    )
+                    .route(
+                        "/{report_id}/remove",
+                        post().to(app::features::report::controllers::remove),
+                    ),
+            )
+            .service(
+                web::scope("/notifications")
+                    .route(
+                        "",
+                        get().to(app::features::notification::controllers::index),
+                    )
+                    .route(
+                        "/read",
+                        post().to(app::features::notification::controllers::read_all),
+                    )
+                    .route(
+                        "/{notification_id}/read",
+                        post().to(app::features::notification::controllers::read),
+                    ),
+            )
+            .service(
+                web::scope("/trash").route("", get().to(app::features::trash::controllers::index)),
+            )
+            .service(
+                web::scope("/series")
+                    .route("", post().to(app::features::series::controllers::create))
+                    .route(
+                        "/{series_slug}",
+                        get().to(app::features::series::controllers::show),
+                    )
+                    .route(
+                        "/{series_slug}",
+                        put().to(app::features::series::controllers::update),
+                    )
+                    .route(
+                        "/{series_slug}",
+                        delete().to(app::features::series::controllers::delete),
+                    ),
+            )
             .service(
                 web::scope("/articles")
                     .route("/feed", get().to(app::features::article::controllers::feed))
                     .route("", get().to(app::features::article::controllers::index))
                     .route("", post().to(app::features::article::controllers::create))
+                    .route(
+                        "/import",
+                        post().to(app::features::import::controllers::import),
+                    )
                     .service(
                         web::scope("/{article_title_slug}")
                             .route("", get().to(app::features::article::controllers::show))
                             .route("", put().to(app::features::article::controllers::update))
                             .route("", delete().to(app::features::article::controllers::delete))
+                            .route(
+                                "/restore",
+                                post().to(app::features::trash::controllers::restore_article),
+                            )
+                            .route(
+                                "/related",
+                                get().to(app::features::article::controllers::related),
+                            )
+                            .route(
+                                "/stats",
+                                get().to(app::features::analytics::controllers::stats),
+                            )
+                            .service(
+                                web::scope("/translations")
+                                    .route(
+                                        "",
+                                        get().to(app::features::translation::controllers::index),
+                                    )
+                                    .route(
+                                        "/{locale}",
+                                        put().to(app::features::translation::controllers::save),
+                                    )
+                                    .route(
+                                        "/{locale}",
+                                        delete()
+                                            .to(app::features::translation::controllers::delete),
+                                    ),
+                            )
+                            .route(
+                                "/meta",
+                                get().to(app::features::seo::controllers::article_meta),
+                            )
+                            .route(
+                                "/export",
+                                get().to(app::features::export::controllers::article),
+                            )
+                            .route(
+                                "/report",
+                                post().to(app::features::report::controllers::report_article),
+                            )
+                            .route(
+                                "/reactions/{emoji}",
+                                put().to(app::features::reaction::controllers::react_article),
+                            )
+                            .route(
+                                "/reactions/{emoji}",
+                                delete().to(app::features::reaction::controllers::unreact_article),
+                            )
+                            .service(
+                                web::scope("/authors")
+                                    .route(
+                                        "",
+                                        get().to(app::features::coauthor::controllers::index),
+                                    )
+                                    .route(
+                                        "",
+                                        post().to(app::features::coauthor::controllers::create),
+                                    )
+                                    .route(
+                                        "/{username}",
+                                        delete().to(app::features::coauthor::controllers::delete),
+                                    ),
+                            )
                             .service(
                                 web::scope("/favorite")
                                     .route(
@@ -62,18 +246,61 @@ pub fn api(cfg: &mut ServiceConfig) {
                                     ),
                             )
                             .service(
-                                web::scope("comment")
+                                web::scope("/bookmark")
                                     .route(
                                         "",
-                                        post().to(app::features::comment::controllers::create),
+                                        post().to(app::features::bookmark::controllers::bookmark),
                                     )
-                                    .route("", get().to(app::features::comment::controllers::index))
                                     .route(
-                                        "/{comment_id}",
-                                        delete().to(app::features::comment::controllers::delete),
+                                        "",
+                                        delete()
+                                            .to(app::features::bookmark::controllers::unbookmark),
                                     ),
-                            ),
+                            )
+                            .service(comments("comment"))
+                            .service(comments("comments")),
                     ),
             ),
     );
 }
+
+// The comments of an article. The scope predates the plural paths of the other
+// resources, so it is served under both `comment` and `comments`.
+fn comments(path: &str) -> Scope {
+    web::scope(path)
+        .route("", post().to(app::features::comment::controllers::create))
+        .route("", get().to(app::features::comment::controllers::index))
+        .route("/lock", put().to(app::features::comment::controllers::lock))
+        .route(
+            "/lock",
+            delete().to(app::features::comment::controllers::unlock),
+        )
+        .route(
+            "/{comment_id}",
+            put().to(app::features::comment::controllers::update),
+        )
+        .route(
+            "/{comment_id}",
+            delete().to(app::features::comment::controllers::delete),
+        )
+        .route(
+            "/{comment_id}/history",
+            get().to(app::features::comment::controllers::history),
+        )
+        .route(
+            "/{comment_id}/reactions/{emoji}",
+            put().to(app::features::reaction::controllers::react_comment),
+        )
+        .route(
+            "/{comment_id}/reactions/{emoji}",
+            delete().to(app::features::reaction::controllers::unreact_comment),
+        )
+        .route(
+            "/{comment_id}/report",
+            post().to(app::features::report::controllers::report_comment),
+        )
+        .route(
+            "/{comment_id}/restore",
+            post().to(app::features::trash::controllers::restore_comment),
+        )
+}
@@ -0,0 +1,29 @@
+use actix_web::{web, HttpRequest};
+use serde::Deserialize;
+
+use crate::{
+    app::drivers::middlewares::{auth, state::AppState},
+    utils::api::ApiResponse,
+};
+
+type ArticleTitleSlug = String;
+
+#[derive(Deserialize)]
+pub struct StatsQueryParameter {
+    days: Option<i64>,
+}
+
+pub async fn stats(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ArticleTitleSlug>,
+    params: web::Query<StatsQueryParameter>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let article_title_slug = path.into_inner();
+    let days = params.days.unwrap_or(30).clamp(1, 365);
+    state
+        .di_container
+        .analytics_usecase
+        .fetch_article_stats(current_user, article_title_slug, days)
+}
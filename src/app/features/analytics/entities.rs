@@ -0,0 +1,202 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use diesel::dsl::Eq;
+use diesel::prelude::*;
+use diesel::upsert::excluded;
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use uuid::Uuid;
+
+use crate::app::features::article::entities::Article;
+use crate::error::AppError;
+use crate::schema::{article_daily_views, article_views};
+
+type WithArticleId<T> = Eq<article_views::article_id, T>;
+type WithReaderKey<T> = Eq<article_views::reader_key, T>;
+
+#[derive(Identifiable, Queryable, Associations, Serialize, Deserialize, Debug, Clone)]
+#[diesel(belongs_to(Article, foreign_key = article_id))]
+#[diesel(table_name = article_views)]
+pub struct ArticleView {
+    pub id: Uuid,
+    pub article_id: Uuid,
+    pub viewer_id: Option<Uuid>,
+    pub reader_key: String,
+    pub viewed_at: NaiveDateTime,
+    pub bucket: i64,
+}
+
+impl ArticleView {
+    fn with_article_id(article_id: &Uuid) -> WithArticleId<&Uuid> {
+        article_views::article_id.eq(article_id)
+    }
+
+    fn with_reader_key(reader_key: &str) -> WithReaderKey<&str> {
+        article_views::reader_key.eq(reader_key)
+    }
+}
+
+impl ArticleView {
+    // Whether the view was recorded, a reader counts once per bucket.
+    pub fn create_once(
+        conn: &mut PgConnection,
+        record: &CreateArticleView,
+    ) -> Result<bool, AppError> {
+        let inserted = diesel::insert_into(article_views::table)
+            .values(record)
+            .on_conflict((
+                article_views::article_id,
+                article_views::reader_key,
+                article_views::bucket,
+            ))
+            .do_nothing()
+            .execute(conn)?;
+        Ok(inserted > 0)
+    }
+
+    pub fn exists_since(
+        conn: &mut PgConnection,
+        article_id: &Uuid,
+        reader_key: &str,
+        since: NaiveDateTime,
+    ) -> Result<bool, AppError> {
+        let t = article_views::table
+            .filter(Self::with_article_id(article_id))
+            .filter(Self::with_reader_key(reader_key))
+            .filter(article_views::viewed_at.ge(since));
+        let exists = diesel::select(diesel::dsl::exists(t)).get_result::<bool>(conn)?;
+        Ok(exists)
+    }
+
+    pub fn count_unique_readers(
+        conn: &mut PgConnection,
+        article_id: &Uuid,
+    ) -> Result<i64, AppError> {
+        let t = article_views::table
+            .filter(Self::with_article_id(article_id))
+            .select(diesel::dsl::count_distinct(article_views::reader_key));
+        let count = t.first::<i64>(conn)?;
+        Ok(count)
+    }
+}
+
+#[derive(Identifiable, Queryable, Associations, Serialize, Deserialize, Debug, Clone)]
+#[diesel(belongs_to(Article, foreign_key = article_id))]
+#[diesel(primary_key(article_id, day))]
+#[diesel(table_name = article_daily_views)]
+pub struct ArticleDailyViews {
+    pub article_id: Uuid,
+    pub day: NaiveDate,
+    pub views: i32,
+    pub unique_readers: i32,
+}
+
+impl ArticleDailyViews {
+    pub fn increment(
+        conn: &mut PgConnection,
+        article_id: &Uuid,
+        day: NaiveDate,
+        is_new_reader: bool,
+    ) -> Result<(), AppError> {
+        let unique_readers = if is_new_reader { 1 } else { 0 };
+        diesel::insert_into(article_daily_views::table)
+            .values((
+                article_daily_views::article_id.eq(article_id),
+                article_daily_views::day.eq(day),
+                article_daily_views::views.eq(1),
+                article_daily_views::unique_readers.eq(unique_readers),
+            ))
+            .on_conflict((article_daily_views::article_id, article_daily_views::day))
+            .do_update()
+            .set((
+                article_daily_views::views
+                    .eq(article_daily_views::views + excluded(article_daily_views::views)),
+                article_daily_views::unique_readers.eq(article_daily_views::unique_readers
+                    + excluded(article_daily_views::unique_readers)),
+            ))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub fn fetch_since(
+        conn: &mut PgConnection,
+        article_id: &Uuid,
+        since: NaiveDate,
+    ) -> Result<Vec<Self>, AppError> {
+        let t = article_daily_views::table
+            .filter(article_daily_views::article_id.eq(article_id))
+            .filter(article_daily_views::day.ge(since))
+            .order(article_daily_views::day.asc());
+        let list = t.load::<Self>(conn)?;
+        Ok(list)
+    }
+
+    pub fn sum_views(conn: &mut PgConnection, article_id: &Uuid) -> Result<i64, AppError> {
+        let t = article_daily_views::table
+            .filter(article_daily_views::article_id.eq(article_id))
+            .select(diesel::dsl::sum(article_daily_views::views));
+        let total = t.first::<Option<i64>>(conn)?;
+        Ok(total.unwrap_or(0))
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = article_views)]
+pub struct CreateArticleView {
+    pub article_id: Uuid,
+    pub viewer_id: Option<Uuid>,
+    pub reader_key: String,
+    pub bucket: i64,
+}
+
+// A reader is identified by account when signed in, otherwise by client IP.
+#[derive(Clone, Debug)]
+pub struct Reader {
+    pub viewer_id: Option<Uuid>,
+    pub peer_ip: Option<IpAddr>,
+    // X-Forwarded-For entries, from the client to the last proxy.
+    pub forwarded_for: Vec<String>,
+}
+
+impl Reader {
+    // X-Forwarded-For is only believed when the connection comes from a
+    // trusted proxy, and then only up to the first hop that isn't trusted.
+    pub fn ip(&self, trusted_proxies: &[IpAddr]) -> Option<String> {
+        let peer_ip = self.peer_ip?;
+        if !trusted_proxies.contains(&peer_ip) {
+            return Some(peer_ip.to_string());
+        }
+        let mut ip = peer_ip.to_string();
+        for hop in self.forwarded_for.iter().rev() {
+            ip = hop.clone();
+            match hop.parse::<IpAddr>() {
+                Ok(hop_ip) if trusted_proxies.contains(&hop_ip) => continue,
+                _ => break,
+            }
+        }
+        Some(ip)
+    }
+
+    pub fn key(&self, trusted_proxies: &[IpAddr]) -> Option<String> {
+        match (&self.viewer_id, self.ip(trusted_proxies)) {
+            (Some(viewer_id), _) => Some(format!("user:{}", viewer_id)),
+            (None, Some(ip)) => Some(format!("ip:{}", ip)),
+            (None, None) => None,
+        }
+    }
+}
+
+pub struct DailyStats {
+    pub day: NaiveDate,
+    pub views: i64,
+    pub unique_readers: i64,
+    pub favorites: i64,
+    pub comments: i64,
+}
+
+pub struct ArticleStats {
+    pub total_views: i64,
+    pub unique_readers: i64,
+    pub favorites_count: i64,
+    pub comments_count: i64,
+    pub daily: Vec<DailyStats>,
+}
@@ -0,0 +1,5 @@
+pub mod controllers;
+pub mod entities;
+pub mod presenters;
+pub mod repositories;
+pub mod usecases;
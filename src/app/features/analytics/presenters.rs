@@ -0,0 +1,79 @@
+use actix_web::HttpResponse;
+use serde::{Deserialize, Serialize};
+
+use super::entities::{ArticleStats, DailyStats};
+
+#[derive(Deserialize, Serialize)]
+pub struct ArticleStatsResponse {
+    pub stats: ArticleStatsContent,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleStatsContent {
+    pub total_views: i64,
+    pub unique_readers: i64,
+    pub favorites_count: i64,
+    pub comments_count: i64,
+    pub daily: Vec<DailyStatsContent>,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyStatsContent {
+    pub date: String,
+    pub views: i64,
+    pub unique_readers: i64,
+    pub favorites: i64,
+    pub comments: i64,
+}
+
+impl From<DailyStats> for DailyStatsContent {
+    fn from(stats: DailyStats) -> Self {
+        Self {
+            date: stats.day.format("%Y-%m-%d").to_string(),
+            views: stats.views,
+            unique_readers: stats.unique_readers,
+            favorites: stats.favorites,
+            comments: stats.comments,
+        }
+    }
+}
+
+impl From<ArticleStats> for ArticleStatsResponse {
+    fn from(stats: ArticleStats) -> Self {
+        Self {
+            stats: ArticleStatsContent {
+                total_views: stats.total_views,
+                unique_readers: stats.unique_readers,
+                favorites_count: stats.favorites_count,
+                comments_count: stats.comments_count,
+                daily: stats
+                    .daily
+                    .into_iter()
+                    .map(DailyStatsContent::from)
+                    .collect(),
+            },
+        }
+    }
+}
+
+pub trait AnalyticsPresenter: Send + Sync + 'static {
+    fn to_stats_json(&self, stats: ArticleStats) -> HttpResponse;
+}
+
+#[derive(Clone)]
+pub struct AnalyticsPresenterImpl {}
+
+impl AnalyticsPresenterImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl AnalyticsPresenter for AnalyticsPresenterImpl {
+    fn to_stats_json(&self, stats: ArticleStats) -> HttpResponse {
+        let res = ArticleStatsResponse::from(stats);
+        HttpResponse::Ok().json(res)
+    }
+}
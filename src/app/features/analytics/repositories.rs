@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+
+use chrono::{Duration, Utc};
+use diesel::prelude::*;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    app::features::{
+        article::entities::Article, coauthor::entities::ArticleAuthor, comment::entities::Comment,
+        user::entities::User,
+    },
+    constants::VIEW_DEDUP_WINDOW_MINUTES,
+    error::AppError,
+    schema::{comments, favorites},
+    utils::db::DbPool,
+};
+
+use super::entities::{
+    ArticleDailyViews, ArticleStats, ArticleView, CreateArticleView, DailyStats, Reader,
+};
+
+pub trait AnalyticsRepository: Send + Sync + 'static {
+    fn record_view(
+        &self,
+        article_id: &Uuid,
+        reader: &Reader,
+        reader_key: &str,
+    ) -> Result<(), AppError>;
+    fn fetch_article_stats(
+        &self,
+        input: &FetchArticleStatsRepositoryInput,
+    ) -> Result<ArticleStats, AppError>;
+}
+
+#[derive(Clone)]
+pub struct AnalyticsRepositoryImpl {
+    pool: DbPool,
+}
+
+impl AnalyticsRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl AnalyticsRepository for AnalyticsRepositoryImpl {
+    fn record_view(
+        &self,
+        article_id: &Uuid,
+        reader: &Reader,
+        reader_key: &str,
+    ) -> Result<(), AppError> {
+        let conn = &mut self.pool.get()?;
+        conn.transaction(|conn| {
+            let now = Utc::now().naive_utc();
+            let today = now.date();
+            let day_start = today.and_hms_opt(0, 0, 0).unwrap_or(now);
+            let is_new_reader =
+                !ArticleView::exists_since(conn, article_id, reader_key, day_start)?;
+            // The unique index on the bucket settles concurrent hits of the same reader.
+            let recorded = ArticleView::create_once(
+                conn,
+                &CreateArticleView {
+                    article_id: *article_id,
+                    viewer_id: reader.viewer_id,
+                    reader_key: reader_key.to_owned(),
+                    bucket: now.and_utc().timestamp() / (VIEW_DEDUP_WINDOW_MINUTES * 60),
+                },
+            )?;
+            if !recorded {
+                return Ok(());
+            }
+            ArticleDailyViews::increment(conn, article_id, today, is_new_reader)
+        })
+    }
+
+    fn fetch_article_stats(
+        &self,
+        input: &FetchArticleStatsRepositoryInput,
+    ) -> Result<ArticleStats, AppError> {
+        let conn = &mut self.pool.get()?;
+        let article = Article::fetch_by_slug(conn, &input.article_title_slug)?;
+        if ArticleAuthor::fetch_role(conn, &article.id, &input.current_user.id)?.is_none() {
+            return Err(AppError::Forbidden(
+                json!({"error": "Only the authors of this article can view its stats."}),
+            ));
+        }
+
+        let today = Utc::now().naive_utc().date();
+        let since = today - Duration::days(input.days - 1);
+        let mut daily = BTreeMap::new();
+        let mut day = since;
+        while day <= today {
+            daily.insert(
+                day,
+                DailyStats {
+                    day,
+                    views: 0,
+                    unique_readers: 0,
+                    favorites: 0,
+                    comments: 0,
+                },
+            );
+            day += Duration::days(1);
+        }
+
+        for bucket in ArticleDailyViews::fetch_since(conn, &article.id, since)? {
+            if let Some(stats) = daily.get_mut(&bucket.day) {
+                stats.views = bucket.views as i64;
+                stats.unique_readers = bucket.unique_readers as i64;
+            }
+        }
+
+        let since_start = since.and_hms_opt(0, 0, 0).unwrap_or_default();
+        let favorited_at_list = favorites::table
+            .filter(favorites::article_id.eq(&article.id))
+            .filter(favorites::created_at.ge(since_start))
+            .select(favorites::created_at)
+            .load::<chrono::NaiveDateTime>(conn)?;
+        for favorited_at in favorited_at_list {
+            if let Some(stats) = daily.get_mut(&favorited_at.date()) {
+                stats.favorites += 1;
+            }
+        }
+
+        let commented_at_list = comments::table
+            .filter(comments::article_id.eq(&article.id))
+            .filter(Comment::not_deleted())
+            .filter(Comment::not_held())
+            .filter(comments::created_at.ge(since_start))
+            .select(comments::created_at)
+            .load::<chrono::NaiveDateTime>(conn)?;
+        for commented_at in commented_at_list {
+            if let Some(stats) = daily.get_mut(&commented_at.date()) {
+                stats.comments += 1;
+            }
+        }
+
+        let comments_count = comments::table
+            .filter(comments::article_id.eq(&article.id))
+            .filter(Comment::not_deleted())
+            .filter(Comment::not_held())
+            .select(diesel::dsl::count(comments::id))
+            .first::<i64>(conn)?;
+
+        Ok(ArticleStats {
+            total_views: ArticleDailyViews::sum_views(conn, &article.id)?,
+            unique_readers: ArticleView::count_unique_readers(conn, &article.id)?,
+            favorites_count: article.fetch_favorites_count(conn)?,
+            comments_count,
+            daily: daily.into_values().collect(),
+        })
+    }
+}
+
+pub struct FetchArticleStatsRepositoryInput {
+    pub article_title_slug: String,
+    pub current_user: User,
+    pub days: i64,
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use actix_web::HttpResponse;
+
+use crate::{app::features::user::entities::User, error::AppError};
+
+use super::{
+    presenters::AnalyticsPresenter,
+    repositories::{AnalyticsRepository, FetchArticleStatsRepositoryInput},
+};
+
+#[derive(Clone)]
+pub struct AnalyticsUsecase {
+    analytics_repository: Arc<dyn AnalyticsRepository>,
+    analytics_presenter: Arc<dyn AnalyticsPresenter>,
+}
+
+impl AnalyticsUsecase {
+    pub fn new(
+        analytics_repository: Arc<dyn AnalyticsRepository>,
+        analytics_presenter: Arc<dyn AnalyticsPresenter>,
+    ) -> Self {
+        Self {
+            analytics_repository,
+            analytics_presenter,
+        }
+    }
+
+    pub fn fetch_article_stats(
+        &self,
+        current_user: User,
+        article_title_slug: String,
+        days: i64,
+    ) -> Result<HttpResponse, AppError> {
+        let stats =
+            self.analytics_repository
+                .fetch_article_stats(&FetchArticleStatsRepositoryInput {
+                    article_title_slug,
+                    current_user,
+                    days,
+                })?;
+        let res = self.analytics_presenter.to_stats_json(stats);
+        Ok(res)
+    }
+}
@@ -1,9 +1,19 @@
-use actix_web::{web, HttpRequest};
+use actix_web::{http::header, web, HttpRequest};
+use chrono::NaiveDateTime;
 use serde::Deserialize;
+use serde_json::json;
 
 use crate::{
-    app::drivers::middlewares::{auth, state::AppState},
-    utils::api::ApiResponse,
+    app::{
+        drivers::middlewares::{auth, state::AppState},
+        features::{
+            analytics::entities::Reader, tag::entities::TagMatch,
+            translation::entities::LocalePreference,
+        },
+    },
+    constants::RELATED_ARTICLES_LIMIT,
+    error::AppError,
+    utils::{api::ApiResponse, date, etag::Preconditions, locale},
 };
 
 use super::{
@@ -15,32 +25,76 @@ use super::{
 };
 
 #[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ArticlesListQueryParameter {
+    // `tag`, `author`, `excludeTag` and `excludeAuthor` take comma separated lists.
     tag: Option<String>,
+    tag_mode: Option<String>,
+    exclude_tag: Option<String>,
     author: Option<String>,
+    exclude_author: Option<String>,
     favorited: Option<String>,
+    created_after: Option<String>,
+    created_before: Option<String>,
+    min_reading_minutes: Option<i32>,
+    max_reading_minutes: Option<i32>,
     limit: Option<i64>,
     offset: Option<i64>,
 }
 
 pub async fn index(
     state: web::Data<AppState>,
+    req: HttpRequest,
     params: web::Query<ArticlesListQueryParameter>,
 ) -> ApiResponse {
     let offset = std::cmp::min(params.offset.unwrap_or(0), 100);
     let limit = params.limit.unwrap_or(20);
+    let tag_match = match params.tag_mode.as_deref() {
+        None => TagMatch::default(),
+        Some(mode) => TagMatch::parse(mode).ok_or_else(|| {
+            AppError::UnprocessableEntity(json!({"error": "tagMode must be any or all."}))
+        })?,
+    };
     state
         .di_container
         .article_usecase
         .fetch_articles(FetchArticlesUsecaseInput {
-            tag: params.tag.clone(),
-            author: params.author.clone(),
+            tags: split_list(&params.tag),
+            tag_match,
+            exclude_tags: split_list(&params.exclude_tag),
+            authors: split_list(&params.author),
+            exclude_authors: split_list(&params.exclude_author),
             favorited: params.favorited.clone(),
+            created_after: parse_date_param("createdAfter", &params.created_after)?,
+            created_before: parse_date_param("createdBefore", &params.created_before)?,
+            min_reading_minutes: params.min_reading_minutes,
+            max_reading_minutes: params.max_reading_minutes,
+            current_user: auth::get_current_user(&req).ok(),
             offset,
             limit,
         })
 }
 
+fn split_list(value: &Option<String>) -> Vec<String> {
+    value
+        .iter()
+        .flat_map(|value| value.split(','))
+        .map(|item| item.trim().to_owned())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+fn parse_date_param(name: &str, value: &Option<String>) -> Result<Option<NaiveDateTime>, AppError> {
+    match value.as_deref().map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(value) => date::parse_date_time(value).map(Some).ok_or_else(|| {
+            AppError::UnprocessableEntity(json!({
+                "error": format!("{} must look like 2024-01-31 or 2024-01-31T09:00:00Z.", name)
+            }))
+        }),
+    }
+}
+
 #[derive(Deserialize)]
 pub struct FeedQueryParameter {
     limit: Option<i64>,
@@ -63,12 +117,71 @@ pub async fn feed(
 
 type ArticleTitleSlug = String;
 
-pub async fn show(state: web::Data<AppState>, path: web::Path<ArticleTitleSlug>) -> ApiResponse {
+#[derive(Deserialize)]
+pub struct ShowQueryParameter {
+    lang: Option<String>,
+}
+
+pub async fn show(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ArticleTitleSlug>,
+    params: web::Query<ShowQueryParameter>,
+) -> ApiResponse {
+    let article_title_slug = path.into_inner();
+    let current_user = auth::get_current_user(&req).ok();
+    let reader = Reader {
+        viewer_id: current_user.as_ref().map(|user| user.id),
+        peer_ip: req.peer_addr().map(|addr| addr.ip()),
+        forwarded_for: req
+            .headers()
+            .get(header::X_FORWARDED_FOR)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(|hop| hop.trim().to_owned())
+                    .filter(|hop| !hop.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default(),
+    };
+    let preference = LocalePreference {
+        lang: params.lang.as_deref().and_then(locale::normalize),
+        accept_language: req
+            .headers()
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .map(locale::parse_accept_language)
+            .unwrap_or_default(),
+    };
+    state.di_container.article_usecase.fetch_article_by_slug(
+        article_title_slug,
+        current_user,
+        reader,
+        preference,
+        Preconditions::from_request(&req),
+    )
+}
+
+#[derive(Deserialize)]
+pub struct RelatedQueryParameter {
+    limit: Option<usize>,
+}
+
+pub async fn related(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ArticleTitleSlug>,
+    params: web::Query<RelatedQueryParameter>,
+) -> ApiResponse {
     let article_title_slug = path.into_inner();
-    state
-        .di_container
-        .article_usecase
-        .fetch_article_by_slug(article_title_slug)
+    let limit = std::cmp::min(params.limit.unwrap_or(5), RELATED_ARTICLES_LIMIT);
+    state.di_container.article_usecase.fetch_related_articles(
+        article_title_slug,
+        auth::get_current_user(&req).ok(),
+        limit,
+    )
 }
 
 pub async fn create(
@@ -85,6 +198,10 @@ pub async fn create(
             description: form.article.description.clone(),
             body: form.article.body.clone(),
             tag_name_list: form.article.tag_list.to_owned(),
+            meta_title: form.article.meta_title.clone(),
+            meta_description: form.article.meta_description.clone(),
+            canonical_url: form.article.canonical_url.clone(),
+            cover_image: form.article.cover_image.clone(),
             current_user,
         })
 }
@@ -100,6 +217,7 @@ pub async fn update(
     let title = form.article.title.clone();
     let description = form.article.description.clone();
     let body = form.article.body.clone();
+    let form = form.into_inner().article;
     state
         .di_container
         .article_usecase
@@ -109,6 +227,11 @@ pub async fn update(
             title,
             description,
             body,
+            meta_title: form.meta_title,
+            meta_description: form.meta_description,
+            canonical_url: form.canonical_url,
+            cover_image: form.cover_image,
+            preconditions: Preconditions::from_request(&req),
         })
 }
 
@@ -125,5 +248,6 @@ pub async fn delete(
         .delete_article(DeleteArticleUsecaseInput {
             author_id: current_user.id,
             slug: article_title_slug,
+            preconditions: Preconditions::from_request(&req),
         })
 }
@@ -1,8 +1,8 @@
-use chrono::NaiveDateTime;
+use chrono::{NaiveDateTime, Utc};
 use diesel::{
     associations::{Associations, Identifiable},
     deserialize::Queryable,
-    dsl::Eq,
+    dsl::{Eq, IsNotNull, IsNull},
     prelude::*,
     PgConnection, QueryDsl,
 };
@@ -10,15 +10,20 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
-    app::features::{favorite::entities::Favorite, user::entities::User},
+    app::features::{
+        coauthor::entities::ArticleAuthor, favorite::entities::Favorite, user::entities::User,
+    },
+    constants::WORDS_PER_MINUTE,
     error::AppError,
     schema::{articles, favorites, users},
-    utils::converter,
+    utils::{converter, etag, markdown},
 };
 
 type WithAuthorId<T> = Eq<articles::author_id, T>;
 type WithSlug<T> = Eq<articles::slug, T>;
 type WithId<T> = Eq<articles::id, T>;
+type NotDeleted = IsNull<articles::deleted_at>;
+type Deleted = IsNotNull<articles::deleted_at>;
 
 #[derive(Identifiable, Queryable, Debug, Serialize, Deserialize, Associations, Clone)]
 #[diesel(belongs_to(User, foreign_key = author_id))]
@@ -32,6 +37,16 @@ pub struct Article {
     pub body: String,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub word_count: i32,
+    pub reading_minutes: i32,
+    pub table_of_contents: serde_json::Value,
+    pub deleted_at: Option<NaiveDateTime>,
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub canonical_url: Option<String>,
+    pub cover_image: Option<String>,
+    pub version: i32,
+    pub comments_locked: bool,
 }
 
 impl Article {
@@ -46,6 +61,15 @@ impl Article {
     fn with_id(id: &Uuid) -> WithId<&Uuid> {
         articles::id.eq(id)
     }
+
+    // Every read must go through this filter so trashed articles stay hidden.
+    pub fn not_deleted() -> NotDeleted {
+        articles::deleted_at.is_null()
+    }
+
+    fn deleted() -> Deleted {
+        articles::deleted_at.is_not_null()
+    }
 }
 
 impl Article {
@@ -55,18 +79,6 @@ impl Article {
             .get_result::<Article>(conn)?;
         Ok(article)
     }
-    pub fn fetch_ids_by_author_name(
-        conn: &mut PgConnection,
-        name: &str,
-    ) -> Result<Vec<Uuid>, AppError> {
-        use crate::schema::users;
-        let t = users::table
-            .inner_join(articles::table)
-            .filter(User::with_username(name))
-            .select(articles::id);
-        let ids = t.load::<Uuid>(conn)?;
-        Ok(ids)
-    }
 
     pub fn update(
         conn: &mut PgConnection,
@@ -76,22 +88,96 @@ impl Article {
     ) -> Result<Self, AppError> {
         let t = articles::table
             .filter(Self::with_slug(article_title_slug))
-            .filter(Self::with_author_id(author_id));
-        let article = diesel::update(t).set(record).get_result::<Article>(conn)?;
+            .filter(Self::not_deleted())
+            .filter(articles::id.eq_any(ArticleAuthor::editable_article_ids(author_id)));
+        let article = diesel::update(t)
+            .set((record, articles::version.eq(articles::version + 1)))
+            .get_result::<Article>(conn)?;
         Ok(article)
     }
 
+    pub fn set_comments_locked(
+        conn: &mut PgConnection,
+        id: &Uuid,
+        locked: bool,
+    ) -> Result<Self, AppError> {
+        let item = diesel::update(articles::table.filter(Self::with_id(id)))
+            .set((
+                articles::comments_locked.eq(locked),
+                articles::version.eq(articles::version + 1),
+            ))
+            .get_result::<Self>(conn)?;
+        Ok(item)
+    }
+
+    // Marks the article as changed for writes that don't go through `update`,
+    // e.g. its translations.
+    pub fn bump_version(conn: &mut PgConnection, id: &Uuid) -> Result<(), AppError> {
+        diesel::update(articles::table.filter(Self::with_id(id)))
+            .set(articles::version.eq(articles::version + 1))
+            .execute(conn)?;
+        Ok(())
+    }
+
     pub fn fetch_by_slug_and_author_id(
         conn: &mut PgConnection,
         params: &FetchBySlugAndAuthorId,
     ) -> Result<Self, AppError> {
         let t = articles::table
             .filter(Self::with_slug(&params.slug))
-            .filter(Self::with_author_id(&params.author_id));
+            .filter(Self::with_author_id(&params.author_id))
+            .filter(Self::not_deleted());
         let item = t.first::<Self>(conn)?;
         Ok(item)
     }
 
+    pub fn fetch_by_slug(conn: &mut PgConnection, slug: &str) -> Result<Self, AppError> {
+        let t = articles::table
+            .filter(Self::with_slug(slug))
+            .filter(Self::not_deleted());
+        let item = t.first::<Self>(conn)?;
+        Ok(item)
+    }
+
+    // Same as `fetch_by_slug`, holding the row until the transaction ends.
+    pub fn lock_by_slug(conn: &mut PgConnection, slug: &str) -> Result<Self, AppError> {
+        let t = articles::table
+            .filter(Self::with_slug(slug))
+            .filter(Self::not_deleted())
+            .for_update();
+        let item = t.first::<Self>(conn)?;
+        Ok(item)
+    }
+
+    pub fn entity_tag(&self) -> String {
+        etag::from_version(&self.id, self.version)
+    }
+
+    // Slugs are only unique among live articles, so the trash may hold several
+    // articles with the same slug; only those `owner_id` owns are considered,
+    // the one trashed last first.
+    pub fn fetch_deleted_by_slug(
+        conn: &mut PgConnection,
+        slug: &str,
+        owner_id: &Uuid,
+    ) -> Result<Option<Self>, AppError> {
+        let t = articles::table
+            .filter(Self::with_slug(slug))
+            .filter(Self::deleted())
+            .filter(articles::id.eq_any(ArticleAuthor::owned_article_ids(owner_id)))
+            .order(articles::deleted_at.desc());
+        let item = t.first::<Self>(conn).optional()?;
+        Ok(item)
+    }
+
+    pub fn is_slug_taken(conn: &mut PgConnection, slug: &str) -> Result<bool, AppError> {
+        let t = articles::table
+            .filter(Self::with_slug(slug))
+            .filter(Self::not_deleted());
+        let taken = diesel::select(diesel::dsl::exists(t)).get_result::<bool>(conn)?;
+        Ok(taken)
+    }
+
     pub fn fetch_by_slug_with_author(
         conn: &mut PgConnection,
         slug: &str,
@@ -100,27 +186,79 @@ impl Article {
 
         let t = articles::table
             .inner_join(users::table)
-            .filter(Self::with_slug(slug));
+            .filter(Self::with_slug(slug))
+            .filter(Self::not_deleted());
         let result = t.get_result::<(Self, User)>(conn)?;
         Ok(result)
     }
 
+    // Moves the article to the trash; `purge_deleted_before` removes it for good.
     pub fn delete(conn: &mut PgConnection, params: &DeleteArticle) -> Result<(), AppError> {
         let t = articles::table
             .filter(Self::with_slug(&params.slug))
-            .filter(Self::with_author_id(&params.author_id));
-        diesel::delete(t).execute(conn)?;
+            .filter(Self::not_deleted())
+            .filter(articles::id.eq_any(ArticleAuthor::owned_article_ids(&params.author_id)));
+        diesel::update(t)
+            .set(articles::deleted_at.eq(Some(Utc::now().naive_utc())))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    // Moves the article to the trash on behalf of a moderator, whoever owns it.
+    pub fn remove(conn: &mut PgConnection, id: &Uuid) -> Result<(), AppError> {
+        let t = articles::table
+            .filter(Self::with_id(id))
+            .filter(Self::not_deleted());
+        diesel::update(t)
+            .set(articles::deleted_at.eq(Some(Utc::now().naive_utc())))
+            .execute(conn)?;
         Ok(())
     }
 
+    pub fn restore(conn: &mut PgConnection, id: &Uuid) -> Result<Self, AppError> {
+        let t = articles::table
+            .filter(Self::with_id(id))
+            .filter(Self::deleted());
+        let item = diesel::update(t)
+            .set(articles::deleted_at.eq(None::<NaiveDateTime>))
+            .get_result::<Self>(conn)?;
+        Ok(item)
+    }
+
+    pub fn fetch_deleted_by_owner(
+        conn: &mut PgConnection,
+        owner_id: &Uuid,
+    ) -> Result<Vec<Self>, AppError> {
+        let t = articles::table
+            .filter(Self::deleted())
+            .filter(articles::id.eq_any(ArticleAuthor::owned_article_ids(owner_id)))
+            .order(articles::deleted_at.desc());
+        let list = t.load::<Self>(conn)?;
+        Ok(list)
+    }
+
+    pub fn purge_deleted_before(
+        conn: &mut PgConnection,
+        cutoff: &NaiveDateTime,
+    ) -> Result<usize, AppError> {
+        let t = articles::table.filter(articles::deleted_at.lt(cutoff));
+        let count = diesel::delete(t).execute(conn)?;
+        Ok(count)
+    }
+
     pub fn convert_title_to_slug(title: &str) -> String {
         converter::to_kebab(title)
     }
 
+    pub fn toc_entries(&self) -> Vec<TocEntry> {
+        serde_json::from_value(self.table_of_contents.clone()).unwrap_or_default()
+    }
+
     pub fn find_with_author(conn: &mut PgConnection, id: &Uuid) -> Result<(Self, User), AppError> {
         let t = articles::table
             .inner_join(users::table)
-            .filter(Self::with_id(id));
+            .filter(Self::with_id(id))
+            .filter(Self::not_deleted());
         let result = t.get_result::<(Article, User)>(conn)?;
         Ok(result)
     }
@@ -151,6 +289,55 @@ impl Article {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TocEntry {
+    pub level: u8,
+    pub text: String,
+    pub anchor: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReadingMetadata {
+    pub word_count: i32,
+    pub reading_minutes: i32,
+    pub table_of_contents: Vec<TocEntry>,
+}
+
+impl ReadingMetadata {
+    pub fn from_body(body: &str) -> Self {
+        let word_count = markdown::count_words(body) as i32;
+        let reading_minutes = (word_count + WORDS_PER_MINUTE - 1) / WORDS_PER_MINUTE;
+        let mut anchors: Vec<String> = vec![];
+        let table_of_contents = markdown::headings(body)
+            .into_iter()
+            .map(|heading| {
+                let base = converter::to_kebab(&heading.text);
+                let mut anchor = base.clone();
+                let mut suffix = 0;
+                while anchors.contains(&anchor) {
+                    suffix += 1;
+                    anchor = format!("{}-{}", base, suffix);
+                }
+                anchors.push(anchor.clone());
+                TocEntry {
+                    level: heading.level,
+                    text: heading.text,
+                    anchor,
+                }
+            })
+            .collect();
+        Self {
+            word_count,
+            reading_minutes,
+            table_of_contents,
+        }
+    }
+
+    pub fn table_of_contents_json(&self) -> serde_json::Value {
+        serde_json::to_value(&self.table_of_contents).unwrap_or_default()
+    }
+}
+
 #[derive(Insertable, Clone)]
 #[diesel(table_name = articles)]
 pub struct CreateArticle {
@@ -159,6 +346,15 @@ pub struct CreateArticle {
     pub title: String,
     pub description: String,
     pub body: String,
+    pub word_count: i32,
+    pub reading_minutes: i32,
+    pub table_of_contents: serde_json::Value,
+    // Falls back to the column default when not set.
+    pub created_at: Option<NaiveDateTime>,
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub canonical_url: Option<String>,
+    pub cover_image: Option<String>,
 }
 
 #[derive(AsChangeset)]
@@ -168,6 +364,14 @@ pub struct UpdateArticle {
     pub title: Option<String>,
     pub description: Option<String>,
     pub body: Option<String>,
+    pub word_count: Option<i32>,
+    pub reading_minutes: Option<i32>,
+    pub table_of_contents: Option<serde_json::Value>,
+    // `Some(None)` clears the field.
+    pub meta_title: Option<Option<String>>,
+    pub meta_description: Option<Option<String>>,
+    pub canonical_url: Option<Option<String>>,
+    pub cover_image: Option<Option<String>>,
 }
 
 pub struct DeleteArticle {
@@ -1,15 +1,34 @@
-use super::{entities::Article, repositories::ArticlesList};
+use super::{
+    entities::TocEntry,
+    repositories::{ArticlesList, SingleArticle},
+};
 use crate::{
     app::features::{
-        favorite::entities::FavoriteInfo, profile::entities::Profile, tag::entities::Tag,
+        coauthor::presenters::ArticleAuthorContent,
+        reaction::presenters::ReactionContent,
+        series::{entities::SeriesNavigation, presenters::SeriesNavigationContent},
+        translation::entities::Localization,
+    },
+    utils::{
+        date::Iso8601,
+        etag::{self, Preconditions},
     },
-    utils::date::Iso8601,
 };
 use actix_web::HttpResponse;
 use serde::{Deserialize, Serialize};
 
+// The article is shown in the reader's language, with flags of the signed-in user.
+const VARY: &str = "Accept-Language, Authorization";
+
 pub trait ArticlePresenter: Send + Sync + 'static {
-    fn to_single_json(&self, item: (Article, Profile, FavoriteInfo, Vec<Tag>)) -> HttpResponse;
+    fn to_single_json(&self, item: SingleArticle) -> HttpResponse;
+    fn to_detail_json(
+        &self,
+        item: SingleArticle,
+        series: Option<SeriesNavigation>,
+        localization: Localization,
+        preconditions: &Preconditions,
+    ) -> HttpResponse;
     fn to_multi_json(&self, list: ArticlesList, count: i64) -> HttpResponse;
     fn to_http_res(&self) -> HttpResponse;
 }
@@ -23,15 +42,33 @@ impl ArticlePresenterImpl {
 }
 
 impl ArticlePresenter for ArticlePresenterImpl {
-    fn to_single_json(&self, item: (Article, Profile, FavoriteInfo, Vec<Tag>)) -> HttpResponse {
+    fn to_single_json(&self, item: SingleArticle) -> HttpResponse {
+        let entity_tag = item.0.entity_tag();
         let res = SingleArticleResponse::from(item);
-        HttpResponse::Ok().json(res)
+        etag::respond_json(&entity_tag, &res, &Preconditions::default(), VARY)
+    }
+
+    fn to_detail_json(
+        &self,
+        item: SingleArticle,
+        series: Option<SeriesNavigation>,
+        localization: Localization,
+        preconditions: &Preconditions,
+    ) -> HttpResponse {
+        let entity_tag = item.0.entity_tag();
+        let mut res = SingleArticleResponse::from(item);
+        res.article.series = series.map(SeriesNavigationContent::from);
+        res.article.locale = localization.locale;
+        res.article.translation_slug = localization.translation_slug;
+        res.article.locales = Some(localization.locales);
+        etag::respond_json(&entity_tag, &res, preconditions, VARY)
     }
 
     fn to_multi_json(&self, list: ArticlesList, count: i64) -> HttpResponse {
         let res = MultipleArticlesResponse::from((list, count));
         HttpResponse::Ok().json(res)
     }
+
     fn to_http_res(&self) -> HttpResponse {
         HttpResponse::Ok().json(())
     }
@@ -50,21 +87,29 @@ pub struct MultipleArticlesResponse {
 }
 
 type ArticleCount = i64;
-type Inner = ((Article, Profile, FavoriteInfo), Vec<Tag>);
 type Item = (ArticlesList, ArticleCount);
 
 impl From<Item> for MultipleArticlesResponse {
-    fn from((list, articles_count): (Vec<Inner>, ArticleCount)) -> Self {
+    fn from((list, articles_count): Item) -> Self {
         let articles = list
             .iter()
-            .map(|((article, profile, favorite_info), tags_list)| {
-                ArticleContent::from((
-                    article.to_owned(),
-                    profile.to_owned(),
-                    favorite_info.to_owned(),
-                    tags_list.to_owned(),
-                ))
-            })
+            .map(
+                |(
+                    (article, profile, favorite_info, bookmark_info, reaction_info),
+                    tags_list,
+                    authors,
+                )| {
+                    ArticleContent::from((
+                        article.to_owned(),
+                        profile.to_owned(),
+                        favorite_info.to_owned(),
+                        tags_list.to_owned(),
+                        authors.to_owned(),
+                        bookmark_info.to_owned(),
+                        reaction_info.to_owned(),
+                    ))
+                },
+            )
             .collect();
         Self {
             articles_count,
@@ -85,7 +130,29 @@ pub struct ArticleContent {
     pub updated_at: Iso8601,
     pub favorited: bool,
     pub favorites_count: i64,
+    pub bookmarked: bool,
+    pub reactions: Vec<ReactionContent>,
+    pub word_count: i32,
+    pub reading_minutes: i32,
+    pub table_of_contents: Vec<TocEntry>,
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub canonical_url: Option<String>,
+    pub cover_image: Option<String>,
+    pub comments_locked: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub series: Option<SeriesNavigationContent>,
+    // The translation shown, absent when the article is shown as written.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    // The slug of that translation; `slug` stays the article's own.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translation_slug: Option<String>,
+    // Locales the article is translated to, only listed for a single article.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locales: Option<Vec<String>>,
     pub author: AuthorContent,
+    pub authors: Vec<ArticleAuthorContent>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -96,10 +163,11 @@ pub struct AuthorContent {
     pub following: bool,
 }
 
-impl From<(Article, Profile, FavoriteInfo, Vec<Tag>)> for SingleArticleResponse {
+impl From<SingleArticle> for SingleArticleResponse {
     fn from(
-        (article, profile, favorite_info, tag_list): (Article, Profile, FavoriteInfo, Vec<Tag>),
+        (article, profile, favorite_info, tag_list, authors, bookmark_info, reaction_info): SingleArticle,
     ) -> Self {
+        let table_of_contents = article.toc_entries();
         Self {
             article: ArticleContent {
                 slug: article.slug,
@@ -111,21 +179,40 @@ impl From<(Article, Profile, FavoriteInfo, Vec<Tag>)> for SingleArticleResponse
                 updated_at: Iso8601(article.updated_at),
                 favorited: favorite_info.is_favorited,
                 favorites_count: favorite_info.favorites_count,
+                bookmarked: bookmark_info.is_bookmarked,
+                reactions: ReactionContent::list(reaction_info),
+                word_count: article.word_count,
+                reading_minutes: article.reading_minutes,
+                table_of_contents,
+                meta_title: article.meta_title,
+                meta_description: article.meta_description,
+                canonical_url: article.canonical_url,
+                cover_image: article.cover_image,
+                comments_locked: article.comments_locked,
+                series: None,
+                locale: None,
+                translation_slug: None,
+                locales: None,
                 author: AuthorContent {
                     username: profile.username,
                     bio: profile.bio,
                     image: profile.image,
                     following: profile.following,
                 },
+                authors: authors
+                    .into_iter()
+                    .map(ArticleAuthorContent::from)
+                    .collect(),
             },
         }
     }
 }
 
-impl From<(Article, Profile, FavoriteInfo, Vec<Tag>)> for ArticleContent {
+impl From<SingleArticle> for ArticleContent {
     fn from(
-        (article, profile, favorite_info, tag_list): (Article, Profile, FavoriteInfo, Vec<Tag>),
+        (article, profile, favorite_info, tag_list, authors, bookmark_info, reaction_info): SingleArticle,
     ) -> Self {
+        let table_of_contents = article.toc_entries();
         Self {
             slug: article.slug,
             title: article.title,
@@ -136,12 +223,30 @@ impl From<(Article, Profile, FavoriteInfo, Vec<Tag>)> for ArticleContent {
             updated_at: Iso8601(article.updated_at),
             favorited: favorite_info.is_favorited,
             favorites_count: favorite_info.favorites_count,
+            bookmarked: bookmark_info.is_bookmarked,
+            reactions: ReactionContent::list(reaction_info),
+            word_count: article.word_count,
+            reading_minutes: article.reading_minutes,
+            table_of_contents,
+            meta_title: article.meta_title,
+            meta_description: article.meta_description,
+            canonical_url: article.canonical_url,
+            cover_image: article.cover_image,
+            comments_locked: article.comments_locked,
+            series: None,
+            locale: None,
+            translation_slug: None,
+            locales: None,
             author: AuthorContent {
                 username: profile.username,
                 bio: profile.bio,
                 image: profile.image,
                 following: profile.following,
             },
+            authors: authors
+                .into_iter()
+                .map(ArticleAuthorContent::from)
+                .collect(),
         }
     }
 }
@@ -1,15 +1,25 @@
-use crate::app::features::article::entities::{Article, CreateArticle};
+use crate::app::features::article::entities::{Article, CreateArticle, ReadingMetadata};
+use crate::app::features::bookmark::entities::{Bookmark, BookmarkInfo};
+use crate::app::features::coauthor::entities::{
+    ArticleAuthor, ArticleAuthorProfile, AuthorRole, CreateArticleAuthor,
+};
 use crate::app::features::favorite::entities::{Favorite, FavoriteInfo};
 use crate::app::features::follow::entities::Follow;
+use crate::app::features::mention::entities::{Mention, MentionSource};
 use crate::app::features::profile::entities::Profile;
-use crate::app::features::tag::entities::{CreateTag, Tag};
+use crate::app::features::reaction::entities::{ArticleReaction, ReactionInfo};
+use crate::app::features::tag::entities::{CreateTag, Tag, TagMatch};
 use crate::app::features::user::entities::User;
 use crate::error::AppError;
 use crate::schema::articles::dsl::*;
-use crate::schema::{articles, follows, users};
-use crate::utils::db::DbPool;
+use crate::schema::{articles, favorites, tags, users};
+use crate::utils::{db::DbPool, etag::Preconditions, similarity};
+use chrono::NaiveDateTime;
+use diesel::pg::Pg;
 use diesel::prelude::*;
 use diesel::QueryDsl;
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 use super::entities::{DeleteArticle, UpdateArticle};
@@ -27,24 +37,48 @@ pub trait ArticleRepository: Send + Sync + 'static {
     fn fetch_article_by_slug(
         &self,
         article_title_slug: String,
+        current_user: &Option<User>,
     ) -> Result<FetchArticleBySlugOutput, AppError>;
 
     fn create_article(
         &self,
         params: CreateArticleRepositoryInput,
-    ) -> Result<(Article, Profile, FavoriteInfo, Vec<Tag>), AppError>;
+    ) -> Result<SingleArticle, AppError>;
+    // Creates every article inside one transaction. An article failing validation
+    // (e.g. a taken slug) is rolled back on its own and reported in place; any other
+    // error rolls back the whole batch.
+    fn create_articles(
+        &self,
+        list: Vec<CreateArticleRepositoryInput>,
+    ) -> Result<Vec<Result<SingleArticle, AppError>>, AppError>;
     fn update_article(
         &self,
         input: UpdateArticleRepositoryInput,
-    ) -> Result<(Article, Profile, FavoriteInfo, Vec<Tag>), AppError>;
+    ) -> Result<SingleArticle, AppError>;
 
     fn delete_article(&self, input: DeleteArticleRepositoryInput) -> Result<(), AppError>;
     fn fetch_article(
         &self,
         params: &FetchArticleRepositoryInput,
-    ) -> Result<(Article, Profile, FavoriteInfo, Vec<Tag>), AppError>;
+    ) -> Result<SingleArticle, AppError>;
+
+    fn fetch_related_article_ids(
+        &self,
+        article_title_slug: &str,
+        limit: usize,
+    ) -> Result<Vec<Uuid>, AppError>;
+    fn fetch_articles_by_ids(
+        &self,
+        ids: &[Uuid],
+        current_user: &Option<User>,
+    ) -> Result<ArticlesList, AppError>;
 }
 
+const TAG_WEIGHT: f64 = 0.5;
+const FAVORITER_WEIGHT: f64 = 0.3;
+const TEXT_WEIGHT: f64 = 0.2;
+const RECENT_CANDIDATES: i64 = 100;
+
 #[derive(Clone)]
 pub struct ArticleRepositoryImpl {
     pool: DbPool,
@@ -71,105 +105,225 @@ impl ArticleRepositoryImpl {
             .unwrap_or_else(|| Ok(vec![]));
         list
     }
-}
-impl ArticleRepository for ArticleRepositoryImpl {
-    fn fetch_articles(
-        &self,
-        params: FetchArticlesRepositoryInput,
-    ) -> Result<(ArticlesList, ArticlesCount), AppError> {
-        use crate::schema::{articles, tags, users};
-        use diesel::prelude::*;
 
-        let conn = &mut self.pool.get()?;
-        let query = {
-            let mut query = articles::table.inner_join(users::table).into_boxed();
-
-            if let Some(tag_name) = &params.tag {
-                let ids = Tag::fetch_article_ids_by_name(conn, tag_name)?;
-                query = query.filter(articles::id.eq_any(ids));
+    // Inside a caller's transaction the inner transaction becomes a savepoint.
+    // Mentioned users are only notified when `notify` is set.
+    fn insert_article(
+        conn: &mut PgConnection,
+        params: CreateArticleRepositoryInput,
+        notify: bool,
+    ) -> Result<SingleArticle, AppError> {
+        let (article, tag_list) = conn.transaction(|conn| {
+            let article = Article::create(
+                conn,
+                &CreateArticle {
+                    author_id: params.current_user.id,
+                    slug: params.slug.clone(),
+                    title: params.title.clone(),
+                    description: params.description.clone(),
+                    body: params.body.clone(),
+                    word_count: params.reading_metadata.word_count,
+                    reading_minutes: params.reading_metadata.reading_minutes,
+                    table_of_contents: params.reading_metadata.table_of_contents_json(),
+                    created_at: params.published_at,
+                    meta_title: params.meta_title.clone(),
+                    meta_description: params.meta_description.clone(),
+                    canonical_url: params.canonical_url.clone(),
+                    cover_image: params.cover_image.clone(),
+                },
+            )?;
+            ArticleAuthor::upsert(
+                conn,
+                &CreateArticleAuthor {
+                    article_id: article.id,
+                    user_id: params.current_user.id,
+                    role: AuthorRole::Owner.as_str(),
+                },
+            )?;
+            let tag_list = Self::create_tag_list(conn, params.tag_name_list, &article.id)?;
+            Mention::record(
+                conn,
+                MentionSource::Article {
+                    article_id: article.id,
+                },
+                &params.current_user,
+                &article.body,
+                notify,
+            )?;
+            Ok::<_, AppError>((article, tag_list))
+        })?;
+        let profile = params
+            .current_user
+            .fetch_profile(conn, &article.author_id)?;
+        let favorite_info = {
+            let is_favorited = article.is_favorited_by_user_id(conn, &params.current_user.id)?;
+            let favorites_count = article.fetch_favorites_count(conn)?;
+            FavoriteInfo {
+                is_favorited,
+                favorites_count,
             }
+        };
+        let authors =
+            ArticleAuthor::fetch_profiles(conn, &article, &Some(params.current_user.clone()))?;
+        Ok((
+            article,
+            profile,
+            favorite_info,
+            tag_list,
+            authors,
+            BookmarkInfo::default(),
+            ReactionInfo::default(),
+        ))
+    }
 
-            if let Some(author_name) = &params.author {
-                let ids = Article::fetch_ids_by_author_name(conn, author_name)?;
-                query = query.filter(articles::id.eq_any(ids));
-            }
+    // Every filter is a condition or subquery of a single SQL statement.
+    fn filter_articles(params: &FetchArticlesRepositoryInput) -> ArticlesWithAuthorQuery<'_> {
+        let mut query = articles::table
+            .inner_join(users::table)
+            .filter(Article::not_deleted())
+            .into_boxed();
 
-            if let Some(username) = &params.favorited {
-                let ids = Favorite::fetch_favorited_artcile_ids_by_username(conn, username)?;
-                query = query.filter(articles::id.eq_any(ids));
+        match params.tag_match {
+            TagMatch::Any if !params.tags.is_empty() => {
+                query = query.filter(articles::id.eq_any(Tag::article_ids_by_names(&params.tags)));
             }
-            query
-        };
-        let articles_count = query
-            .select(diesel::dsl::count(articles::id))
-            .first::<i64>(conn)?;
-        let query = {
-            let mut query = articles::table.inner_join(users::table).into_boxed();
-
-            if let Some(tag_name) = &params.tag {
-                let ids = Tag::fetch_article_ids_by_name(conn, tag_name)?;
-                query = query.filter(articles::id.eq_any(ids));
+            TagMatch::Any => {}
+            TagMatch::All => {
+                for tag_name in params.tags.iter() {
+                    query = query.filter(
+                        articles::id
+                            .eq_any(Tag::article_ids_by_names(std::slice::from_ref(tag_name))),
+                    );
+                }
             }
+        }
 
-            if let Some(author_name) = &params.author {
-                let ids = Article::fetch_ids_by_author_name(conn, author_name)?;
-                query = query.filter(articles::id.eq_any(ids));
-            }
+        if !params.exclude_tags.is_empty() {
+            query =
+                query.filter(articles::id.ne_all(Tag::article_ids_by_names(&params.exclude_tags)));
+        }
 
-            if let Some(username) = &params.favorited {
-                let ids = Favorite::fetch_favorited_artcile_ids_by_username(conn, username)?;
-                query = query.filter(articles::id.eq_any(ids));
-            }
-            query
-        };
-        let result = {
-            let article_and_user_list =
-                query
-                    .offset(params.offset)
-                    .limit(params.limit)
-                    .load::<(Article, User)>(conn)?;
-            let tags_list = {
-                let article_list = article_and_user_list
-                    .clone()
-                    .into_iter()
-                    .map(|(article, _)| article)
+        if !params.authors.is_empty() {
+            query = query.filter(users::username.eq_any(&params.authors));
+        }
+
+        if !params.exclude_authors.is_empty() {
+            query = query.filter(users::username.ne_all(&params.exclude_authors));
+        }
+
+        if let Some(username) = &params.favorited {
+            query = query.filter(articles::id.eq_any(Favorite::article_ids_by_username(username)));
+        }
+
+        if let Some(created_after) = params.created_after {
+            query = query.filter(articles::created_at.ge(created_after));
+        }
+
+        if let Some(created_before) = params.created_before {
+            query = query.filter(articles::created_at.lt(created_before));
+        }
+
+        if let Some(minutes) = params.min_reading_minutes {
+            query = query.filter(articles::reading_minutes.ge(minutes));
+        }
+
+        if let Some(minutes) = params.max_reading_minutes {
+            query = query.filter(articles::reading_minutes.le(minutes));
+        }
+        query
+    }
+
+    // Loads what a list of articles shows in a fixed number of queries, with
+    // the following, favorited, bookmarked and reacted flags seen by `current_user`.
+    fn to_articles_list(
+        conn: &mut PgConnection,
+        article_and_user_list: Vec<(Article, User)>,
+        current_user: &Option<User>,
+    ) -> Result<ArticlesList, AppError> {
+        let article_list = article_and_user_list
+            .iter()
+            .map(|(article, _)| article.clone())
+            .collect::<Vec<_>>();
+        let article_ids = article_list
+            .iter()
+            .map(|article| article.id)
+            .collect::<Vec<_>>();
+        let tags_list = Tag::belonging_to(&article_list)
+            .order(tags::name.asc())
+            .load::<Tag>(conn)?
+            .grouped_by(&article_list);
+        let authors_list =
+            ArticleAuthor::fetch_profiles_grouped(conn, &article_list, current_user)?;
+        let favorites_counts = Favorite::fetch_counts_by_article_ids(conn, &article_ids)?;
+        let mut reaction_infos = ArticleReaction::fetch_infos(
+            conn,
+            &article_ids,
+            current_user.as_ref().map(|current_user| &current_user.id),
+        )?;
+        let (followed_ids, favorited_ids, bookmarked_ids) = match current_user {
+            Some(current_user) => {
+                let author_ids = article_and_user_list
+                    .iter()
+                    .map(|(_, user)| user.id)
                     .collect::<Vec<_>>();
-                let tag_list = Tag::belonging_to(&article_list)
-                    .order(tags::name.asc())
-                    .load::<Tag>(conn)?;
-                let tags_list: Vec<Vec<Tag>> = tag_list.grouped_by(&article_list);
-                tags_list
-            };
-            let favorites_count_list = {
-                let list: Result<Vec<_>, _> = article_and_user_list
-                    .clone()
-                    .into_iter()
-                    .map(|(article, _)| article.fetch_favorites_count(conn))
-                    .collect();
-                list?
-            };
-            article_and_user_list
-                .into_iter()
-                .zip(favorites_count_list)
-                .map(|((article, user), favorites_count)| {
-                    (
-                        article,
-                        Profile {
-                            username: user.username,
-                            bio: user.bio,
-                            image: user.image,
-                            following: false, // NOTE: because not authz
-                        },
-                        FavoriteInfo {
-                            is_favorited: false,
-                            favorites_count,
-                        },
-                    )
-                })
-                .zip(tags_list)
-                .collect::<Vec<_>>()
+                (
+                    Follow::fetch_followed_ids(conn, &current_user.id, &author_ids)?,
+                    Favorite::fetch_favorited_article_ids(conn, &current_user.id, &article_ids)?,
+                    Bookmark::fetch_bookmarked_article_ids(conn, &current_user.id, &article_ids)?,
+                )
+            }
+            None => Default::default(),
         };
-        Ok((result, articles_count))
+        let list = article_and_user_list
+            .into_iter()
+            .map(|(article, user)| {
+                let favorite_info = FavoriteInfo {
+                    is_favorited: favorited_ids.contains(&article.id),
+                    favorites_count: favorites_counts.get(&article.id).copied().unwrap_or(0),
+                };
+                let bookmark_info = BookmarkInfo {
+                    is_bookmarked: bookmarked_ids.contains(&article.id),
+                };
+                let reaction_info = reaction_infos.remove(&article.id).unwrap_or_default();
+                (
+                    article,
+                    Profile {
+                        following: followed_ids.contains(&user.id),
+                        username: user.username,
+                        bio: user.bio,
+                        image: user.image,
+                    },
+                    favorite_info,
+                    bookmark_info,
+                    reaction_info,
+                )
+            })
+            .zip(tags_list)
+            .zip(authors_list)
+            .map(|((item, tag_list), authors)| (item, tag_list, authors))
+            .collect::<Vec<_>>();
+        Ok(list)
+    }
+
+    fn text_of(article: &Article) -> HashSet<String> {
+        similarity::word_set(&format!("{} {}", article.title, article.description))
+    }
+}
+impl ArticleRepository for ArticleRepositoryImpl {
+    fn fetch_articles(
+        &self,
+        params: FetchArticlesRepositoryInput,
+    ) -> Result<(ArticlesList, ArticlesCount), AppError> {
+        let conn = &mut self.pool.get()?;
+        let articles_count = Self::filter_articles(&params)
+            .select(diesel::dsl::count(articles::id))
+            .first::<i64>(conn)?;
+        let article_and_user_list = Self::filter_articles(&params)
+            .offset(params.offset)
+            .limit(params.limit)
+            .load::<(Article, User)>(conn)?;
+        let list = Self::to_articles_list(conn, article_and_user_list, &params.current_user)?;
+        Ok((list, articles_count))
     }
 
     fn fetch_following_articles(
@@ -178,80 +332,29 @@ impl ArticleRepository for ArticleRepositoryImpl {
     ) -> Result<(ArticlesList, ArticlesCount), AppError> {
         let conn = &mut self.pool.get()?;
         let create_query = {
-            let ids = Follow::fetch_followee_ids_by_follower_id(conn, &params.current_user.id)?;
-            articles.filter(articles::author_id.eq_any(ids))
-        };
-
-        let articles_list = {
-            let article_and_user_list = create_query
-                .to_owned()
-                .inner_join(users::table)
-                .limit(params.limit)
-                .offset(params.offset)
-                .order(articles::created_at.desc())
-                .get_results::<(Article, User)>(conn)?;
-            let tag_list = {
-                let articles_list = article_and_user_list
-                    .clone()
-                    .into_iter()
-                    .map(|(article, _)| article)
-                    .collect::<Vec<_>>();
-                let tag_list = Tag::belonging_to(&articles_list).load::<Tag>(conn)?;
-                let tag_list = tag_list.grouped_by(&articles_list);
-                tag_list
-            };
-
-            let follows_list = {
-                let user_ids_list = article_and_user_list
-                    .clone()
-                    .into_iter()
-                    .map(|(_, user)| user.id)
-                    .collect::<Vec<_>>();
-                let list = follows::table
-                    .filter(Follow::with_follower(&params.current_user.id))
-                    .filter(follows::followee_id.eq_any(user_ids_list))
-                    .get_results::<Follow>(conn)?;
-                list.into_iter()
-            };
-
-            let favorites_count_list = {
-                let list: Result<Vec<_>, _> = article_and_user_list
-                    .clone()
-                    .into_iter()
-                    .map(|(article, _)| article.fetch_favorites_count(conn))
-                    .collect();
-                list?
-            };
-            let favorited_articles_ids = params.current_user.fetch_favorited_article_ids(conn)?;
-            let is_favorited_by_me = |article: &Article| {
-                favorited_articles_ids
-                    .iter()
-                    .copied()
-                    .any(|_id| _id == article.id)
-            };
-            article_and_user_list
+            // Muted users and users on either side of a block stay out of the feed.
+            let hidden_ids = params.current_user.fetch_hidden_user_ids(conn)?;
+            let ids = Follow::fetch_followee_ids_by_follower_id(conn, &params.current_user.id)?
                 .into_iter()
-                .zip(favorites_count_list)
-                .map(|((article, user), favorites_count)| {
-                    let following = follows_list.clone().any(|item| item.followee_id == user.id);
-                    let is_favorited = is_favorited_by_me(&article);
-                    (
-                        article,
-                        Profile {
-                            username: user.username,
-                            bio: user.bio,
-                            image: user.image,
-                            following: following.to_owned(),
-                        },
-                        FavoriteInfo {
-                            is_favorited,
-                            favorites_count,
-                        },
-                    )
-                })
-                .zip(tag_list)
-                .collect::<Vec<_>>()
+                .filter(|followee_id| !hidden_ids.contains(followee_id))
+                .collect::<Vec<_>>();
+            articles
+                .filter(articles::author_id.eq_any(ids))
+                .filter(Article::not_deleted())
         };
+
+        let article_and_user_list = create_query
+            .to_owned()
+            .inner_join(users::table)
+            .limit(params.limit)
+            .offset(params.offset)
+            .order(articles::created_at.desc())
+            .get_results::<(Article, User)>(conn)?;
+        let articles_list = Self::to_articles_list(
+            conn,
+            article_and_user_list,
+            &Some(params.current_user.clone()),
+        )?;
         let articles_count = create_query
             .select(diesel::dsl::count(articles::id))
             .first::<i64>(conn)?;
@@ -261,72 +364,131 @@ impl ArticleRepository for ArticleRepositoryImpl {
     fn fetch_article_by_slug(
         &self,
         article_title_slug: String,
+        current_user: &Option<User>,
     ) -> Result<FetchArticleBySlugOutput, AppError> {
         let conn = &mut self.pool.get()?;
         let (article, author) = Article::fetch_by_slug_with_author(conn, &article_title_slug)?;
-        let profile = author.fetch_profile(conn, &author.id)?;
-        let tag_list = Tag::belonging_to(&article).load::<Tag>(conn)?;
+        let profile = author.to_profile(conn, current_user);
+        let tag_list = Tag::belonging_to(&article)
+            .order(tags::name.asc())
+            .load::<Tag>(conn)?;
         let favorite_info = {
-            let is_favorited = article.is_favorited_by_user_id(conn, &author.id)?;
+            let is_favorited = match current_user {
+                Some(current_user) => article.is_favorited_by_user_id(conn, &current_user.id)?,
+                None => false,
+            };
             let favorites_count = article.fetch_favorites_count(conn)?;
             FavoriteInfo {
                 is_favorited,
                 favorites_count,
             }
         };
-        Ok((article, profile, favorite_info, tag_list))
+        let bookmark_info = BookmarkInfo {
+            is_bookmarked: match current_user {
+                Some(current_user) => Bookmark::is_bookmarked(conn, &current_user.id, &article.id)?,
+                None => false,
+            },
+        };
+        let reaction_info = ArticleReaction::fetch_info(
+            conn,
+            &article.id,
+            current_user.as_ref().map(|current_user| &current_user.id),
+        )?;
+        let authors = ArticleAuthor::fetch_profiles(conn, &article, current_user)?;
+        Ok((
+            article,
+            profile,
+            favorite_info,
+            tag_list,
+            authors,
+            bookmark_info,
+            reaction_info,
+        ))
     }
 
     fn create_article(
         &self,
         params: CreateArticleRepositoryInput,
-    ) -> Result<(Article, Profile, FavoriteInfo, Vec<Tag>), AppError> {
+    ) -> Result<SingleArticle, AppError> {
         let conn = &mut self.pool.get()?;
-        let article = Article::create(
-            conn,
-            &CreateArticle {
-                author_id: params.current_user.id,
-                slug: params.slug.clone(),
-                title: params.title.clone(),
-                description: params.description.clone(),
-                body: params.body.clone(),
-            },
-        )?;
-        let tag_list = Self::create_tag_list(conn, params.tag_name_list, &article.id)?;
-        let profile = params
-            .current_user
-            .fetch_profile(conn, &article.author_id)?;
-        let favorite_info = {
-            let is_favorited = article.is_favorited_by_user_id(conn, &params.current_user.id)?;
-            let favorites_count = article.fetch_favorites_count(conn)?;
-            FavoriteInfo {
-                is_favorited,
-                favorites_count,
+        Self::insert_article(conn, params, true)
+    }
+
+    fn create_articles(
+        &self,
+        list: Vec<CreateArticleRepositoryInput>,
+    ) -> Result<Vec<Result<SingleArticle, AppError>>, AppError> {
+        let conn = &mut self.pool.get()?;
+        conn.transaction(|conn| {
+            let mut results = vec![];
+            for params in list {
+                // Imported articles were written elsewhere, so nobody is notified.
+                match Self::insert_article(conn, params, false) {
+                    Err(err @ AppError::UnprocessableEntity(_)) => results.push(Err(err)),
+                    Err(err) => return Err(err),
+                    Ok(item) => results.push(Ok(item)),
+                }
             }
-        };
-        Ok((article, profile, favorite_info, tag_list))
+            Ok(results)
+        })
     }
 
     fn update_article(
         &self,
         input: UpdateArticleRepositoryInput,
-    ) -> Result<(Article, Profile, FavoriteInfo, Vec<Tag>), AppError> {
+    ) -> Result<SingleArticle, AppError> {
         let conn = &mut self.pool.get()?;
-        let article = Article::update(
-            conn,
-            &input.article_title_slug,
-            &input.current_user.id,
-            &UpdateArticle {
-                slug: input.slug.to_owned(),
-                title: input.title.to_owned(),
-                description: input.description.to_owned(),
-                body: input.body.to_owned(),
-            },
-        )?;
+        let article = conn.transaction::<_, AppError, _>(|conn| {
+            let article = Article::lock_by_slug(conn, &input.article_title_slug)?;
+            if ArticleAuthor::fetch_role(conn, &article.id, &input.current_user.id)?.is_none() {
+                return Err(AppError::Forbidden(
+                    json!({"error": "Only the authors of this article can update it."}),
+                ));
+            }
+            input.preconditions.check_if_match(&article.entity_tag())?;
+            Article::update(
+                conn,
+                &input.article_title_slug,
+                &input.current_user.id,
+                &UpdateArticle {
+                    slug: input.slug.to_owned(),
+                    title: input.title.to_owned(),
+                    description: input.description.to_owned(),
+                    body: input.body.to_owned(),
+                    word_count: input
+                        .reading_metadata
+                        .as_ref()
+                        .map(|metadata| metadata.word_count),
+                    reading_minutes: input
+                        .reading_metadata
+                        .as_ref()
+                        .map(|metadata| metadata.reading_minutes),
+                    table_of_contents: input
+                        .reading_metadata
+                        .as_ref()
+                        .map(|metadata| metadata.table_of_contents_json()),
+                    meta_title: input.meta_title,
+                    meta_description: input.meta_description,
+                    canonical_url: input.canonical_url,
+                    cover_image: input.cover_image,
+                },
+            )?;
+            if input.body.is_some() {
+                Mention::record(
+                    conn,
+                    MentionSource::Article {
+                        article_id: article.id,
+                    },
+                    &input.current_user,
+                    &article.body,
+                    true,
+                )?;
+            }
+            Ok(article)
+        })?;
         let tag_list = Tag::fetch_by_article_id(conn, &article.id)?;
-        let profile = input
-            .current_user
-            .fetch_profile(conn, &input.current_user.id)?;
+        let (_, author) = Article::find_with_author(conn, &article.id)?;
+        let profile = author.to_profile(conn, &Some(input.current_user.clone()));
         let favorite_info = {
             let is_favorited = article.is_favorited_by_user_id(conn, &input.current_user.id)?;
             let favorites_count = article.fetch_favorites_count(conn)?;
@@ -335,24 +497,49 @@ impl ArticleRepository for ArticleRepositoryImpl {
                 favorites_count,
             }
         };
-        Ok((article, profile, favorite_info, tag_list))
+        let bookmark_info = BookmarkInfo {
+            is_bookmarked: Bookmark::is_bookmarked(conn, &input.current_user.id, &article.id)?,
+        };
+        let reaction_info =
+            ArticleReaction::fetch_info(conn, &article.id, Some(&input.current_user.id))?;
+        let authors = ArticleAuthor::fetch_profiles(conn, &article, &Some(input.current_user))?;
+        Ok((
+            article,
+            profile,
+            favorite_info,
+            tag_list,
+            authors,
+            bookmark_info,
+            reaction_info,
+        ))
     }
 
     fn delete_article(&self, input: DeleteArticleRepositoryInput) -> Result<(), AppError> {
         let conn = &mut self.pool.get()?;
-        Article::delete(
-            conn,
-            &DeleteArticle {
-                slug: input.slug,
-                author_id: input.author_id,
-            },
-        )
+        conn.transaction(|conn| {
+            let article = Article::lock_by_slug(conn, &input.slug)?;
+            if ArticleAuthor::fetch_role(conn, &article.id, &input.author_id)?
+                != Some(AuthorRole::Owner)
+            {
+                return Err(AppError::Forbidden(
+                    json!({"error": "Only an owner can delete this article."}),
+                ));
+            }
+            input.preconditions.check_if_match(&article.entity_tag())?;
+            Article::delete(
+                conn,
+                &DeleteArticle {
+                    slug: input.slug,
+                    author_id: input.author_id,
+                },
+            )
+        })
     }
 
     fn fetch_article(
         &self,
         params: &FetchArticleRepositoryInput,
-    ) -> Result<(Article, Profile, FavoriteInfo, Vec<Tag>), AppError> {
+    ) -> Result<SingleArticle, AppError> {
         let conn = &mut self.pool.get()?;
         let (article, author) = Article::find_with_author(conn, &params.article_id)?;
         let profile = params.current_user.fetch_profile(conn, &author.id)?;
@@ -365,20 +552,157 @@ impl ArticleRepository for ArticleRepositoryImpl {
             }
         };
         let tag_list = { Tag::belonging_to(&article).load::<Tag>(conn)? };
-        Ok((article, profile, favorite_info, tag_list))
+        let authors =
+            ArticleAuthor::fetch_profiles(conn, &article, &Some(params.current_user.clone()))?;
+        let bookmark_info = BookmarkInfo {
+            is_bookmarked: Bookmark::is_bookmarked(conn, &params.current_user.id, &article.id)?,
+        };
+        let reaction_info =
+            ArticleReaction::fetch_info(conn, &article.id, Some(&params.current_user.id))?;
+        Ok((
+            article,
+            profile,
+            favorite_info,
+            tag_list,
+            authors,
+            bookmark_info,
+            reaction_info,
+        ))
+    }
+
+    fn fetch_related_article_ids(
+        &self,
+        article_title_slug: &str,
+        limit: usize,
+    ) -> Result<Vec<Uuid>, AppError> {
+        let conn = &mut self.pool.get()?;
+        let (target, _) = Article::fetch_by_slug_with_author(conn, article_title_slug)?;
+        let target_tags = Tag::belonging_to(&target)
+            .select(tags::name)
+            .load::<String>(conn)?;
+        let target_favoriters = favorites::table
+            .filter(Favorite::with_article_id(&target.id))
+            .select(favorites::user_id)
+            .load::<Uuid>(conn)?;
+
+        let candidate_ids = {
+            let mut ids = HashSet::new();
+            ids.extend(
+                tags::table
+                    .filter(tags::name.eq_any(&target_tags))
+                    .select(tags::article_id)
+                    .load::<Uuid>(conn)?,
+            );
+            ids.extend(
+                favorites::table
+                    .filter(favorites::user_id.eq_any(&target_favoriters))
+                    .select(favorites::article_id)
+                    .load::<Uuid>(conn)?,
+            );
+            ids.extend(
+                articles::table
+                    .filter(Article::not_deleted())
+                    .order(articles::created_at.desc())
+                    .limit(RECENT_CANDIDATES)
+                    .select(articles::id)
+                    .load::<Uuid>(conn)?,
+            );
+            ids.remove(&target.id);
+            ids.into_iter().collect::<Vec<_>>()
+        };
+        let candidates = articles::table
+            .filter(articles::id.eq_any(candidate_ids))
+            .filter(Article::not_deleted())
+            .load::<Article>(conn)?;
+        let tags_list = Tag::belonging_to(&candidates)
+            .load::<Tag>(conn)?
+            .grouped_by(&candidates);
+        let favorites_list = Favorite::belonging_to(&candidates)
+            .load::<Favorite>(conn)?
+            .grouped_by(&candidates);
+
+        let target_tags = target_tags.into_iter().collect::<HashSet<_>>();
+        let target_favoriters = target_favoriters.into_iter().collect::<HashSet<_>>();
+        let target_text = Self::text_of(&target);
+        let mut scored = candidates
+            .iter()
+            .zip(tags_list)
+            .zip(favorites_list)
+            .map(|((candidate, tag_list), favorite_list)| {
+                let tag_names = tag_list.into_iter().map(|tag| tag.name).collect();
+                let favoriters = favorite_list
+                    .into_iter()
+                    .map(|favorite| favorite.user_id)
+                    .collect();
+                let score = TAG_WEIGHT * similarity::jaccard(&target_tags, &tag_names)
+                    + FAVORITER_WEIGHT * similarity::jaccard(&target_favoriters, &favoriters)
+                    + TEXT_WEIGHT * similarity::jaccard(&target_text, &Self::text_of(candidate));
+                (candidate.id, score)
+            })
+            .filter(|(_, score)| *score > 0.0)
+            .collect::<Vec<_>>();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+        Ok(scored
+            .into_iter()
+            .take(limit)
+            .map(|(article_id, _)| article_id)
+            .collect())
+    }
+
+    fn fetch_articles_by_ids(
+        &self,
+        ids: &[Uuid],
+        current_user: &Option<User>,
+    ) -> Result<ArticlesList, AppError> {
+        let conn = &mut self.pool.get()?;
+        let article_and_user_list = {
+            let list = articles::table
+                .inner_join(users::table)
+                .filter(articles::id.eq_any(ids))
+                .filter(Article::not_deleted())
+                .load::<(Article, User)>(conn)?;
+            let mut by_id = list
+                .into_iter()
+                .map(|(article, user)| (article.id, (article, user)))
+                .collect::<HashMap<_, _>>();
+            ids.iter()
+                .filter_map(|article_id| by_id.remove(article_id))
+                .collect::<Vec<_>>()
+        };
+        Self::to_articles_list(conn, article_and_user_list, current_user)
     }
 }
 
-type ArticlesListInner = (Article, Profile, FavoriteInfo);
-pub type ArticlesList = Vec<(ArticlesListInner, Vec<Tag>)>;
+type ArticlesListInner = (Article, Profile, FavoriteInfo, BookmarkInfo, ReactionInfo);
+pub type ArticlesList = Vec<(ArticlesListInner, Vec<Tag>, Vec<ArticleAuthorProfile>)>;
 type ArticlesCount = i64;
 
-pub type FetchArticleBySlugOutput = (Article, Profile, FavoriteInfo, Vec<Tag>);
+pub type SingleArticle = (
+    Article,
+    Profile,
+    FavoriteInfo,
+    Vec<Tag>,
+    Vec<ArticleAuthorProfile>,
+    BookmarkInfo,
+    ReactionInfo,
+);
+pub type FetchArticleBySlugOutput = SingleArticle;
+
+type ArticlesWithAuthorQuery<'a> =
+    diesel::dsl::IntoBoxed<'a, diesel::dsl::InnerJoin<articles::table, users::table>, Pg>;
 
 pub struct FetchArticlesRepositoryInput {
-    pub tag: Option<String>,
-    pub author: Option<String>,
+    pub tags: Vec<String>,
+    pub tag_match: TagMatch,
+    pub exclude_tags: Vec<String>,
+    pub authors: Vec<String>,
+    pub exclude_authors: Vec<String>,
     pub favorited: Option<String>,
+    pub created_after: Option<NaiveDateTime>,
+    pub created_before: Option<NaiveDateTime>,
+    pub min_reading_minutes: Option<i32>,
+    pub max_reading_minutes: Option<i32>,
+    pub current_user: Option<User>,
     pub offset: i64,
     pub limit: i64,
 }
@@ -394,7 +718,13 @@ pub struct CreateArticleRepositoryInput {
     pub title: String,
     pub description: String,
     pub body: String,
+    pub reading_metadata: ReadingMetadata,
     pub tag_name_list: Option<Vec<String>>,
+    pub published_at: Option<NaiveDateTime>,
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub canonical_url: Option<String>,
+    pub cover_image: Option<String>,
     pub current_user: User,
 }
 
@@ -405,11 +735,18 @@ pub struct UpdateArticleRepositoryInput {
     pub title: Option<String>,
     pub description: Option<String>,
     pub body: Option<String>,
+    pub reading_metadata: Option<ReadingMetadata>,
+    pub meta_title: Option<Option<String>>,
+    pub meta_description: Option<Option<String>>,
+    pub canonical_url: Option<Option<String>>,
+    pub cover_image: Option<Option<String>>,
+    pub preconditions: Preconditions,
 }
 
 pub struct DeleteArticleRepositoryInput {
     pub slug: String,
     pub author_id: Uuid,
+    pub preconditions: Preconditions,
 }
 
 pub struct FetchArticleRepositoryInput {
@@ -11,6 +11,14 @@ pub struct CreateArticleInner {
     pub description: String,
     pub body: String,
     pub tag_list: Option<Vec<String>>,
+    #[serde(rename = "metaTitle")]
+    pub meta_title: Option<String>,
+    #[serde(rename = "metaDescription")]
+    pub meta_description: Option<String>,
+    #[serde(rename = "canonicalUrl")]
+    pub canonical_url: Option<String>,
+    #[serde(rename = "coverImage")]
+    pub cover_image: Option<String>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -23,4 +31,13 @@ pub struct UpdateArticleInner {
     pub title: Option<String>,
     pub description: Option<String>,
     pub body: Option<String>,
+    // An empty string clears the field.
+    #[serde(rename = "metaTitle")]
+    pub meta_title: Option<String>,
+    #[serde(rename = "metaDescription")]
+    pub meta_description: Option<String>,
+    #[serde(rename = "canonicalUrl")]
+    pub canonical_url: Option<String>,
+    #[serde(rename = "coverImage")]
+    pub cover_image: Option<String>,
 }
@@ -1,5 +1,5 @@
 use super::{
-    entities::Article,
+    entities::{Article, ReadingMetadata},
     presenters::ArticlePresenter,
     repositories::{
         ArticleRepository, CreateArticleRepositoryInput, DeleteArticleRepositoryInput,
@@ -7,25 +7,58 @@ use super::{
         UpdateArticleRepositoryInput,
     },
 };
-use crate::{app::features::user::entities::User, error::AppError};
+use crate::{
+    app::features::{
+        analytics::{entities::Reader, repositories::AnalyticsRepository},
+        series::repositories::SeriesRepository,
+        tag::entities::TagMatch,
+        translation::{
+            entities::{LocalePreference, Localization},
+            repositories::TranslationRepository,
+        },
+        user::entities::User,
+    },
+    constants::RELATED_ARTICLES_LIMIT,
+    error::AppError,
+    utils::{cache::MemoryCache, etag::Preconditions, locale, seo},
+};
 use actix_web::HttpResponse;
-use std::sync::Arc;
+use chrono::NaiveDateTime;
+use std::{net::IpAddr, sync::Arc};
 use uuid::Uuid;
 
+// Ranked related article ids keyed by article slug.
+pub type RelatedArticlesCache = MemoryCache<String, Vec<Uuid>>;
+
 #[derive(Clone)]
 pub struct ArticleUsecase {
     article_repository: Arc<dyn ArticleRepository>,
     article_presenter: Arc<dyn ArticlePresenter>,
+    analytics_repository: Arc<dyn AnalyticsRepository>,
+    series_repository: Arc<dyn SeriesRepository>,
+    translation_repository: Arc<dyn TranslationRepository>,
+    related_articles_cache: RelatedArticlesCache,
+    trusted_proxies: Vec<IpAddr>,
 }
 
 impl ArticleUsecase {
     pub fn new(
         article_repository: Arc<dyn ArticleRepository>,
         article_presenter: Arc<dyn ArticlePresenter>,
+        analytics_repository: Arc<dyn AnalyticsRepository>,
+        series_repository: Arc<dyn SeriesRepository>,
+        translation_repository: Arc<dyn TranslationRepository>,
+        related_articles_cache: RelatedArticlesCache,
+        trusted_proxies: Vec<IpAddr>,
     ) -> Self {
         Self {
             article_repository,
             article_presenter,
+            analytics_repository,
+            series_repository,
+            translation_repository,
+            related_articles_cache,
+            trusted_proxies,
         }
     }
 
@@ -36,9 +69,17 @@ impl ArticleUsecase {
         let (list, count) =
             self.article_repository
                 .fetch_articles(FetchArticlesRepositoryInput {
-                    tag: params.tag,
-                    author: params.author,
+                    tags: params.tags,
+                    tag_match: params.tag_match,
+                    exclude_tags: params.exclude_tags,
+                    authors: params.authors,
+                    exclude_authors: params.exclude_authors,
                     favorited: params.favorited,
+                    created_after: params.created_after,
+                    created_before: params.created_before,
+                    min_reading_minutes: params.min_reading_minutes,
+                    max_reading_minutes: params.max_reading_minutes,
+                    current_user: params.current_user,
                     offset: params.offset,
                     limit: params.limit,
                 })?;
@@ -46,14 +87,101 @@ impl ArticleUsecase {
         Ok(res)
     }
 
+    // The slug may be the one of a translation, which is then shown unless the
+    // reader asks for another locale with `?lang=`. As the original text has no
+    // translation of its own, a `?lang=` no translation matches asks for it.
     pub fn fetch_article_by_slug(
         &self,
         article_title_slug: String,
+        current_user: Option<User>,
+        reader: Reader,
+        preference: LocalePreference,
+        preconditions: Preconditions,
     ) -> Result<HttpResponse, AppError> {
-        let result = self
+        let (mut result, slug_locale) = match self
             .article_repository
-            .fetch_article_by_slug(article_title_slug)?;
-        let res = self.article_presenter.to_single_json(result);
+            .fetch_article_by_slug(article_title_slug.clone(), &current_user)
+        {
+            Ok(result) => (result, None),
+            Err(AppError::NotFound(_)) => {
+                let (translation, article) = self
+                    .translation_repository
+                    .fetch_translation_by_slug(&article_title_slug)?;
+                let result = self
+                    .article_repository
+                    .fetch_article_by_slug(article.slug, &current_user)?;
+                (result, Some(translation.locale))
+            }
+            Err(err) => return Err(err),
+        };
+        if let Some(reader_key) = reader.key(&self.trusted_proxies) {
+            if let Err(err) =
+                self.analytics_repository
+                    .record_view(&result.0.id, &reader, &reader_key)
+            {
+                log::warn!("failed to record view of article {}: {}", result.0.id, err);
+            }
+        }
+        let series = self.series_repository.fetch_navigation(&result.0.id)?;
+
+        let translations = self
+            .translation_repository
+            .fetch_translations_by_article_id(&result.0.id)?;
+        let locales = translations
+            .iter()
+            .map(|translation| translation.locale.clone())
+            .collect::<Vec<_>>();
+        let preferred = match preference.lang {
+            Some(lang) => vec![lang],
+            None => slug_locale
+                .into_iter()
+                .chain(preference.accept_language)
+                .collect::<Vec<_>>(),
+        };
+        let selected = locale::best_match(&preferred, &locales).cloned();
+        let translation = translations
+            .iter()
+            .find(|translation| Some(&translation.locale) == selected.as_ref());
+        if let Some(translation) = translation {
+            result.0 = translation.apply_to(result.0);
+        }
+
+        let res = self.article_presenter.to_detail_json(
+            result,
+            series,
+            Localization {
+                locale: selected,
+                translation_slug: translation.map(|translation| translation.slug.clone()),
+                locales,
+            },
+            &preconditions,
+        );
+        Ok(res)
+    }
+
+    pub fn fetch_related_articles(
+        &self,
+        article_title_slug: String,
+        current_user: Option<User>,
+        limit: usize,
+    ) -> Result<HttpResponse, AppError> {
+        let ids = match self.related_articles_cache.get(&article_title_slug) {
+            Some(ids) => ids,
+            None => {
+                let ids = self
+                    .article_repository
+                    .fetch_related_article_ids(&article_title_slug, RELATED_ARTICLES_LIMIT)?;
+                self.related_articles_cache
+                    .insert(article_title_slug, ids.clone());
+                ids
+            }
+        };
+        let ids = ids.into_iter().take(limit).collect::<Vec<_>>();
+        let list = self
+            .article_repository
+            .fetch_articles_by_ids(&ids, &current_user)?;
+        let count = list.len() as i64;
+        let res = self.article_presenter.to_multi_json(list, count);
         Ok(res)
     }
 
@@ -78,17 +206,30 @@ impl ArticleUsecase {
         &self,
         params: CreateArticleUsecaseInput,
     ) -> Result<HttpResponse, AppError> {
+        params.current_user.ensure_not_held()?;
         let slug = Article::convert_title_to_slug(&params.title);
+        let reading_metadata = ReadingMetadata::from_body(&params.body);
+        let canonical_url = seo::non_blank(params.canonical_url);
+        let cover_image = seo::non_blank(params.cover_image);
+        seo::validate_url("canonicalUrl", canonical_url.as_deref(), false)?;
+        seo::validate_url("coverImage", cover_image.as_deref(), true)?;
         let result = self
             .article_repository
             .create_article(CreateArticleRepositoryInput {
                 body: params.body,
+                reading_metadata,
                 current_user: params.current_user,
                 description: params.description,
                 tag_name_list: params.tag_name_list,
                 title: params.title,
                 slug,
+                published_at: None,
+                meta_title: seo::non_blank(params.meta_title),
+                meta_description: seo::non_blank(params.meta_description),
+                canonical_url,
+                cover_image,
             })?;
+        self.related_articles_cache.clear();
         let res = self.article_presenter.to_single_json(result);
         Ok(res)
     }
@@ -97,10 +238,20 @@ impl ArticleUsecase {
         &self,
         input: UpdateArticleUsecaseInput,
     ) -> Result<HttpResponse, AppError> {
+        input.current_user.ensure_not_held()?;
         let slug = input
             .title
             .as_ref()
             .map(|title| Article::convert_title_to_slug(title));
+        let reading_metadata = input.body.as_deref().map(ReadingMetadata::from_body);
+        let canonical_url = input.canonical_url.map(|url| seo::non_blank(Some(url)));
+        let cover_image = input.cover_image.map(|url| seo::non_blank(Some(url)));
+        seo::validate_url(
+            "canonicalUrl",
+            canonical_url.clone().flatten().as_deref(),
+            false,
+        )?;
+        seo::validate_url("coverImage", cover_image.clone().flatten().as_deref(), true)?;
         let result = self
             .article_repository
             .update_article(UpdateArticleRepositoryInput {
@@ -110,7 +261,16 @@ impl ArticleUsecase {
                 title: input.title,
                 description: input.description,
                 body: input.body,
+                reading_metadata,
+                meta_title: input.meta_title.map(|title| seo::non_blank(Some(title))),
+                meta_description: input
+                    .meta_description
+                    .map(|description| seo::non_blank(Some(description))),
+                canonical_url,
+                cover_image,
+                preconditions: input.preconditions,
             })?;
+        self.related_articles_cache.clear();
         let res = self.article_presenter.to_single_json(result);
         Ok(res)
     }
@@ -123,16 +283,26 @@ impl ArticleUsecase {
             .delete_article(DeleteArticleRepositoryInput {
                 slug: input.slug,
                 author_id: input.author_id,
+                preconditions: input.preconditions,
             })?;
+        self.related_articles_cache.clear();
         let res = self.article_presenter.to_http_res();
         Ok(res)
     }
 }
 
 pub struct FetchArticlesUsecaseInput {
-    pub tag: Option<String>,
-    pub author: Option<String>,
+    pub tags: Vec<String>,
+    pub tag_match: TagMatch,
+    pub exclude_tags: Vec<String>,
+    pub authors: Vec<String>,
+    pub exclude_authors: Vec<String>,
     pub favorited: Option<String>,
+    pub created_after: Option<NaiveDateTime>,
+    pub created_before: Option<NaiveDateTime>,
+    pub min_reading_minutes: Option<i32>,
+    pub max_reading_minutes: Option<i32>,
+    pub current_user: Option<User>,
     pub offset: i64,
     pub limit: i64,
 }
@@ -142,6 +312,10 @@ pub struct CreateArticleUsecaseInput {
     pub description: String,
     pub body: String,
     pub tag_name_list: Option<Vec<String>>,
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub canonical_url: Option<String>,
+    pub cover_image: Option<String>,
     pub current_user: User,
 }
 
@@ -151,9 +325,15 @@ pub struct UpdateArticleUsecaseInput {
     pub title: Option<String>,
     pub description: Option<String>,
     pub body: Option<String>,
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub canonical_url: Option<String>,
+    pub cover_image: Option<String>,
+    pub preconditions: Preconditions,
 }
 
 pub struct DeleteArticleUsecaseInput {
     pub slug: String,
     pub author_id: Uuid,
+    pub preconditions: Preconditions,
 }
@@ -0,0 +1,93 @@
+use crate::{error::AppError, schema::blocks};
+use diesel::{dsl::Eq, prelude::*};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+// A block keeps the blocked user from following the blocker or commenting on
+// their articles, and keeps both out of each other's feeds.
+pub struct Block;
+
+type WithBlocker<T> = Eq<blocks::blocker_id, T>;
+type WithBlocked<T> = Eq<blocks::blocked_id, T>;
+
+impl Block {
+    fn with_blocker(blocker_id: &Uuid) -> WithBlocker<&Uuid> {
+        blocks::blocker_id.eq(blocker_id)
+    }
+    fn with_blocked(blocked_id: &Uuid) -> WithBlocked<&Uuid> {
+        blocks::blocked_id.eq(blocked_id)
+    }
+}
+
+impl Block {
+    // Blocking twice is a no-op.
+    pub fn create(conn: &mut PgConnection, params: &CreateBlock) -> Result<(), AppError> {
+        diesel::insert_into(blocks::table)
+            .values(params)
+            .on_conflict_do_nothing()
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub fn delete(
+        conn: &mut PgConnection,
+        blocker_id: &Uuid,
+        blocked_id: &Uuid,
+    ) -> Result<(), AppError> {
+        let t = blocks::table
+            .filter(Self::with_blocker(blocker_id))
+            .filter(Self::with_blocked(blocked_id));
+        diesel::delete(t).execute(conn)?;
+        Ok(())
+    }
+
+    // Whether any of the blockers blocks the user.
+    pub fn is_blocked_by_any(
+        conn: &mut PgConnection,
+        blocker_ids: &[Uuid],
+        blocked_id: &Uuid,
+    ) -> Result<bool, AppError> {
+        let t = blocks::table
+            .filter(blocks::blocker_id.eq_any(blocker_ids))
+            .filter(Self::with_blocked(blocked_id));
+        let exists = diesel::select(diesel::dsl::exists(t)).get_result::<bool>(conn)?;
+        Ok(exists)
+    }
+
+    // Which of the blockers block the user.
+    pub fn fetch_blocker_ids(
+        conn: &mut PgConnection,
+        blocker_ids: &[Uuid],
+        blocked_id: &Uuid,
+    ) -> Result<HashSet<Uuid>, AppError> {
+        let ids = blocks::table
+            .filter(blocks::blocker_id.eq_any(blocker_ids))
+            .filter(Self::with_blocked(blocked_id))
+            .select(blocks::blocker_id)
+            .load::<Uuid>(conn)?;
+        Ok(ids.into_iter().collect())
+    }
+
+    // The users the user blocks together with the ones blocking them.
+    pub fn fetch_related_ids(
+        conn: &mut PgConnection,
+        user_id: &Uuid,
+    ) -> Result<HashSet<Uuid>, AppError> {
+        let blocked_ids = blocks::table
+            .filter(Self::with_blocker(user_id))
+            .select(blocks::blocked_id)
+            .load::<Uuid>(conn)?;
+        let blocker_ids = blocks::table
+            .filter(Self::with_blocked(user_id))
+            .select(blocks::blocker_id)
+            .load::<Uuid>(conn)?;
+        Ok(blocked_ids.into_iter().chain(blocker_ids).collect())
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = blocks)]
+pub struct CreateBlock {
+    pub blocker_id: Uuid,
+    pub blocked_id: Uuid,
+}
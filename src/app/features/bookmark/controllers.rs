@@ -0,0 +1,74 @@
+use actix_web::{web, HttpRequest};
+use serde::Deserialize;
+
+use crate::{
+    app::drivers::middlewares::{auth, state::AppState},
+    constants::BOOKMARKS_PAGE_LIMIT,
+    utils::api::ApiResponse,
+};
+
+use super::{requests, usecases::FetchBookmarksUsecaseInput};
+
+type ArticleTitleSlug = String;
+
+#[derive(Deserialize)]
+pub struct BookmarksListQueryParameter {
+    folder: Option<String>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+pub async fn index(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    params: web::Query<BookmarksListQueryParameter>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let offset = params.offset.unwrap_or(0).max(0);
+    let limit = params.limit.unwrap_or(20).clamp(1, BOOKMARKS_PAGE_LIMIT);
+    state
+        .di_container
+        .bookmark_usecase
+        .fetch_bookmarks(FetchBookmarksUsecaseInput {
+            current_user,
+            folder: params.folder.clone(),
+            offset,
+            limit,
+        })
+}
+
+pub async fn folders(state: web::Data<AppState>, req: HttpRequest) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    state
+        .di_container
+        .bookmark_usecase
+        .fetch_folders(&current_user)
+}
+
+pub async fn bookmark(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ArticleTitleSlug>,
+    form: Option<web::Json<requests::BookmarkRequest>>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let article_title_slug = path.into_inner();
+    let folder = form.and_then(|form| form.into_inner().bookmark.folder);
+    state
+        .di_container
+        .bookmark_usecase
+        .bookmark_article(current_user, article_title_slug, folder)
+}
+
+pub async fn unbookmark(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ArticleTitleSlug>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let article_title_slug = path.into_inner();
+    state
+        .di_container
+        .bookmark_usecase
+        .unbookmark_article(current_user, article_title_slug)
+}
@@ -0,0 +1,171 @@
+use chrono::NaiveDateTime;
+use diesel::dsl::{Eq, Filter, IsNull, Select};
+use diesel::pg::upsert::excluded;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::{
+    app::features::{article::entities::Article, user::entities::User},
+    error::AppError,
+    schema::{articles, bookmarks},
+};
+
+type WithUserId<T> = Eq<bookmarks::user_id, T>;
+type WithArticleId<T> = Eq<bookmarks::article_id, T>;
+type LiveArticleIds = Select<Filter<articles::table, IsNull<articles::deleted_at>>, articles::id>;
+
+#[derive(Identifiable, Queryable, Associations, Serialize, Deserialize, Debug, Clone)]
+#[diesel(belongs_to(Article, foreign_key = article_id))]
+#[diesel(belongs_to(User, foreign_key = user_id))]
+#[diesel(table_name = bookmarks)]
+pub struct Bookmark {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub article_id: Uuid,
+    pub folder: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl Bookmark {
+    fn with_user_id(user_id: &Uuid) -> WithUserId<&Uuid> {
+        bookmarks::user_id.eq(user_id)
+    }
+
+    fn with_article_id(article_id: &Uuid) -> WithArticleId<&Uuid> {
+        bookmarks::article_id.eq(article_id)
+    }
+
+    // Bookmarks of trashed articles stay around but are hidden until restored.
+    fn live_article_ids() -> LiveArticleIds {
+        articles::table
+            .filter(Article::not_deleted())
+            .select(articles::id)
+    }
+}
+
+impl Bookmark {
+    // Bookmarking an already bookmarked article moves it to the given folder.
+    pub fn upsert(conn: &mut PgConnection, record: &CreateBookmark) -> Result<Self, AppError> {
+        let item = diesel::insert_into(bookmarks::table)
+            .values(record)
+            .on_conflict((bookmarks::user_id, bookmarks::article_id))
+            .do_update()
+            .set(bookmarks::folder.eq(excluded(bookmarks::folder)))
+            .get_result::<Self>(conn)?;
+        Ok(item)
+    }
+
+    pub fn delete(
+        conn: &mut PgConnection,
+        user_id: &Uuid,
+        article_id: &Uuid,
+    ) -> Result<usize, AppError> {
+        let t = bookmarks::table
+            .filter(Self::with_user_id(user_id))
+            .filter(Self::with_article_id(article_id));
+        let item = diesel::delete(t).execute(conn)?;
+        Ok(item)
+    }
+
+    pub fn fetch_list(
+        conn: &mut PgConnection,
+        params: &FetchBookmarks,
+    ) -> Result<(Vec<Self>, i64), AppError> {
+        let create_query = || {
+            let mut query = bookmarks::table
+                .filter(Self::with_user_id(params.user_id))
+                .filter(bookmarks::article_id.eq_any(Self::live_article_ids()))
+                .into_boxed();
+            if let Some(folder) = params.folder {
+                query = query.filter(bookmarks::folder.eq(folder));
+            }
+            query
+        };
+        let list = create_query()
+            .order(bookmarks::created_at.desc())
+            .offset(params.offset)
+            .limit(params.limit)
+            .load::<Self>(conn)?;
+        let count = create_query().count().get_result::<i64>(conn)?;
+        Ok((list, count))
+    }
+
+    pub fn fetch_folders(
+        conn: &mut PgConnection,
+        user_id: &Uuid,
+    ) -> Result<Vec<BookmarkFolder>, AppError> {
+        let list = bookmarks::table
+            .filter(Self::with_user_id(user_id))
+            .filter(bookmarks::article_id.eq_any(Self::live_article_ids()))
+            .filter(bookmarks::folder.is_not_null())
+            .group_by(bookmarks::folder)
+            .select((bookmarks::folder, diesel::dsl::count(bookmarks::id)))
+            .order(bookmarks::folder.asc())
+            .load::<(Option<String>, i64)>(conn)?;
+        let folders = list
+            .into_iter()
+            .filter_map(|(name, bookmarks_count)| {
+                name.map(|name| BookmarkFolder {
+                    name,
+                    bookmarks_count,
+                })
+            })
+            .collect();
+        Ok(folders)
+    }
+
+    pub fn is_bookmarked(
+        conn: &mut PgConnection,
+        user_id: &Uuid,
+        article_id: &Uuid,
+    ) -> Result<bool, AppError> {
+        let t = bookmarks::table
+            .filter(Self::with_user_id(user_id))
+            .filter(Self::with_article_id(article_id));
+        let exists = diesel::select(diesel::dsl::exists(t)).get_result::<bool>(conn)?;
+        Ok(exists)
+    }
+
+    // Which of the given articles the user has bookmarked, in a single query.
+    pub fn fetch_bookmarked_article_ids(
+        conn: &mut PgConnection,
+        user_id: &Uuid,
+        article_ids: &[Uuid],
+    ) -> Result<HashSet<Uuid>, AppError> {
+        let ids = bookmarks::table
+            .filter(Self::with_user_id(user_id))
+            .filter(bookmarks::article_id.eq_any(article_ids))
+            .select(bookmarks::article_id)
+            .load::<Uuid>(conn)?;
+        Ok(ids.into_iter().collect())
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = bookmarks)]
+pub struct CreateBookmark {
+    pub user_id: Uuid,
+    pub article_id: Uuid,
+    pub folder: Option<String>,
+}
+
+pub struct FetchBookmarks<'a> {
+    pub user_id: &'a Uuid,
+    pub folder: Option<&'a str>,
+    pub offset: i64,
+    pub limit: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct BookmarkFolder {
+    pub name: String,
+    pub bookmarks_count: i64,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct BookmarkInfo {
+    pub is_bookmarked: bool,
+}
@@ -0,0 +1,6 @@
+pub mod controllers;
+pub mod entities;
+pub mod presenters;
+pub mod repositories;
+pub mod requests;
+pub mod usecases;
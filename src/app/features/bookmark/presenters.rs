@@ -0,0 +1,104 @@
+use actix_web::HttpResponse;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::features::article::{
+        presenters::{ArticleContent, SingleArticleResponse},
+        repositories::SingleArticle,
+    },
+    utils::date::Iso8601,
+};
+
+use super::entities::{Bookmark, BookmarkFolder};
+
+pub type BookmarksList = Vec<(Bookmark, SingleArticle)>;
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultipleBookmarksResponse {
+    pub bookmarks: Vec<BookmarkContent>,
+    pub bookmarks_count: i64,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookmarkContent {
+    pub folder: Option<String>,
+    pub created_at: Iso8601,
+    pub article: ArticleContent,
+}
+
+impl From<(BookmarksList, i64)> for MultipleBookmarksResponse {
+    fn from((list, bookmarks_count): (BookmarksList, i64)) -> Self {
+        let bookmarks = list
+            .into_iter()
+            .map(|(bookmark, article)| BookmarkContent {
+                folder: bookmark.folder,
+                created_at: Iso8601(bookmark.created_at),
+                article: ArticleContent::from(article),
+            })
+            .collect();
+        Self {
+            bookmarks,
+            bookmarks_count,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct BookmarkFoldersResponse {
+    pub folders: Vec<BookmarkFolderContent>,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookmarkFolderContent {
+    pub name: String,
+    pub bookmarks_count: i64,
+}
+
+impl From<Vec<BookmarkFolder>> for BookmarkFoldersResponse {
+    fn from(list: Vec<BookmarkFolder>) -> Self {
+        Self {
+            folders: list
+                .into_iter()
+                .map(|folder| BookmarkFolderContent {
+                    name: folder.name,
+                    bookmarks_count: folder.bookmarks_count,
+                })
+                .collect(),
+        }
+    }
+}
+
+pub trait BookmarkPresenter: Send + Sync + 'static {
+    fn to_single_json(&self, item: SingleArticle) -> HttpResponse;
+    fn to_multi_json(&self, list: BookmarksList, count: i64) -> HttpResponse;
+    fn to_folders_json(&self, list: Vec<BookmarkFolder>) -> HttpResponse;
+}
+
+#[derive(Clone)]
+pub struct BookmarkPresenterImpl {}
+
+impl BookmarkPresenterImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl BookmarkPresenter for BookmarkPresenterImpl {
+    fn to_single_json(&self, item: SingleArticle) -> HttpResponse {
+        let res = SingleArticleResponse::from(item);
+        HttpResponse::Ok().json(res)
+    }
+
+    fn to_multi_json(&self, list: BookmarksList, count: i64) -> HttpResponse {
+        let res = MultipleBookmarksResponse::from((list, count));
+        HttpResponse::Ok().json(res)
+    }
+
+    fn to_folders_json(&self, list: Vec<BookmarkFolder>) -> HttpResponse {
+        let res = BookmarkFoldersResponse::from(list);
+        HttpResponse::Ok().json(res)
+    }
+}
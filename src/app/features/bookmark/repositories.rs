@@ -0,0 +1,103 @@
+use serde_json::json;
+
+use crate::{
+    app::features::{article::entities::Article, user::entities::User},
+    error::AppError,
+    utils::db::DbPool,
+};
+
+use super::entities::{Bookmark, BookmarkFolder, CreateBookmark, FetchBookmarks};
+
+pub trait BookmarkRepository: Send + Sync + 'static {
+    fn bookmark_article(&self, input: BookmarkArticleRepositoryInput) -> Result<Article, AppError>;
+    fn unbookmark_article(
+        &self,
+        user: &User,
+        article_title_slug: &str,
+    ) -> Result<Article, AppError>;
+    fn fetch_bookmarks(
+        &self,
+        input: &FetchBookmarksRepositoryInput,
+    ) -> Result<(Vec<Bookmark>, i64), AppError>;
+    fn fetch_folders(&self, user: &User) -> Result<Vec<BookmarkFolder>, AppError>;
+}
+
+#[derive(Clone)]
+pub struct BookmarkRepositoryImpl {
+    pool: DbPool,
+}
+
+impl BookmarkRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl BookmarkRepository for BookmarkRepositoryImpl {
+    fn bookmark_article(&self, input: BookmarkArticleRepositoryInput) -> Result<Article, AppError> {
+        let folder = match input.folder.map(|folder| folder.trim().to_owned()) {
+            Some(folder) if folder.is_empty() => {
+                return Err(AppError::UnprocessableEntity(
+                    json!({"error": "A bookmark folder name can't be blank."}),
+                ))
+            }
+            folder => folder,
+        };
+        let conn = &mut self.pool.get()?;
+        let article = Article::fetch_by_slug(conn, &input.article_title_slug)?;
+        Bookmark::upsert(
+            conn,
+            &CreateBookmark {
+                user_id: input.current_user.id,
+                article_id: article.id,
+                folder,
+            },
+        )?;
+        Ok(article)
+    }
+
+    fn unbookmark_article(
+        &self,
+        user: &User,
+        article_title_slug: &str,
+    ) -> Result<Article, AppError> {
+        let conn = &mut self.pool.get()?;
+        let article = Article::fetch_by_slug(conn, article_title_slug)?;
+        Bookmark::delete(conn, &user.id, &article.id)?;
+        Ok(article)
+    }
+
+    fn fetch_bookmarks(
+        &self,
+        input: &FetchBookmarksRepositoryInput,
+    ) -> Result<(Vec<Bookmark>, i64), AppError> {
+        let conn = &mut self.pool.get()?;
+        Bookmark::fetch_list(
+            conn,
+            &FetchBookmarks {
+                user_id: &input.current_user.id,
+                folder: input.folder.as_deref(),
+                offset: input.offset,
+                limit: input.limit,
+            },
+        )
+    }
+
+    fn fetch_folders(&self, user: &User) -> Result<Vec<BookmarkFolder>, AppError> {
+        let conn = &mut self.pool.get()?;
+        Bookmark::fetch_folders(conn, &user.id)
+    }
+}
+
+pub struct BookmarkArticleRepositoryInput {
+    pub current_user: User,
+    pub article_title_slug: String,
+    pub folder: Option<String>,
+}
+
+pub struct FetchBookmarksRepositoryInput {
+    pub current_user: User,
+    pub folder: Option<String>,
+    pub offset: i64,
+    pub limit: i64,
+}
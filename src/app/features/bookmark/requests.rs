@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct BookmarkRequest {
+    pub bookmark: BookmarkInner,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct BookmarkInner {
+    pub folder: Option<String>,
+}
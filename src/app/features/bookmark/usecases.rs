@@ -0,0 +1,146 @@
+use std::{collections::HashMap, sync::Arc};
+
+use actix_web::HttpResponse;
+
+use crate::{
+    app::features::{
+        article::repositories::{ArticleRepository, FetchArticleRepositoryInput},
+        user::entities::User,
+    },
+    error::AppError,
+};
+
+use super::{
+    presenters::BookmarkPresenter,
+    repositories::{
+        BookmarkArticleRepositoryInput, BookmarkRepository, FetchBookmarksRepositoryInput,
+    },
+};
+
+#[derive(Clone)]
+pub struct BookmarkUsecase {
+    bookmark_repository: Arc<dyn BookmarkRepository>,
+    bookmark_presenter: Arc<dyn BookmarkPresenter>,
+    article_repository: Arc<dyn ArticleRepository>,
+}
+
+impl BookmarkUsecase {
+    pub fn new(
+        bookmark_repository: Arc<dyn BookmarkRepository>,
+        bookmark_presenter: Arc<dyn BookmarkPresenter>,
+        article_repository: Arc<dyn ArticleRepository>,
+    ) -> Self {
+        Self {
+            bookmark_repository,
+            bookmark_presenter,
+            article_repository,
+        }
+    }
+
+    pub fn bookmark_article(
+        &self,
+        current_user: User,
+        article_title_slug: String,
+        folder: Option<String>,
+    ) -> Result<HttpResponse, AppError> {
+        let article =
+            self.bookmark_repository
+                .bookmark_article(BookmarkArticleRepositoryInput {
+                    current_user: current_user.clone(),
+                    article_title_slug,
+                    folder,
+                })?;
+        let result = self
+            .article_repository
+            .fetch_article(&FetchArticleRepositoryInput {
+                article_id: article.id,
+                current_user,
+            })?;
+        let res = self.bookmark_presenter.to_single_json(result);
+        Ok(res)
+    }
+
+    pub fn unbookmark_article(
+        &self,
+        current_user: User,
+        article_title_slug: String,
+    ) -> Result<HttpResponse, AppError> {
+        let article = self
+            .bookmark_repository
+            .unbookmark_article(&current_user, &article_title_slug)?;
+        let result = self
+            .article_repository
+            .fetch_article(&FetchArticleRepositoryInput {
+                article_id: article.id,
+                current_user,
+            })?;
+        let res = self.bookmark_presenter.to_single_json(result);
+        Ok(res)
+    }
+
+    pub fn fetch_bookmarks(
+        &self,
+        input: FetchBookmarksUsecaseInput,
+    ) -> Result<HttpResponse, AppError> {
+        let (bookmarks, count) =
+            self.bookmark_repository
+                .fetch_bookmarks(&FetchBookmarksRepositoryInput {
+                    current_user: input.current_user.clone(),
+                    folder: input.folder,
+                    offset: input.offset,
+                    limit: input.limit,
+                })?;
+        let ids = bookmarks
+            .iter()
+            .map(|bookmark| bookmark.article_id)
+            .collect::<Vec<_>>();
+        let mut articles_by_id = self
+            .article_repository
+            .fetch_articles_by_ids(&ids, &Some(input.current_user))?
+            .into_iter()
+            .map(
+                |(
+                    (article, profile, favorite_info, bookmark_info, reaction_info),
+                    tag_list,
+                    authors,
+                )| {
+                    (
+                        article.id,
+                        (
+                            article,
+                            profile,
+                            favorite_info,
+                            tag_list,
+                            authors,
+                            bookmark_info,
+                            reaction_info,
+                        ),
+                    )
+                },
+            )
+            .collect::<HashMap<_, _>>();
+        let list = bookmarks
+            .into_iter()
+            .filter_map(|bookmark| {
+                articles_by_id
+                    .remove(&bookmark.article_id)
+                    .map(|article| (bookmark, article))
+            })
+            .collect::<Vec<_>>();
+        let res = self.bookmark_presenter.to_multi_json(list, count);
+        Ok(res)
+    }
+
+    pub fn fetch_folders(&self, current_user: &User) -> Result<HttpResponse, AppError> {
+        let list = self.bookmark_repository.fetch_folders(current_user)?;
+        let res = self.bookmark_presenter.to_folders_json(list);
+        Ok(res)
+    }
+}
+
+pub struct FetchBookmarksUsecaseInput {
+    pub current_user: User,
+    pub folder: Option<String>,
+    pub offset: i64,
+    pub limit: i64,
+}
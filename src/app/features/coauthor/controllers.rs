@@ -0,0 +1,53 @@
+use actix_web::{web, HttpRequest};
+
+use crate::{
+    app::drivers::middlewares::{auth, state::AppState},
+    utils::api::ApiResponse,
+};
+
+use super::{entities::AuthorRole, requests};
+
+type ArticleTitleSlug = String;
+type UsernameSlug = String;
+
+pub async fn index(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ArticleTitleSlug>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req).ok();
+    let article_title_slug = path.into_inner();
+    state
+        .di_container
+        .coauthor_usecase
+        .fetch_authors(&article_title_slug, &current_user)
+}
+
+pub async fn create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ArticleTitleSlug>,
+    form: web::Json<requests::AddAuthorRequest>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let article_title_slug = path.into_inner();
+    state.di_container.coauthor_usecase.add_author(
+        current_user,
+        article_title_slug,
+        form.author.username.clone(),
+        form.author.role.unwrap_or(AuthorRole::Editor),
+    )
+}
+
+pub async fn delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(ArticleTitleSlug, UsernameSlug)>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let (article_title_slug, username) = path.into_inner();
+    state
+        .di_container
+        .coauthor_usecase
+        .remove_author(current_user, article_title_slug, username)
+}
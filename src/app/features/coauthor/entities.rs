@@ -0,0 +1,200 @@
+use chrono::NaiveDateTime;
+use diesel::dsl::{Eq, Filter, Select};
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    app::features::{
+        article::entities::Article, follow::entities::Follow, profile::entities::Profile,
+        user::entities::User,
+    },
+    error::AppError,
+    schema::{article_authors, users},
+};
+
+type WithArticleId<T> = Eq<article_authors::article_id, T>;
+type WithUserId<T> = Eq<article_authors::user_id, T>;
+type WithRole<T> = Eq<article_authors::role, T>;
+type ArticleIdsByUserId<T> =
+    Select<Filter<article_authors::table, WithUserId<T>>, article_authors::article_id>;
+type ArticleIdsByUserIdAndRole<T, R> = Select<
+    Filter<Filter<article_authors::table, WithUserId<T>>, WithRole<R>>,
+    article_authors::article_id,
+>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuthorRole {
+    Owner,
+    Editor,
+}
+
+impl AuthorRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuthorRole::Owner => "owner",
+            AuthorRole::Editor => "editor",
+        }
+    }
+
+    pub fn parse(role: &str) -> Option<Self> {
+        match role {
+            "owner" => Some(AuthorRole::Owner),
+            "editor" => Some(AuthorRole::Editor),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Identifiable, Queryable, Associations, Serialize, Deserialize, Debug, Clone)]
+#[diesel(belongs_to(Article, foreign_key = article_id))]
+#[diesel(belongs_to(User, foreign_key = user_id))]
+#[diesel(primary_key(article_id, user_id))]
+#[diesel(table_name = article_authors)]
+pub struct ArticleAuthor {
+    pub article_id: Uuid,
+    pub user_id: Uuid,
+    pub role: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl ArticleAuthor {
+    fn with_article_id(article_id: &Uuid) -> WithArticleId<&Uuid> {
+        article_authors::article_id.eq(article_id)
+    }
+
+    fn with_user_id(user_id: &Uuid) -> WithUserId<&Uuid> {
+        article_authors::user_id.eq(user_id)
+    }
+
+    // Subquery of the articles a user may edit, whatever their role.
+    pub fn editable_article_ids(user_id: &Uuid) -> ArticleIdsByUserId<&Uuid> {
+        article_authors::table
+            .filter(Self::with_user_id(user_id))
+            .select(article_authors::article_id)
+    }
+
+    // Subquery of the articles a user owns.
+    pub fn owned_article_ids(user_id: &Uuid) -> ArticleIdsByUserIdAndRole<&Uuid, &'static str> {
+        article_authors::table
+            .filter(Self::with_user_id(user_id))
+            .filter(article_authors::role.eq(AuthorRole::Owner.as_str()))
+            .select(article_authors::article_id)
+    }
+
+    pub fn role(&self) -> AuthorRole {
+        AuthorRole::parse(&self.role).unwrap_or(AuthorRole::Editor)
+    }
+}
+
+impl ArticleAuthor {
+    pub fn upsert(conn: &mut PgConnection, record: &CreateArticleAuthor) -> Result<Self, AppError> {
+        let item = diesel::insert_into(article_authors::table)
+            .values(record)
+            .on_conflict((article_authors::article_id, article_authors::user_id))
+            .do_update()
+            .set(article_authors::role.eq(record.role))
+            .get_result::<Self>(conn)?;
+        Ok(item)
+    }
+
+    pub fn delete(
+        conn: &mut PgConnection,
+        article_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<(), AppError> {
+        let t = article_authors::table
+            .filter(Self::with_article_id(article_id))
+            .filter(Self::with_user_id(user_id));
+        diesel::delete(t).execute(conn)?;
+        Ok(())
+    }
+
+    pub fn fetch_role(
+        conn: &mut PgConnection,
+        article_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<Option<AuthorRole>, AppError> {
+        let t = article_authors::table
+            .filter(Self::with_article_id(article_id))
+            .filter(Self::with_user_id(user_id));
+        let item = t.first::<Self>(conn).optional()?;
+        Ok(item.map(|author| author.role()))
+    }
+
+    pub fn fetch_user_ids(
+        conn: &mut PgConnection,
+        article_id: &Uuid,
+    ) -> Result<Vec<Uuid>, AppError> {
+        let ids = article_authors::table
+            .filter(Self::with_article_id(article_id))
+            .select(article_authors::user_id)
+            .load::<Uuid>(conn)?;
+        Ok(ids)
+    }
+
+    // Authors of every given article, in the same order as `article_list`. Whether
+    // the current user follows them is looked up once for the whole list.
+    pub fn fetch_profiles_grouped(
+        conn: &mut PgConnection,
+        article_list: &[Article],
+        current_user: &Option<User>,
+    ) -> Result<Vec<Vec<ArticleAuthorProfile>>, AppError> {
+        let list = Self::belonging_to(article_list)
+            .inner_join(users::table)
+            .order(article_authors::created_at.asc())
+            .load::<(Self, User)>(conn)?;
+        let followed_ids = match current_user {
+            Some(current_user) => {
+                let user_ids = list.iter().map(|(_, user)| user.id).collect::<Vec<_>>();
+                Follow::fetch_followed_ids(conn, &current_user.id, &user_ids)?
+            }
+            None => Default::default(),
+        };
+        let grouped = list
+            .grouped_by(article_list)
+            .into_iter()
+            .map(|authors| {
+                authors
+                    .into_iter()
+                    .map(|(author, user)| ArticleAuthorProfile {
+                        role: author.role(),
+                        profile: Profile {
+                            following: followed_ids.contains(&user.id),
+                            username: user.username,
+                            bio: user.bio,
+                            image: user.image,
+                        },
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(grouped)
+    }
+
+    pub fn fetch_profiles(
+        conn: &mut PgConnection,
+        article: &Article,
+        current_user: &Option<User>,
+    ) -> Result<Vec<ArticleAuthorProfile>, AppError> {
+        let grouped =
+            Self::fetch_profiles_grouped(conn, std::slice::from_ref(article), current_user)?;
+        Ok(grouped.into_iter().next().unwrap_or_default())
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = article_authors)]
+pub struct CreateArticleAuthor {
+    pub article_id: Uuid,
+    pub user_id: Uuid,
+    pub role: &'static str,
+}
+
+#[derive(Clone, Debug)]
+pub struct ArticleAuthorProfile {
+    pub profile: Profile,
+    pub role: AuthorRole,
+}
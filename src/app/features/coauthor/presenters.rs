@@ -0,0 +1,58 @@
+use actix_web::HttpResponse;
+use serde::{Deserialize, Serialize};
+
+use super::entities::{ArticleAuthorProfile, AuthorRole};
+
+#[derive(Deserialize, Serialize)]
+pub struct MultipleAuthorsResponse {
+    pub authors: Vec<ArticleAuthorContent>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ArticleAuthorContent {
+    pub username: String,
+    pub bio: Option<String>,
+    pub image: Option<String>,
+    pub following: bool,
+    pub role: AuthorRole,
+}
+
+impl From<ArticleAuthorProfile> for ArticleAuthorContent {
+    fn from(author: ArticleAuthorProfile) -> Self {
+        Self {
+            username: author.profile.username,
+            bio: author.profile.bio,
+            image: author.profile.image,
+            following: author.profile.following,
+            role: author.role,
+        }
+    }
+}
+
+impl From<Vec<ArticleAuthorProfile>> for MultipleAuthorsResponse {
+    fn from(list: Vec<ArticleAuthorProfile>) -> Self {
+        Self {
+            authors: list.into_iter().map(ArticleAuthorContent::from).collect(),
+        }
+    }
+}
+
+pub trait CoauthorPresenter: Send + Sync + 'static {
+    fn to_multi_json(&self, list: Vec<ArticleAuthorProfile>) -> HttpResponse;
+}
+
+#[derive(Clone)]
+pub struct CoauthorPresenterImpl {}
+
+impl CoauthorPresenterImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl CoauthorPresenter for CoauthorPresenterImpl {
+    fn to_multi_json(&self, list: Vec<ArticleAuthorProfile>) -> HttpResponse {
+        let res = MultipleAuthorsResponse::from(list);
+        HttpResponse::Ok().json(res)
+    }
+}
@@ -0,0 +1,117 @@
+use diesel::prelude::*;
+use serde_json::json;
+
+use crate::{
+    app::features::{article::entities::Article, user::entities::User},
+    error::AppError,
+    utils::db::DbPool,
+};
+
+use super::entities::{ArticleAuthor, ArticleAuthorProfile, AuthorRole, CreateArticleAuthor};
+
+pub trait CoauthorRepository: Send + Sync + 'static {
+    fn fetch_authors(
+        &self,
+        article_title_slug: &str,
+        current_user: &Option<User>,
+    ) -> Result<Vec<ArticleAuthorProfile>, AppError>;
+    fn add_author(
+        &self,
+        input: AddAuthorRepositoryInput,
+    ) -> Result<Vec<ArticleAuthorProfile>, AppError>;
+    fn remove_author(
+        &self,
+        input: RemoveAuthorRepositoryInput,
+    ) -> Result<Vec<ArticleAuthorProfile>, AppError>;
+}
+
+#[derive(Clone)]
+pub struct CoauthorRepositoryImpl {
+    pool: DbPool,
+}
+
+impl CoauthorRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    fn fetch_owned_article(
+        conn: &mut PgConnection,
+        article_title_slug: &str,
+        current_user: &User,
+    ) -> Result<Article, AppError> {
+        let article = Article::fetch_by_slug(conn, article_title_slug)?;
+        match ArticleAuthor::fetch_role(conn, &article.id, &current_user.id)? {
+            Some(AuthorRole::Owner) => Ok(article),
+            _ => Err(AppError::Forbidden(
+                json!({"error": "Only an owner can manage the authors of this article."}),
+            )),
+        }
+    }
+}
+
+impl CoauthorRepository for CoauthorRepositoryImpl {
+    fn fetch_authors(
+        &self,
+        article_title_slug: &str,
+        current_user: &Option<User>,
+    ) -> Result<Vec<ArticleAuthorProfile>, AppError> {
+        let conn = &mut self.pool.get()?;
+        let article = Article::fetch_by_slug(conn, article_title_slug)?;
+        ArticleAuthor::fetch_profiles(conn, &article, current_user)
+    }
+
+    fn add_author(
+        &self,
+        input: AddAuthorRepositoryInput,
+    ) -> Result<Vec<ArticleAuthorProfile>, AppError> {
+        let conn = &mut self.pool.get()?;
+        let article =
+            Self::fetch_owned_article(conn, &input.article_title_slug, &input.current_user)?;
+        let invitee = User::find_by_username(conn, &input.username)?;
+        if invitee.id == article.author_id && input.role != AuthorRole::Owner {
+            return Err(AppError::UnprocessableEntity(
+                json!({"error": "The original author must remain an owner."}),
+            ));
+        }
+        ArticleAuthor::upsert(
+            conn,
+            &CreateArticleAuthor {
+                article_id: article.id,
+                user_id: invitee.id,
+                role: input.role.as_str(),
+            },
+        )?;
+        ArticleAuthor::fetch_profiles(conn, &article, &Some(input.current_user))
+    }
+
+    fn remove_author(
+        &self,
+        input: RemoveAuthorRepositoryInput,
+    ) -> Result<Vec<ArticleAuthorProfile>, AppError> {
+        let conn = &mut self.pool.get()?;
+        let article =
+            Self::fetch_owned_article(conn, &input.article_title_slug, &input.current_user)?;
+        let author = User::find_by_username(conn, &input.username)?;
+        if author.id == article.author_id {
+            return Err(AppError::UnprocessableEntity(
+                json!({"error": "The original author cannot be removed."}),
+            ));
+        }
+        ArticleAuthor::delete(conn, &article.id, &author.id)?;
+        ArticleAuthor::fetch_profiles(conn, &article, &Some(input.current_user))
+    }
+}
+
+pub struct AddAuthorRepositoryInput {
+    pub current_user: User,
+    pub article_title_slug: String,
+    pub username: String,
+    pub role: AuthorRole,
+}
+
+pub struct RemoveAuthorRepositoryInput {
+    pub current_user: User,
+    pub article_title_slug: String,
+    pub username: String,
+}
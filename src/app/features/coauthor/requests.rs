@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use super::entities::AuthorRole;
+
+#[derive(Deserialize, Serialize)]
+pub struct AddAuthorRequest {
+    pub author: AddAuthorInner,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct AddAuthorInner {
+    pub username: String,
+    pub role: Option<AuthorRole>,
+}
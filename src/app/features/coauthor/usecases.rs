@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use actix_web::HttpResponse;
+
+use crate::{app::features::user::entities::User, error::AppError};
+
+use super::{
+    entities::AuthorRole,
+    presenters::CoauthorPresenter,
+    repositories::{AddAuthorRepositoryInput, CoauthorRepository, RemoveAuthorRepositoryInput},
+};
+
+#[derive(Clone)]
+pub struct CoauthorUsecase {
+    coauthor_repository: Arc<dyn CoauthorRepository>,
+    coauthor_presenter: Arc<dyn CoauthorPresenter>,
+}
+
+impl CoauthorUsecase {
+    pub fn new(
+        coauthor_repository: Arc<dyn CoauthorRepository>,
+        coauthor_presenter: Arc<dyn CoauthorPresenter>,
+    ) -> Self {
+        Self {
+            coauthor_repository,
+            coauthor_presenter,
+        }
+    }
+
+    pub fn fetch_authors(
+        &self,
+        article_title_slug: &str,
+        current_user: &Option<User>,
+    ) -> Result<HttpResponse, AppError> {
+        let list = self
+            .coauthor_repository
+            .fetch_authors(article_title_slug, current_user)?;
+        let res = self.coauthor_presenter.to_multi_json(list);
+        Ok(res)
+    }
+
+    pub fn add_author(
+        &self,
+        current_user: User,
+        article_title_slug: String,
+        username: String,
+        role: AuthorRole,
+    ) -> Result<HttpResponse, AppError> {
+        let list = self
+            .coauthor_repository
+            .add_author(AddAuthorRepositoryInput {
+                current_user,
+                article_title_slug,
+                username,
+                role,
+            })?;
+        let res = self.coauthor_presenter.to_multi_json(list);
+        Ok(res)
+    }
+
+    pub fn remove_author(
+        &self,
+        current_user: User,
+        article_title_slug: String,
+        username: String,
+    ) -> Result<HttpResponse, AppError> {
+        let list = self
+            .coauthor_repository
+            .remove_author(RemoveAuthorRepositoryInput {
+                current_user,
+                article_title_slug,
+                username,
+            })?;
+        let res = self.coauthor_presenter.to_multi_json(list);
+        Ok(res)
+    }
+}
@@ -1,11 +1,20 @@
 use actix_web::{web, HttpRequest};
 
+use serde::Deserialize;
+use serde_json::json;
+
 use crate::{
     app::drivers::middlewares::{auth, state::AppState},
+    constants::COMMENTS_PAGE_LIMIT,
+    error::AppError,
     utils::{api::ApiResponse, uuid},
 };
 
-use super::request;
+use super::{
+    entities::{CommentCursor, CommentOrder},
+    request,
+    usecases::FetchCommentsUsecaseInput,
+};
 
 type ArticleIdSlug = String;
 type CommentIdSlug = String;
@@ -19,18 +28,90 @@ pub async fn create(
     let current_user = auth::get_current_user(&req)?;
     let article_title_slug = path.into_inner();
     let body = form.comment.body.to_owned();
-    state
-        .di_container
-        .comment_usecase
-        .create_comment(body, article_title_slug, current_user)
+    let parent_id = form
+        .comment
+        .parent_id
+        .as_deref()
+        .map(uuid::parse)
+        .transpose()?;
+    state.di_container.comment_usecase.create_comment(
+        body,
+        article_title_slug,
+        current_user,
+        parent_id,
+    )
+}
+
+#[derive(Deserialize)]
+pub struct CommentsListQueryParameter {
+    order: Option<String>,
+    cursor: Option<String>,
+    limit: Option<i64>,
 }
 
-pub async fn index(state: web::Data<AppState>, req: HttpRequest) -> ApiResponse {
+pub async fn index(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ArticleIdSlug>,
+    params: web::Query<CommentsListQueryParameter>,
+) -> ApiResponse {
     let current_user = auth::get_current_user(&req).ok();
+    let order = match params.order.as_deref() {
+        None => CommentOrder::default(),
+        Some(order) => CommentOrder::parse(order).ok_or_else(|| {
+            AppError::UnprocessableEntity(json!({"error": "order must be oldest or newest."}))
+        })?,
+    };
+    let after = match params.cursor.as_deref() {
+        None | Some("") => None,
+        Some(cursor) => Some(CommentCursor::parse(cursor).ok_or_else(|| {
+            AppError::UnprocessableEntity(json!({"error": "cursor is invalid."}))
+        })?),
+    };
+    let limit = params.limit.unwrap_or(20).clamp(1, COMMENTS_PAGE_LIMIT);
     state
         .di_container
         .comment_usecase
-        .fetch_comments(&current_user)
+        .fetch_comments(FetchCommentsUsecaseInput {
+            article_title_slug: path.into_inner(),
+            current_user,
+            order,
+            after,
+            limit,
+        })
+}
+
+pub async fn update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(ArticleIdSlug, CommentIdSlug)>,
+    form: web::Json<request::UpdateCommentRequest>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let (article_title_slug, comment_id) = path.into_inner();
+    let comment_id = uuid::parse(&comment_id)?;
+    let body = form.comment.body.to_owned();
+    state.di_container.comment_usecase.update_comment(
+        article_title_slug,
+        comment_id,
+        current_user,
+        body,
+    )
+}
+
+pub async fn history(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(ArticleIdSlug, CommentIdSlug)>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let (article_title_slug, comment_id) = path.into_inner();
+    let comment_id = uuid::parse(&comment_id)?;
+    state.di_container.comment_usecase.fetch_comment_history(
+        &article_title_slug,
+        comment_id,
+        &current_user,
+    )
 }
 
 pub async fn delete(
@@ -47,3 +128,31 @@ pub async fn delete(
         current_user.id,
     )
 }
+
+pub async fn lock(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ArticleIdSlug>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let article_title_slug = path.into_inner();
+    state.di_container.comment_usecase.set_comments_locked(
+        &article_title_slug,
+        current_user.id,
+        true,
+    )
+}
+
+pub async fn unlock(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ArticleIdSlug>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let article_title_slug = path.into_inner();
+    state.di_container.comment_usecase.set_comments_locked(
+        &article_title_slug,
+        current_user.id,
+        false,
+    )
+}
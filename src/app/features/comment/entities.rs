@@ -1,15 +1,17 @@
 use crate::app::features::{article::entities::Article, user::entities::User};
 use crate::error::AppError;
-use crate::schema::{articles, comments};
-use chrono::NaiveDateTime;
-use diesel::dsl::Eq;
+use crate::schema::{articles, comment_edits, comments, users};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use diesel::dsl::{Eq, IsNotNull, IsNull};
 use diesel::pg::PgConnection;
 use diesel::prelude::*;
+use diesel::sql_types::{Array, Bool};
 use diesel::{
     associations::{Associations, Identifiable},
     deserialize::Queryable,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 #[derive(Identifiable, Deserialize, Serialize, Queryable, Associations, Debug, Clone)]
@@ -23,10 +25,29 @@ pub struct Comment {
     pub body: String,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub deleted_at: Option<NaiveDateTime>,
+    pub parent_id: Option<Uuid>,
+    pub depth: i32,
+    pub edited_at: Option<NaiveDateTime>,
+    // Set while the spam filter holds the comment for a moderator.
+    pub held_at: Option<NaiveDateTime>,
 }
 
 type WithId<T> = Eq<comments::id, T>;
 type WithAuthor<T> = Eq<comments::author_id, T>;
+type NotDeleted = IsNull<comments::deleted_at>;
+type NotHeld = IsNull<comments::held_at>;
+type ParentIdsQuery = diesel::dsl::IntoBoxed<
+    'static,
+    diesel::dsl::Select<
+        diesel::query_source::Alias<Replies>,
+        diesel::query_source::AliasedField<Replies, comments::parent_id>,
+    >,
+    diesel::pg::Pg,
+>;
+
+diesel::alias!(comments as replies: Replies);
+type Deleted = IsNotNull<comments::deleted_at>;
 
 impl Comment {
     fn with_id(id: &Uuid) -> WithId<&Uuid> {
@@ -35,6 +56,18 @@ impl Comment {
     fn with_author(author_id: &Uuid) -> WithAuthor<&Uuid> {
         comments::author_id.eq(author_id)
     }
+
+    pub fn not_deleted() -> NotDeleted {
+        comments::deleted_at.is_null()
+    }
+
+    fn deleted() -> Deleted {
+        comments::deleted_at.is_not_null()
+    }
+
+    pub fn not_held() -> NotHeld {
+        comments::held_at.is_null()
+    }
 }
 
 impl Comment {
@@ -45,21 +78,348 @@ impl Comment {
         Ok(new_comment)
     }
 
-    pub fn delete(
+    // One page of the comments on an article with their authors, starting right
+    // after `after` in the given order. Deleted comments that still have live
+    // replies are kept so their threads stay intact. Held comments are only
+    // listed for their author. Comments by `hidden_author_ids` are left out
+    // along with every reply under them, so no thread loses its parent.
+    pub fn fetch_page(
         conn: &mut PgConnection,
-        (comment_id, author_id, slug): (&Uuid, &Uuid, &str),
-    ) -> Result<(), AppError> {
-        let subquery = articles::table
-            .filter(articles::slug.eq(slug))
-            .filter(articles::author_id.eq(author_id))
-            .select(articles::id);
-        let query = comments::table
+        article_id: &Uuid,
+        viewer_id: Option<&Uuid>,
+        hidden_author_ids: &[Uuid],
+        order: CommentOrder,
+        after: Option<CommentCursor>,
+        limit: i64,
+    ) -> Result<Vec<(Self, User)>, AppError> {
+        let mut query = comments::table
+            .inner_join(users::table)
+            .filter(comments::article_id.eq(article_id))
+            .filter(
+                Self::not_deleted().or(comments::id
+                    .nullable()
+                    .eq_any(Self::parent_ids_of_replies(true))),
+            )
+            .select((comments::all_columns, users::all_columns))
+            .into_boxed();
+        // Diesel can't express recursive queries, hence the raw SQL.
+        if !hidden_author_ids.is_empty() {
+            query = query.filter(
+                diesel::dsl::sql::<Bool>(
+                    "comments.id NOT IN (WITH RECURSIVE subtree AS (\
+                     SELECT id FROM comments WHERE article_id = ",
+                )
+                .bind::<diesel::sql_types::Uuid, _>(*article_id)
+                .sql(" AND author_id = ANY(")
+                .bind::<Array<diesel::sql_types::Uuid>, _>(hidden_author_ids.to_vec())
+                .sql(
+                    ") UNION ALL SELECT c.id FROM comments c \
+                     INNER JOIN subtree ON c.parent_id = subtree.id\
+                     ) SELECT id FROM subtree)",
+                ),
+            );
+        }
+        query = match viewer_id {
+            Some(viewer_id) => query.filter(Self::not_held().or(Self::with_author(viewer_id))),
+            None => query.filter(Self::not_held()),
+        };
+        query = match order {
+            CommentOrder::Oldest => query.order((comments::created_at.asc(), comments::id.asc())),
+            CommentOrder::Newest => query.order((comments::created_at.desc(), comments::id.desc())),
+        };
+        if let Some(CommentCursor { created_at, id }) = after {
+            query = match order {
+                CommentOrder::Oldest => query.filter(
+                    comments::created_at
+                        .gt(created_at)
+                        .or(comments::created_at.eq(created_at).and(comments::id.gt(id))),
+                ),
+                CommentOrder::Newest => query.filter(
+                    comments::created_at
+                        .lt(created_at)
+                        .or(comments::created_at.eq(created_at).and(comments::id.lt(id))),
+                ),
+            };
+        }
+        let list = query.limit(limit).load::<(Self, User)>(conn)?;
+        Ok(list)
+    }
+
+    pub fn fetch_on_article(
+        conn: &mut PgConnection,
+        comment_id: &Uuid,
+        article_id: &Uuid,
+    ) -> Result<Self, AppError> {
+        let t = comments::table
             .filter(Self::with_id(comment_id))
+            .filter(comments::article_id.eq(article_id))
+            .filter(Self::not_deleted());
+        let item = t.first::<Self>(conn)?;
+        Ok(item)
+    }
+
+    // Subquery of the comments that have replies, only counting live ones when
+    // `live_only` is set.
+    fn parent_ids_of_replies(live_only: bool) -> ParentIdsQuery {
+        let mut query = replies
+            .filter(replies.field(comments::parent_id).is_not_null())
+            .select(replies.field(comments::parent_id))
+            .into_boxed();
+        if live_only {
+            query = query
+                .filter(replies.field(comments::deleted_at).is_null())
+                .filter(replies.field(comments::held_at).is_null());
+        }
+        query
+    }
+
+    // Number of live direct replies of each comment, comments without any are left out.
+    pub fn fetch_reply_counts(
+        conn: &mut PgConnection,
+        comment_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, i64>, AppError> {
+        let counts = comments::table
+            .filter(comments::parent_id.eq_any(comment_ids))
+            .filter(Self::not_deleted())
+            .filter(Self::not_held())
+            .group_by(comments::parent_id)
+            .select((comments::parent_id, diesel::dsl::count(comments::id)))
+            .load::<(Option<Uuid>, i64)>(conn)?;
+        Ok(counts
+            .into_iter()
+            .filter_map(|(parent_id, count)| parent_id.map(|id| (id, count)))
+            .collect())
+    }
+
+    // Replaces the body, keeping the previous one in the edit history.
+    pub fn update_body(
+        conn: &mut PgConnection,
+        comment: &Comment,
+        body: &str,
+    ) -> Result<Self, AppError> {
+        conn.transaction(|conn| {
+            diesel::insert_into(comment_edits::table)
+                .values(&CreateCommentEdit {
+                    comment_id: comment.id,
+                    body: comment.body.clone(),
+                })
+                .execute(conn)?;
+            let now = Utc::now().naive_utc();
+            let item = diesel::update(comments::table.filter(Self::with_id(&comment.id)))
+                .set((
+                    comments::body.eq(body),
+                    comments::edited_at.eq(Some(now)),
+                    comments::updated_at.eq(now),
+                ))
+                .get_result::<Self>(conn)?;
+            Ok(item)
+        })
+    }
+
+    // Holds a published comment for a moderator.
+    pub fn hold(conn: &mut PgConnection, comment_id: &Uuid) -> Result<Self, AppError> {
+        let item = diesel::update(comments::table.filter(Self::with_id(comment_id)))
+            .set(comments::held_at.eq(Some(Utc::now().naive_utc())))
+            .get_result::<Self>(conn)?;
+        Ok(item)
+    }
+
+    // Publishes a held comment.
+    pub fn release(conn: &mut PgConnection, comment_id: &Uuid) -> Result<Self, AppError> {
+        let item = diesel::update(comments::table.filter(Self::with_id(comment_id)))
+            .set(comments::held_at.eq(None::<NaiveDateTime>))
+            .get_result::<Self>(conn)?;
+        Ok(item)
+    }
+
+    // Comments the author wrote since `since`, held and deleted ones included.
+    pub fn count_by_author_since(
+        conn: &mut PgConnection,
+        author_id: &Uuid,
+        since: NaiveDateTime,
+    ) -> Result<i64, AppError> {
+        let count = comments::table
+            .filter(Self::with_author(author_id))
+            .filter(comments::created_at.ge(since))
+            .count()
+            .get_result::<i64>(conn)?;
+        Ok(count)
+    }
+
+    // Comments with exactly this body the author wrote since `since`.
+    pub fn count_same_body_by_author_since(
+        conn: &mut PgConnection,
+        author_id: &Uuid,
+        body: &str,
+        since: NaiveDateTime,
+    ) -> Result<i64, AppError> {
+        let count = comments::table
             .filter(Self::with_author(author_id))
-            .filter(comments::article_id.eq_any(subquery));
-        diesel::delete(query).execute(conn)?;
+            .filter(comments::body.eq(body))
+            .filter(comments::created_at.ge(since))
+            .count()
+            .get_result::<i64>(conn)?;
+        Ok(count)
+    }
+
+    // Bodies of the latest published comments, newest first.
+    pub fn fetch_recent_bodies(
+        conn: &mut PgConnection,
+        limit: i64,
+    ) -> Result<Vec<String>, AppError> {
+        let list = comments::table
+            .filter(Self::not_deleted())
+            .filter(Self::not_held())
+            .order(comments::created_at.desc())
+            .select(comments::body)
+            .limit(limit)
+            .load::<String>(conn)?;
+        Ok(list)
+    }
+
+    pub fn delete(conn: &mut PgConnection, comment_id: &Uuid) -> Result<(), AppError> {
+        let query = comments::table
+            .filter(Self::with_id(comment_id))
+            .filter(Self::not_deleted());
+        diesel::update(query)
+            .set(comments::deleted_at.eq(Some(Utc::now().naive_utc())))
+            .execute(conn)?;
         Ok(())
     }
+
+    pub fn fetch_deleted(
+        conn: &mut PgConnection,
+        comment_id: &Uuid,
+        author_id: &Uuid,
+    ) -> Result<Self, AppError> {
+        let t = comments::table
+            .filter(Self::with_id(comment_id))
+            .filter(Self::with_author(author_id))
+            .filter(Self::deleted());
+        let item = t.first::<Self>(conn)?;
+        Ok(item)
+    }
+
+    pub fn restore(conn: &mut PgConnection, comment_id: &Uuid) -> Result<Self, AppError> {
+        let t = comments::table
+            .filter(Self::with_id(comment_id))
+            .filter(Self::deleted());
+        let item = diesel::update(t)
+            .set(comments::deleted_at.eq(None::<NaiveDateTime>))
+            .get_result::<Self>(conn)?;
+        Ok(item)
+    }
+
+    // Trashed comments of the user, with the (live) article each one belongs to.
+    pub fn fetch_deleted_by_author(
+        conn: &mut PgConnection,
+        author_id: &Uuid,
+    ) -> Result<Vec<(Self, Article)>, AppError> {
+        let t = comments::table
+            .inner_join(articles::table)
+            .filter(Self::with_author(author_id))
+            .filter(Self::deleted())
+            .filter(Article::not_deleted())
+            .order(comments::deleted_at.desc());
+        let list = t.load::<(Self, Article)>(conn)?;
+        Ok(list)
+    }
+
+    pub fn purge_deleted_before(
+        conn: &mut PgConnection,
+        cutoff: &NaiveDateTime,
+    ) -> Result<usize, AppError> {
+        // Comments with replies stay behind as placeholders of their threads.
+        let t = comments::table
+            .filter(comments::deleted_at.lt(cutoff))
+            .filter(
+                comments::id
+                    .nullable()
+                    .ne_all(Self::parent_ids_of_replies(false)),
+            );
+        let count = diesel::delete(t).execute(conn)?;
+        Ok(count)
+    }
+}
+
+// A previous body of a comment, `created_at` being when it was replaced.
+#[derive(Identifiable, Queryable, Associations, Debug, Clone)]
+#[diesel(belongs_to(Comment, foreign_key = comment_id))]
+#[diesel(table_name = comment_edits)]
+pub struct CommentEdit {
+    pub id: Uuid,
+    pub comment_id: Uuid,
+    pub body: String,
+    pub created_at: NaiveDateTime,
+}
+
+impl CommentEdit {
+    pub fn fetch_by_comment_id(
+        conn: &mut PgConnection,
+        comment_id: &Uuid,
+    ) -> Result<Vec<Self>, AppError> {
+        let list = comment_edits::table
+            .filter(comment_edits::comment_id.eq(comment_id))
+            .order(comment_edits::created_at.asc())
+            .load::<Self>(conn)?;
+        Ok(list)
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = comment_edits)]
+pub struct CreateCommentEdit {
+    pub comment_id: Uuid,
+    pub body: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum CommentOrder {
+    #[default]
+    Oldest,
+    Newest,
+}
+
+impl CommentOrder {
+    pub fn parse(order: &str) -> Option<Self> {
+        match order {
+            "oldest" => Some(CommentOrder::Oldest),
+            "newest" => Some(CommentOrder::Newest),
+            _ => None,
+        }
+    }
+}
+
+// Where a page of comments ends, handed to clients as an opaque string made of
+// the creation time in microseconds and the id of the last comment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommentCursor {
+    pub created_at: NaiveDateTime,
+    pub id: Uuid,
+}
+
+impl CommentCursor {
+    pub fn of(comment: &Comment) -> Self {
+        Self {
+            created_at: comment.created_at,
+            id: comment.id,
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        format!(
+            "{}_{}",
+            self.created_at.and_utc().timestamp_micros(),
+            self.id.simple()
+        )
+    }
+
+    pub fn parse(cursor: &str) -> Option<Self> {
+        let (micros, id) = cursor.split_once('_')?;
+        let created_at = DateTime::from_timestamp_micros(micros.parse().ok()?)?.naive_utc();
+        let id = Uuid::parse_str(id).ok()?;
+        Some(Self { created_at, id })
+    }
 }
 
 #[derive(Insertable, Clone)]
@@ -68,4 +428,7 @@ pub struct CreateComment {
     pub body: String,
     pub author_id: Uuid,
     pub article_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub depth: i32,
+    pub held_at: Option<NaiveDateTime>,
 }
@@ -2,9 +2,17 @@ use actix_web::HttpResponse;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::{app::features::profile::entities::Profile, utils::date::Iso8601};
+use crate::{
+    app::features::{article::entities::Article, reaction::presenters::ReactionContent},
+    utils::date::Iso8601,
+};
 
-use super::entities::Comment;
+use super::{
+    entities::{Comment, CommentCursor, CommentEdit},
+    repositories::{CommentsList, SingleComment},
+};
+
+const DELETED_COMMENT_BODY: &str = "[deleted]";
 
 #[derive(Serialize, Deserialize)]
 pub struct SingleCommentResponse {
@@ -12,50 +20,26 @@ pub struct SingleCommentResponse {
 }
 
 #[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MultipleCommentResponse {
     pub comments: Vec<InnerComment>,
+    // Passed back as `?cursor=` for the next page, null on the last one.
+    pub next_cursor: Option<String>,
 }
 
-impl From<(Comment, Profile)> for SingleCommentResponse {
-    fn from((comment, profile): (Comment, Profile)) -> Self {
+impl From<SingleComment> for SingleCommentResponse {
+    fn from(item: SingleComment) -> Self {
         Self {
-            comment: InnerComment {
-                id: comment.id,
-                created_at: Iso8601(comment.created_at),
-                updated_at: Iso8601(comment.updated_at),
-                body: comment.body,
-                author: InnerAuthor {
-                    username: profile.username,
-                    bio: profile.bio,
-                    image: profile.image,
-                    following: profile.following,
-                },
-            },
+            comment: InnerComment::from(item),
         }
     }
 }
 
-impl From<Vec<(Comment, Profile)>> for MultipleCommentResponse {
-    fn from(list: Vec<(Comment, Profile)>) -> Self {
+impl From<(CommentsList, Option<CommentCursor>)> for MultipleCommentResponse {
+    fn from((list, next_cursor): (CommentsList, Option<CommentCursor>)) -> Self {
         Self {
-            comments: list
-                .into_iter()
-                .map(|item| {
-                    let (comment, profile) = item;
-                    InnerComment {
-                        id: comment.id,
-                        created_at: Iso8601(comment.created_at),
-                        updated_at: Iso8601(comment.updated_at),
-                        body: comment.body,
-                        author: InnerAuthor {
-                            username: profile.username,
-                            bio: profile.bio,
-                            image: profile.image,
-                            following: profile.following,
-                        },
-                    }
-                })
-                .collect(),
+            next_cursor: next_cursor.map(|cursor| cursor.encode()),
+            comments: list.into_iter().map(InnerComment::from).collect(),
         }
     }
 }
@@ -66,7 +50,50 @@ pub struct InnerComment {
     pub created_at: Iso8601,
     pub updated_at: Iso8601,
     pub body: String,
-    pub author: InnerAuthor,
+    // Null for a deleted comment kept as the placeholder of its replies.
+    pub author: Option<InnerAuthor>,
+    #[serde(rename = "parentId")]
+    pub parent_id: Option<Uuid>,
+    #[serde(rename = "replyCount")]
+    pub reply_count: i64,
+    pub reactions: Vec<ReactionContent>,
+    pub edited: bool,
+    #[serde(rename = "editedAt")]
+    pub edited_at: Option<Iso8601>,
+    // Only the author sees a held comment until a moderator releases it.
+    pub held: bool,
+}
+
+impl From<SingleComment> for InnerComment {
+    fn from((comment, profile, reply_count, reaction_info): SingleComment) -> Self {
+        let deleted = comment.deleted_at.is_some();
+        Self {
+            id: comment.id,
+            created_at: Iso8601(comment.created_at),
+            updated_at: Iso8601(comment.updated_at),
+            body: if deleted {
+                DELETED_COMMENT_BODY.to_owned()
+            } else {
+                comment.body
+            },
+            author: (!deleted).then_some(InnerAuthor {
+                username: profile.username,
+                bio: profile.bio,
+                image: profile.image,
+                following: profile.following,
+            }),
+            parent_id: comment.parent_id,
+            reply_count,
+            reactions: if deleted {
+                vec![]
+            } else {
+                ReactionContent::list(reaction_info)
+            },
+            edited: comment.edited_at.is_some(),
+            edited_at: comment.edited_at.map(Iso8601),
+            held: comment.held_at.is_some(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -77,9 +104,51 @@ pub struct InnerAuthor {
     pub following: bool,
 }
 
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentLockResponse {
+    pub slug: String,
+    pub comments_locked: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CommentHistoryResponse {
+    pub history: Vec<CommentEditContent>,
+}
+
+// `body` is what the comment said until `replacedAt`; the current body is last.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentEditContent {
+    pub body: String,
+    pub replaced_at: Option<Iso8601>,
+}
+
+impl From<(Comment, Vec<CommentEdit>)> for CommentHistoryResponse {
+    fn from((comment, edits): (Comment, Vec<CommentEdit>)) -> Self {
+        let current = CommentEditContent {
+            body: comment.body,
+            replaced_at: None,
+        };
+        Self {
+            history: edits
+                .into_iter()
+                .map(|edit| CommentEditContent {
+                    body: edit.body,
+                    replaced_at: Some(Iso8601(edit.created_at)),
+                })
+                .chain(std::iter::once(current))
+                .collect(),
+        }
+    }
+}
+
 pub trait CommentPresenter: Send + Sync + 'static {
-    fn to_single_json(&self, item: (Comment, Profile)) -> HttpResponse;
-    fn to_multi_json(&self, list: Vec<(Comment, Profile)>) -> HttpResponse;
+    fn to_single_json(&self, item: SingleComment) -> HttpResponse;
+    fn to_multi_json(&self, list: CommentsList, next_cursor: Option<CommentCursor>)
+        -> HttpResponse;
+    fn to_lock_json(&self, article: Article) -> HttpResponse;
+    fn to_history_json(&self, comment: Comment, edits: Vec<CommentEdit>) -> HttpResponse;
     fn to_http_res(&self) -> HttpResponse;
 }
 
@@ -93,13 +162,30 @@ impl CommentPresenterImpl {
 }
 
 impl CommentPresenter for CommentPresenterImpl {
-    fn to_single_json(&self, item: (Comment, Profile)) -> HttpResponse {
+    fn to_single_json(&self, item: SingleComment) -> HttpResponse {
         let res = SingleCommentResponse::from(item);
         HttpResponse::Ok().json(res)
     }
 
-    fn to_multi_json(&self, list: Vec<(Comment, Profile)>) -> HttpResponse {
-        let res = MultipleCommentResponse::from(list);
+    fn to_multi_json(
+        &self,
+        list: CommentsList,
+        next_cursor: Option<CommentCursor>,
+    ) -> HttpResponse {
+        let res = MultipleCommentResponse::from((list, next_cursor));
+        HttpResponse::Ok().json(res)
+    }
+
+    fn to_lock_json(&self, article: Article) -> HttpResponse {
+        let res = CommentLockResponse {
+            slug: article.slug,
+            comments_locked: article.comments_locked,
+        };
+        HttpResponse::Ok().json(res)
+    }
+
+    fn to_history_json(&self, comment: Comment, edits: Vec<CommentEdit>) -> HttpResponse {
+        let res = CommentHistoryResponse::from((comment, edits));
         HttpResponse::Ok().json(res)
     }
 
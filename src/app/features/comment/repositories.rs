@@ -1,36 +1,66 @@
 use crate::{
     app::features::{
-        article::entities::{Article, FetchBySlugAndAuthorId},
+        article::entities::Article,
+        block::entities::Block,
+        coauthor::entities::ArticleAuthor,
+        follow::entities::Follow,
+        mention::entities::{Mention, MentionSource},
         profile::entities::Profile,
+        reaction::entities::{CommentReaction, ReactionInfo},
+        report::entities::{CreateReport, Report, ReportReason, ReportTarget},
         user::entities::User,
     },
     error::AppError,
-    schema::{comments, users},
     utils::db::DbPool,
 };
-use diesel::prelude::*;
-use diesel::QueryDsl;
+use chrono::{Duration, Utc};
+use diesel::Connection;
+use serde_json::json;
+use std::collections::HashSet;
 use uuid::Uuid;
 
-use super::entities::{Comment, CreateComment};
+use super::entities::{Comment, CommentCursor, CommentEdit, CommentOrder, CreateComment};
+
+type NextCursor = Option<CommentCursor>;
+type ReplyCount = i64;
+pub type SingleComment = (Comment, Profile, ReplyCount, ReactionInfo);
+pub type CommentsList = Vec<SingleComment>;
 
 pub trait CommentRepository: Send + Sync + 'static {
     fn create_comment(
         &self,
-        body: String,
-        article_title_slug: String,
-        author: User,
-    ) -> Result<(Comment, Profile), AppError>;
+        input: CreateCommentRepositoryInput,
+    ) -> Result<SingleComment, AppError>;
+    // Only the author of the comment may edit it, within the edit window if any.
+    fn update_comment(
+        &self,
+        input: UpdateCommentRepositoryInput,
+    ) -> Result<SingleComment, AppError>;
+    // The previous bodies of a comment, shown to the authors of the article
+    // and to moderators.
+    fn fetch_comment_history(
+        &self,
+        article_title_slug: &str,
+        comment_id: Uuid,
+        current_user: &User,
+    ) -> Result<(Comment, Vec<CommentEdit>), AppError>;
     fn fetch_comments(
         &self,
-        current_user: &Option<User>,
-    ) -> Result<Vec<(Comment, Profile)>, AppError>;
+        input: FetchCommentsRepositoryInput,
+    ) -> Result<(CommentsList, NextCursor), AppError>;
+    // The author of the comment and the authors of the article may delete it.
     fn delete_comment(
         &self,
         article_title_slug: &str,
         comment_id: Uuid,
-        author_id: Uuid,
+        current_user_id: Uuid,
     ) -> Result<(), AppError>;
+    fn set_comments_locked(
+        &self,
+        article_title_slug: &str,
+        current_user_id: Uuid,
+        locked: bool,
+    ) -> Result<Article, AppError>;
 }
 
 #[derive(Clone)]
@@ -47,56 +77,300 @@ impl CommentRepositoryImpl {
 impl CommentRepository for CommentRepositoryImpl {
     fn create_comment(
         &self,
-        body: String,
-        article_title_slug: String,
-        author: User,
-    ) -> Result<(Comment, Profile), AppError> {
+        input: CreateCommentRepositoryInput,
+    ) -> Result<SingleComment, AppError> {
         let conn = &mut self.pool.get()?;
-        let article = Article::fetch_by_slug_and_author_id(
-            conn,
-            &FetchBySlugAndAuthorId {
-                slug: article_title_slug,
-                author_id: author.id,
-            },
-        )?;
-        let comment = Comment::create(
-            conn,
-            &CreateComment {
-                body,
-                author_id: author.id,
-                article_id: article.id,
-            },
-        )?;
+        let author = input.author;
+        let article = Article::fetch_by_slug(conn, &input.article_title_slug)?;
+        if article.comments_locked {
+            return Err(AppError::Forbidden(
+                json!({"error": "Comments on this article are locked."}),
+            ));
+        }
+        let author_ids = ArticleAuthor::fetch_user_ids(conn, &article.id)?;
+        if Block::is_blocked_by_any(conn, &author_ids, &author.id)? {
+            return Err(AppError::Forbidden(
+                json!({"error": "You can't comment on articles of a user who blocked you."}),
+            ));
+        }
+        let depth = match &input.parent_id {
+            Some(parent_id) => {
+                let parent = Comment::fetch_on_article(conn, parent_id, &article.id)?;
+                if parent.held_at.is_some() {
+                    return Err(AppError::UnprocessableEntity(json!({
+                        "error": "Comments awaiting moderation can't be replied to."
+                    })));
+                }
+                if parent.depth >= input.max_depth {
+                    return Err(AppError::UnprocessableEntity(json!({
+                        "error": format!("Replies can't nest deeper than {} levels.", input.max_depth)
+                    })));
+                }
+                parent.depth + 1
+            }
+            None => 0,
+        };
+        let held = input.hold_details.is_some();
+        let comment = conn.transaction::<_, AppError, _>(|conn| {
+            let comment = Comment::create(
+                conn,
+                &CreateComment {
+                    body: input.body,
+                    author_id: author.id,
+                    article_id: article.id,
+                    parent_id: input.parent_id,
+                    depth,
+                    held_at: held.then(|| Utc::now().naive_utc()),
+                },
+            )?;
+            if let Some(details) = input.hold_details {
+                Report::create(
+                    conn,
+                    &CreateReport {
+                        reporter_id: None,
+                        target_type: ReportTarget::Comment.as_str(),
+                        target_id: comment.id,
+                        reason: ReportReason::Spam.as_str(),
+                        details: Some(details),
+                    },
+                )?;
+            }
+            // Held comments don't notify the users they mention.
+            Mention::record(
+                conn,
+                MentionSource::Comment {
+                    article_id: article.id,
+                    comment_id: comment.id,
+                },
+                &author,
+                &comment.body,
+                !held,
+            )?;
+            Ok(comment)
+        })?;
         let profile = author.fetch_profile(conn, &author.id)?;
-        Ok((comment, profile))
+        Ok((comment, profile, 0, ReactionInfo::default()))
     }
-    fn fetch_comments(
+
+    fn update_comment(
         &self,
-        current_user: &Option<User>,
-    ) -> Result<Vec<(Comment, Profile)>, AppError> {
+        input: UpdateCommentRepositoryInput,
+    ) -> Result<SingleComment, AppError> {
         let conn = &mut self.pool.get()?;
+        let author = input.current_user;
+        let article = Article::fetch_by_slug(conn, &input.article_title_slug)?;
+        let comment = Comment::fetch_on_article(conn, &input.comment_id, &article.id)?;
+        if comment.author_id != author.id {
+            return Err(AppError::Forbidden(
+                json!({"error": "Only the author of the comment can edit it."}),
+            ));
+        }
+        if article.comments_locked {
+            return Err(AppError::Forbidden(
+                json!({"error": "Comments on this article are locked."}),
+            ));
+        }
+        let author_ids = ArticleAuthor::fetch_user_ids(conn, &article.id)?;
+        if Block::is_blocked_by_any(conn, &author_ids, &author.id)? {
+            return Err(AppError::Forbidden(
+                json!({"error": "You can't edit comments on articles of a user who blocked you."}),
+            ));
+        }
+        if let Some(edit_window) = input.edit_window {
+            if Utc::now().naive_utc() > comment.created_at + edit_window {
+                return Err(AppError::Forbidden(json!({
+                    "error": format!(
+                        "Comments can only be edited in the first {} minutes.",
+                        edit_window.num_minutes()
+                    )
+                })));
+            }
+        }
+        let comment = conn.transaction::<_, AppError, _>(|conn| {
+            let mut comment = Comment::update_body(conn, &comment, &input.body)?;
+            if let Some(details) = input.hold_details.filter(|_| comment.held_at.is_none()) {
+                comment = Comment::hold(conn, &comment.id)?;
+                Report::create(
+                    conn,
+                    &CreateReport {
+                        reporter_id: None,
+                        target_type: ReportTarget::Comment.as_str(),
+                        target_id: comment.id,
+                        reason: ReportReason::Spam.as_str(),
+                        details: Some(details),
+                    },
+                )?;
+            }
+            Mention::record(
+                conn,
+                MentionSource::Comment {
+                    article_id: article.id,
+                    comment_id: comment.id,
+                },
+                &author,
+                &comment.body,
+                comment.held_at.is_none(),
+            )?;
+            Ok(comment)
+        })?;
+        let reply_count = Comment::fetch_reply_counts(conn, &[comment.id])?
+            .get(&comment.id)
+            .copied()
+            .unwrap_or(0);
+        let reaction_info = CommentReaction::fetch_info(conn, &comment.id, Some(&author.id))?;
+        let profile = author.fetch_profile(conn, &author.id)?;
+        Ok((comment, profile, reply_count, reaction_info))
+    }
 
-        let comments = comments::table
-            .inner_join(users::table)
-            .get_results::<(Comment, User)>(conn)?;
-        let comments = comments
+    fn fetch_comment_history(
+        &self,
+        article_title_slug: &str,
+        comment_id: Uuid,
+        current_user: &User,
+    ) -> Result<(Comment, Vec<CommentEdit>), AppError> {
+        let conn = &mut self.pool.get()?;
+        let article = Article::fetch_by_slug(conn, article_title_slug)?;
+        if !current_user.is_moderator
+            && ArticleAuthor::fetch_role(conn, &article.id, &current_user.id)?.is_none()
+        {
+            return Err(AppError::Forbidden(json!({
+                "error": "Only the authors of this article and moderators can see the edit history."
+            })));
+        }
+        let comment = Comment::fetch_on_article(conn, &comment_id, &article.id)?;
+        let edits = CommentEdit::fetch_by_comment_id(conn, &comment.id)?;
+        Ok((comment, edits))
+    }
+    fn fetch_comments(
+        &self,
+        input: FetchCommentsRepositoryInput,
+    ) -> Result<(CommentsList, NextCursor), AppError> {
+        let conn = &mut self.pool.get()?;
+        let article = Article::fetch_by_slug(conn, &input.article_title_slug)?;
+        // Comments by muted users and users on either side of a block are left
+        // out, together with the replies under them.
+        let hidden_ids = match &input.current_user {
+            Some(current_user) => current_user
+                .fetch_hidden_user_ids(conn)?
+                .into_iter()
+                .collect::<Vec<_>>(),
+            None => vec![],
+        };
+        // One extra row tells whether another page follows.
+        let mut list = Comment::fetch_page(
+            conn,
+            &article.id,
+            input
+                .current_user
+                .as_ref()
+                .map(|current_user| &current_user.id),
+            &hidden_ids,
+            input.order,
+            input.after,
+            input.limit + 1,
+        )?;
+        let next_cursor = if list.len() as i64 > input.limit {
+            list.truncate(input.limit as usize);
+            list.last().map(|(comment, _)| CommentCursor::of(comment))
+        } else {
+            None
+        };
+        let followed_ids = match &input.current_user {
+            Some(current_user) => {
+                let author_ids = list.iter().map(|(_, user)| user.id).collect::<Vec<_>>();
+                Follow::fetch_followed_ids(conn, &current_user.id, &author_ids)?
+            }
+            None => HashSet::new(),
+        };
+        let comment_ids = list
             .iter()
+            .map(|(comment, _)| comment.id)
+            .collect::<Vec<_>>();
+        let reply_counts = Comment::fetch_reply_counts(conn, &comment_ids)?;
+        let mut reaction_infos = CommentReaction::fetch_infos(
+            conn,
+            &comment_ids,
+            input
+                .current_user
+                .as_ref()
+                .map(|current_user| &current_user.id),
+        )?;
+        let comments = list
+            .into_iter()
             .map(|(comment, user)| {
-                let profile = user.to_profile(conn, current_user);
-                (comment.to_owned(), profile)
+                let profile = Profile {
+                    following: followed_ids.contains(&user.id),
+                    username: user.username,
+                    bio: user.bio,
+                    image: user.image,
+                };
+                let reply_count = reply_counts.get(&comment.id).copied().unwrap_or(0);
+                let reaction_info = reaction_infos.remove(&comment.id).unwrap_or_default();
+                (comment, profile, reply_count, reaction_info)
             })
-            .collect::<Vec<(Comment, Profile)>>();
-        Ok(comments)
+            .collect::<Vec<_>>();
+        Ok((comments, next_cursor))
     }
 
     fn delete_comment(
         &self,
         article_title_slug: &str,
         comment_id: Uuid,
-        author_id: Uuid,
+        current_user_id: Uuid,
     ) -> Result<(), AppError> {
         let conn = &mut self.pool.get()?;
-        let _ = Comment::delete(conn, (&comment_id, &author_id, article_title_slug));
-        Ok(())
+        let article = Article::fetch_by_slug(conn, article_title_slug)?;
+        let comment = Comment::fetch_on_article(conn, &comment_id, &article.id)?;
+        if comment.author_id != current_user_id
+            && ArticleAuthor::fetch_role(conn, &article.id, &current_user_id)?.is_none()
+        {
+            return Err(AppError::Forbidden(json!({
+                "error": "Only the author of the comment or of the article can delete it."
+            })));
+        }
+        Comment::delete(conn, &comment.id)
     }
+
+    fn set_comments_locked(
+        &self,
+        article_title_slug: &str,
+        current_user_id: Uuid,
+        locked: bool,
+    ) -> Result<Article, AppError> {
+        let conn = &mut self.pool.get()?;
+        let article = Article::fetch_by_slug(conn, article_title_slug)?;
+        if ArticleAuthor::fetch_role(conn, &article.id, &current_user_id)?.is_none() {
+            return Err(AppError::Forbidden(json!({
+                "error": "Only the authors of this article can lock its comments."
+            })));
+        }
+        Article::set_comments_locked(conn, &article.id, locked)
+    }
+}
+
+pub struct CreateCommentRepositoryInput {
+    pub body: String,
+    pub article_title_slug: String,
+    pub author: User,
+    pub parent_id: Option<Uuid>,
+    pub max_depth: i32,
+    // When set the comment is held, with a report filed for the moderators.
+    pub hold_details: Option<String>,
+}
+
+pub struct UpdateCommentRepositoryInput {
+    pub article_title_slug: String,
+    pub comment_id: Uuid,
+    pub current_user: User,
+    pub body: String,
+    pub edit_window: Option<Duration>,
+    pub hold_details: Option<String>,
+}
+
+pub struct FetchCommentsRepositoryInput {
+    pub article_title_slug: String,
+    pub current_user: Option<User>,
+    pub order: CommentOrder,
+    pub after: Option<CommentCursor>,
+    pub limit: i64,
 }
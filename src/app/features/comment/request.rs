@@ -6,6 +6,19 @@ pub struct CreateCommentRequest {
 }
 
 #[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct InnerComment {
     pub body: String,
+    // Set when replying to another comment.
+    pub parent_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UpdateCommentRequest {
+    pub comment: UpdateInnerComment,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UpdateInnerComment {
+    pub body: String,
 }
@@ -1,42 +1,128 @@
-use super::{presenters::CommentPresenter, repositories::CommentRepository};
-use crate::{app::features::user::entities::User, error::AppError};
+use super::{
+    entities::{CommentCursor, CommentOrder},
+    presenters::CommentPresenter,
+    repositories::{
+        CommentRepository, CreateCommentRepositoryInput, FetchCommentsRepositoryInput,
+        UpdateCommentRepositoryInput,
+    },
+};
+use crate::{
+    app::features::{
+        spam::{classifiers::SpamClassifier, entities::SpamSubject},
+        user::entities::User,
+    },
+    error::AppError,
+};
 use actix_web::HttpResponse;
+use chrono::Duration;
 use std::sync::Arc;
 use uuid::Uuid;
 
+const HELD_AUTHOR_DETAILS: &str = "Held because the author's account is awaiting moderation.";
+
 #[derive(Clone)]
 pub struct CommentUsecase {
     comment_repository: Arc<dyn CommentRepository>,
     comment_presenter: Arc<dyn CommentPresenter>,
+    spam_classifier: Arc<dyn SpamClassifier>,
+    max_depth: i32,
+    edit_window: Option<Duration>,
 }
 
 impl CommentUsecase {
     pub fn new(
         comment_repository: Arc<dyn CommentRepository>,
         comment_presenter: Arc<dyn CommentPresenter>,
+        spam_classifier: Arc<dyn SpamClassifier>,
+        max_depth: i32,
+        edit_window: Option<Duration>,
     ) -> Self {
         Self {
             comment_repository,
             comment_presenter,
+            spam_classifier,
+            max_depth,
+            edit_window,
         }
     }
 
+    // Comments the spam filter flags, and every comment of a held account, are
+    // stored but held for a moderator instead of being published.
     pub fn create_comment(
         &self,
         body: String,
         article_title_slug: String,
         author: User,
+        parent_id: Option<Uuid>,
+    ) -> Result<HttpResponse, AppError> {
+        let hold_details = self.hold_details(&author, &body)?;
+        let result = self
+            .comment_repository
+            .create_comment(CreateCommentRepositoryInput {
+                body,
+                article_title_slug,
+                author,
+                parent_id,
+                max_depth: self.max_depth,
+                hold_details,
+            })?;
+        let res = self.comment_presenter.to_single_json(result);
+        Ok(res)
+    }
+
+    // Edits go through the spam filter too, so a published comment can't be
+    // turned into spam after the fact.
+    pub fn update_comment(
+        &self,
+        article_title_slug: String,
+        comment_id: Uuid,
+        current_user: User,
+        body: String,
     ) -> Result<HttpResponse, AppError> {
+        let hold_details = self.hold_details(&current_user, &body)?;
         let result = self
             .comment_repository
-            .create_comment(body, article_title_slug, author)?;
+            .update_comment(UpdateCommentRepositoryInput {
+                article_title_slug,
+                comment_id,
+                current_user,
+                body,
+                edit_window: self.edit_window,
+                hold_details,
+            })?;
         let res = self.comment_presenter.to_single_json(result);
         Ok(res)
     }
 
-    pub fn fetch_comments(&self, user: &Option<User>) -> Result<HttpResponse, AppError> {
-        let result = self.comment_repository.fetch_comments(user)?;
-        let res = self.comment_presenter.to_multi_json(result);
+    pub fn fetch_comment_history(
+        &self,
+        article_title_slug: &str,
+        comment_id: Uuid,
+        current_user: &User,
+    ) -> Result<HttpResponse, AppError> {
+        let (comment, edits) = self.comment_repository.fetch_comment_history(
+            article_title_slug,
+            comment_id,
+            current_user,
+        )?;
+        let res = self.comment_presenter.to_history_json(comment, edits);
+        Ok(res)
+    }
+
+    pub fn fetch_comments(
+        &self,
+        input: FetchCommentsUsecaseInput,
+    ) -> Result<HttpResponse, AppError> {
+        let (list, next_cursor) =
+            self.comment_repository
+                .fetch_comments(FetchCommentsRepositoryInput {
+                    article_title_slug: input.article_title_slug,
+                    current_user: input.current_user,
+                    order: input.order,
+                    after: input.after,
+                    limit: input.limit,
+                })?;
+        let res = self.comment_presenter.to_multi_json(list, next_cursor);
         Ok(res)
     }
 
@@ -44,12 +130,49 @@ impl CommentUsecase {
         &self,
         article_title_slug: &str,
         comment_id: Uuid,
-        author_id: Uuid,
+        current_user_id: Uuid,
     ) -> Result<HttpResponse, AppError> {
-        let _ = self
-            .comment_repository
-            .delete_comment(article_title_slug, comment_id, author_id);
+        self.comment_repository
+            .delete_comment(article_title_slug, comment_id, current_user_id)?;
         let res = self.comment_presenter.to_http_res();
         Ok(res)
     }
+
+    pub fn set_comments_locked(
+        &self,
+        article_title_slug: &str,
+        current_user_id: Uuid,
+        locked: bool,
+    ) -> Result<HttpResponse, AppError> {
+        let article = self.comment_repository.set_comments_locked(
+            article_title_slug,
+            current_user_id,
+            locked,
+        )?;
+        let res = self.comment_presenter.to_lock_json(article);
+        Ok(res)
+    }
+
+    // Why a comment with this body should be held, if it should.
+    fn hold_details(&self, author: &User, body: &str) -> Result<Option<String>, AppError> {
+        let verdict = self
+            .spam_classifier
+            .classify(&SpamSubject::Comment { author, body })?;
+        let details = if verdict.is_spam() {
+            Some(verdict.details())
+        } else if author.is_held() {
+            Some(HELD_AUTHOR_DETAILS.to_owned())
+        } else {
+            None
+        };
+        Ok(details)
+    }
+}
+
+pub struct FetchCommentsUsecaseInput {
+    pub article_title_slug: String,
+    pub current_user: Option<User>,
+    pub order: CommentOrder,
+    pub after: Option<CommentCursor>,
+    pub limit: i64,
 }
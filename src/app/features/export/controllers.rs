@@ -0,0 +1,40 @@
+use actix_web::web;
+use serde::Deserialize;
+
+use crate::{app::drivers::middlewares::state::AppState, utils::api::ApiResponse};
+
+use super::entities::ExportFormat;
+
+type ArticleTitleSlug = String;
+type UsernameSlug = String;
+
+#[derive(Deserialize)]
+pub struct ExportQueryParameter {
+    format: Option<String>,
+}
+
+pub async fn article(
+    state: web::Data<AppState>,
+    path: web::Path<ArticleTitleSlug>,
+    params: web::Query<ExportQueryParameter>,
+) -> ApiResponse {
+    let format = ExportFormat::parse(params.format.as_deref())?;
+    let article_title_slug = path.into_inner();
+    state
+        .di_container
+        .export_usecase
+        .export_article(&article_title_slug, format)
+}
+
+pub async fn profile(
+    state: web::Data<AppState>,
+    path: web::Path<UsernameSlug>,
+    params: web::Query<ExportQueryParameter>,
+) -> ApiResponse {
+    let format = ExportFormat::parse(params.format.as_deref())?;
+    let username = path.into_inner();
+    state
+        .di_container
+        .export_usecase
+        .export_profile(&username, format)
+}
@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashSet;
+
+use crate::{
+    app::features::{article::entities::Article, tag::entities::Tag, user::entities::User},
+    error::AppError,
+    utils::{front_matter, markdown::escape_html, mention},
+};
+
+const PUBLISHED_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Markdown,
+    Html,
+}
+
+impl ExportFormat {
+    pub fn parse(format: Option<&str>) -> Result<Self, AppError> {
+        match format.map(str::to_lowercase).as_deref() {
+            None | Some("md") | Some("markdown") => Ok(ExportFormat::Markdown),
+            Some("html") => Ok(ExportFormat::Html),
+            Some(other) => Err(AppError::UnprocessableEntity(json!({
+                "error": format!("Unknown export format {}, use md or html.", other)
+            }))),
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "md",
+            ExportFormat::Html => "html",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "text/markdown; charset=utf-8",
+            ExportFormat::Html => "text/html; charset=utf-8",
+        }
+    }
+}
+
+// Uses the same keys the Markdown import reads, so an export can be imported again.
+#[derive(Serialize, Deserialize)]
+pub struct ExportFrontMatter {
+    pub title: String,
+    pub description: String,
+    pub tags: Vec<String>,
+    pub published: String,
+    pub slug: String,
+    pub author: String,
+}
+
+pub struct ExportedArticle {
+    pub article: Article,
+    pub author: User,
+    pub tags: Vec<Tag>,
+    // Users mentioned in the body, whose mentions link to their profiles.
+    pub mentions: HashSet<String>,
+}
+
+impl ExportedArticle {
+    pub fn file_name(&self, format: ExportFormat) -> String {
+        format!("{}.{}", self.article.slug, format.extension())
+    }
+
+    fn tag_names(&self) -> Vec<String> {
+        self.tags.iter().map(|tag| tag.name.clone()).collect()
+    }
+
+    fn published(&self) -> String {
+        self.article.created_at.format(PUBLISHED_FORMAT).to_string()
+    }
+
+    // `site_url` is where the frontend lives, the profiles mentions link to among others.
+    pub fn render(&self, format: ExportFormat, site_url: &str) -> Result<String, AppError> {
+        match format {
+            ExportFormat::Markdown => self.to_markdown(),
+            ExportFormat::Html => Ok(self.to_html_page(site_url)),
+        }
+    }
+
+    pub fn to_markdown(&self) -> Result<String, AppError> {
+        let meta = ExportFrontMatter {
+            title: self.article.title.clone(),
+            description: self.article.description.clone(),
+            tags: self.tag_names(),
+            published: self.published(),
+            slug: self.article.slug.clone(),
+            author: self.author.username.clone(),
+        };
+        front_matter::render(&meta, &self.article.body).map_err(|err| {
+            log::error!("failed to render front matter: {}", err);
+            AppError::InternalServerError
+        })
+    }
+
+    pub fn to_html_page(&self, site_url: &str) -> String {
+        let site_url = site_url.trim_end_matches('/');
+        let tags = self
+            .tag_names()
+            .iter()
+            .map(|name| format!("<li>{}</li>", escape_html(name)))
+            .collect::<String>();
+        let body = format!(
+            "<article>\n<h1>{}</h1>\n<p class=\"meta\">{} &middot; <time datetime=\"{}\">{}</time></p>\n<ul class=\"tags\">{}</ul>\n{}</article>\n<p><a href=\"index.html\">All articles</a></p>",
+            escape_html(&self.article.title),
+            escape_html(&self.author.username),
+            self.published(),
+            self.article.created_at.format("%B %-d, %Y"),
+            tags,
+            mention::to_html(&self.article.body, &self.mentions, |username| {
+                format!("{}/profile/{}", site_url, username)
+            }),
+        );
+        html_document(&self.article.title, &self.article.description, &body)
+    }
+}
+
+// Lists every exported article, newest first, linking to its page in the bundle.
+pub fn render_index(username: &str, list: &[ExportedArticle]) -> String {
+    let items = list
+        .iter()
+        .map(|item| {
+            format!(
+                "<li><a href=\"{}\">{}</a> <time datetime=\"{}\">{}</time><p>{}</p></li>\n",
+                escape_html(&item.file_name(ExportFormat::Html)),
+                escape_html(&item.article.title),
+                item.published(),
+                item.article.created_at.format("%B %-d, %Y"),
+                escape_html(&item.article.description),
+            )
+        })
+        .collect::<String>();
+    let title = format!("Articles by {}", username);
+    let body = format!(
+        "<h1>{}</h1>\n<ul class=\"articles\">\n{}</ul>",
+        escape_html(&title),
+        items
+    );
+    html_document(&title, "", &body)
+}
+
+fn html_document(title: &str, description: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n<meta name=\"description\" content=\"{}\">\n<title>{}</title>\n</head>\n<body>\n{}\n</body>\n</html>\n",
+        escape_html(description),
+        escape_html(title),
+        body
+    )
+}
+
+pub struct ExportFile {
+    pub name: String,
+    pub content_type: &'static str,
+    pub data: Vec<u8>,
+}
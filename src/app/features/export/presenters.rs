@@ -0,0 +1,32 @@
+use actix_web::{
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    HttpResponse,
+};
+
+use super::entities::ExportFile;
+
+pub trait ExportPresenter: Send + Sync + 'static {
+    fn to_file(&self, file: ExportFile) -> HttpResponse;
+}
+
+#[derive(Clone)]
+pub struct ExportPresenterImpl {}
+
+impl ExportPresenterImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl ExportPresenter for ExportPresenterImpl {
+    fn to_file(&self, file: ExportFile) -> HttpResponse {
+        let disposition = ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(file.name)],
+        };
+        HttpResponse::Ok()
+            .content_type(file.content_type)
+            .insert_header(disposition)
+            .body(file.data)
+    }
+}
@@ -0,0 +1,82 @@
+use diesel::prelude::*;
+
+use crate::{
+    app::features::{
+        article::entities::Article, mention::entities::Mention, tag::entities::Tag,
+        user::entities::User,
+    },
+    error::AppError,
+    schema::articles,
+    utils::db::DbPool,
+};
+
+use super::entities::ExportedArticle;
+
+pub trait ExportRepository: Send + Sync + 'static {
+    fn fetch_article(&self, article_title_slug: &str) -> Result<ExportedArticle, AppError>;
+    fn fetch_author_articles(
+        &self,
+        username: &str,
+    ) -> Result<(User, Vec<ExportedArticle>), AppError>;
+}
+
+#[derive(Clone)]
+pub struct ExportRepositoryImpl {
+    pool: DbPool,
+}
+
+impl ExportRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl ExportRepository for ExportRepositoryImpl {
+    fn fetch_article(&self, article_title_slug: &str) -> Result<ExportedArticle, AppError> {
+        let conn = &mut self.pool.get()?;
+        let (article, author) = Article::fetch_by_slug_with_author(conn, article_title_slug)?;
+        let tags = Tag::fetch_by_article_id(conn, &article.id)?;
+        let mentions = Mention::fetch_usernames_by_article_ids(conn, &[article.id])?
+            .remove(&article.id)
+            .unwrap_or_default();
+        Ok(ExportedArticle {
+            article,
+            author,
+            tags,
+            mentions,
+        })
+    }
+
+    fn fetch_author_articles(
+        &self,
+        username: &str,
+    ) -> Result<(User, Vec<ExportedArticle>), AppError> {
+        let conn = &mut self.pool.get()?;
+        let author = User::find_by_username(conn, username)?;
+        let article_list = articles::table
+            .filter(articles::author_id.eq(author.id))
+            .filter(Article::not_deleted())
+            .order(articles::created_at.desc())
+            .load::<Article>(conn)?;
+        let tags_list = Tag::belonging_to(&article_list)
+            .order(crate::schema::tags::name.asc())
+            .load::<Tag>(conn)?
+            .grouped_by(&article_list);
+        let article_ids = article_list
+            .iter()
+            .map(|article| article.id)
+            .collect::<Vec<_>>();
+        let mut mentions = Mention::fetch_usernames_by_article_ids(conn, &article_ids)?;
+        let list = article_list
+            .into_iter()
+            .zip(tags_list)
+            .map(|(article, tags)| ExportedArticle {
+                mentions: mentions.remove(&article.id).unwrap_or_default(),
+                article,
+                author: author.clone(),
+                tags,
+            })
+            .collect();
+        Ok((author, list))
+    }
+}
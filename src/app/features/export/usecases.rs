@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use actix_web::HttpResponse;
+
+use crate::{
+    error::AppError,
+    utils::archive::{self, ArchiveEntry},
+};
+
+use super::{
+    entities::{render_index, ExportFile, ExportFormat},
+    presenters::ExportPresenter,
+    repositories::ExportRepository,
+};
+
+const ZIP_CONTENT_TYPE: &str = "application/zip";
+
+#[derive(Clone)]
+pub struct ExportUsecase {
+    export_repository: Arc<dyn ExportRepository>,
+    export_presenter: Arc<dyn ExportPresenter>,
+    frontend_origin: String,
+}
+
+impl ExportUsecase {
+    pub fn new(
+        export_repository: Arc<dyn ExportRepository>,
+        export_presenter: Arc<dyn ExportPresenter>,
+        frontend_origin: String,
+    ) -> Self {
+        Self {
+            export_repository,
+            export_presenter,
+            frontend_origin,
+        }
+    }
+
+    pub fn export_article(
+        &self,
+        article_title_slug: &str,
+        format: ExportFormat,
+    ) -> Result<HttpResponse, AppError> {
+        let exported = self.export_repository.fetch_article(article_title_slug)?;
+        let file = ExportFile {
+            name: exported.file_name(format),
+            content_type: format.content_type(),
+            data: exported.render(format, &self.frontend_origin)?.into_bytes(),
+        };
+        let res = self.export_presenter.to_file(file);
+        Ok(res)
+    }
+
+    // Bundles every published article of the author into one ZIP archive. The
+    // HTML bundle is a static site with an index page linking to each article.
+    pub fn export_profile(
+        &self,
+        username: &str,
+        format: ExportFormat,
+    ) -> Result<HttpResponse, AppError> {
+        let (author, list) = self.export_repository.fetch_author_articles(username)?;
+        let mut entries = list
+            .iter()
+            .map(|exported| {
+                Ok(ArchiveEntry {
+                    name: exported.file_name(format),
+                    data: exported.render(format, &self.frontend_origin)?.into_bytes(),
+                })
+            })
+            .collect::<Result<Vec<_>, AppError>>()?;
+        if format == ExportFormat::Html {
+            entries.push(ArchiveEntry {
+                name: "index.html".to_owned(),
+                data: render_index(&author.username, &list).into_bytes(),
+            });
+        }
+        let data = archive::write_zip(&entries).map_err(|err| {
+            log::error!("failed to write export archive: {}", err);
+            AppError::InternalServerError
+        })?;
+        let file = ExportFile {
+            name: format!("{}-{}.zip", author.username, format.extension()),
+            content_type: ZIP_CONTENT_TYPE,
+            data,
+        };
+        let res = self.export_presenter.to_file(file);
+        Ok(res)
+    }
+}
@@ -6,6 +6,7 @@ use diesel::{
     deserialize::Queryable,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 use crate::error::AppError;
@@ -47,18 +48,48 @@ impl Favorite {
         Ok(item)
     }
 
-    pub fn fetch_favorited_artcile_ids_by_username(
-        conn: &mut PgConnection,
+    // Subquery of the articles favorited by the user.
+    pub fn article_ids_by_username(
         username: &str,
-    ) -> Result<Vec<Uuid>, AppError> {
+    ) -> favorites::BoxedQuery<'_, diesel::pg::Pg, sql_types::Uuid> {
         use crate::schema::users;
 
-        let t = favorites::table
-            .inner_join(users::table)
-            .filter(User::with_username(username))
-            .select(favorites::article_id);
-        let ids = t.load::<Uuid>(conn)?;
-        Ok(ids)
+        favorites::table
+            .filter(
+                favorites::user_id.eq_any(
+                    users::table
+                        .filter(User::with_username(username))
+                        .select(users::id),
+                ),
+            )
+            .select(favorites::article_id)
+            .into_boxed()
+    }
+
+    // Favorites count of each article, articles nobody favorited are left out.
+    pub fn fetch_counts_by_article_ids(
+        conn: &mut PgConnection,
+        article_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, i64>, AppError> {
+        let counts = favorites::table
+            .filter(favorites::article_id.eq_any(article_ids))
+            .group_by(favorites::article_id)
+            .select((favorites::article_id, dsl::count(favorites::id)))
+            .load::<(Uuid, i64)>(conn)?;
+        Ok(counts.into_iter().collect())
+    }
+
+    pub fn fetch_favorited_article_ids(
+        conn: &mut PgConnection,
+        user_id: &Uuid,
+        article_ids: &[Uuid],
+    ) -> Result<HashSet<Uuid>, AppError> {
+        let ids = favorites::table
+            .filter(Self::with_user_id(user_id))
+            .filter(favorites::article_id.eq_any(article_ids))
+            .select(favorites::article_id)
+            .load::<Uuid>(conn)?;
+        Ok(ids.into_iter().collect())
     }
 
     pub fn delete(
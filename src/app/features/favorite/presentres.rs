@@ -1,15 +1,11 @@
 use actix_web::HttpResponse;
 
-use crate::app::features::{
-    article::{entities::Article, presenters::SingleArticleResponse},
-    profile::entities::Profile,
-    tag::entities::Tag,
+use crate::app::features::article::{
+    presenters::SingleArticleResponse, repositories::SingleArticle,
 };
 
-use super::entities::FavoriteInfo;
-
 pub trait FavoritePresenter: Send + Sync + 'static {
-    fn to_single_json(&self, item: (Article, Profile, FavoriteInfo, Vec<Tag>)) -> HttpResponse;
+    fn to_single_json(&self, item: SingleArticle) -> HttpResponse;
 }
 
 #[derive(Clone)]
@@ -22,11 +18,8 @@ impl FavoritePresenterImpl {
 }
 
 impl FavoritePresenter for FavoritePresenterImpl {
-    fn to_single_json(
-        &self,
-        (article, profile, favorite_info, tags): (Article, Profile, FavoriteInfo, Vec<Tag>),
-    ) -> HttpResponse {
-        let res_model = SingleArticleResponse::from((article, profile, favorite_info, tags));
+    fn to_single_json(&self, item: SingleArticle) -> HttpResponse {
+        let res_model = SingleArticleResponse::from(item);
         HttpResponse::Ok().json(res_model)
     }
 }
@@ -4,7 +4,10 @@ use actix_web::HttpResponse;
 
 use crate::{
     app::features::{
-        article::repositories::{ArticleRepository, FetchArticleRepositoryInput},
+        article::{
+            repositories::{ArticleRepository, FetchArticleRepositoryInput},
+            usecases::RelatedArticlesCache,
+        },
         user::entities::User,
     },
     error::AppError,
@@ -17,6 +20,7 @@ pub struct FavoriteUsecase {
     favorite_repository: Arc<dyn FavoriteRepository>,
     favorite_presenter: Arc<dyn FavoritePresenter>,
     article_repository: Arc<dyn ArticleRepository>,
+    related_articles_cache: RelatedArticlesCache,
 }
 
 impl FavoriteUsecase {
@@ -24,11 +28,13 @@ impl FavoriteUsecase {
         favorite_repository: Arc<dyn FavoriteRepository>,
         favorite_presenter: Arc<dyn FavoritePresenter>,
         article_repository: Arc<dyn ArticleRepository>,
+        related_articles_cache: RelatedArticlesCache,
     ) -> Self {
         Self {
             favorite_repository,
             favorite_presenter,
             article_repository,
+            related_articles_cache,
         }
     }
 
@@ -40,6 +46,7 @@ impl FavoriteUsecase {
         let article = self
             .favorite_repository
             .favorite_article(user.clone(), article_title_slug)?;
+        self.related_articles_cache.clear();
 
         let result = self
             .article_repository
@@ -59,6 +66,7 @@ impl FavoriteUsecase {
         let article = self
             .favorite_repository
             .unfavorite_article(user.clone(), article_title_slug)?;
+        self.related_articles_cache.clear();
         let result = self
             .article_repository
             .fetch_article(&FetchArticleRepositoryInput {
@@ -1,7 +1,12 @@
-use crate::{app::features::user::entities::User, error::AppError, schema::follows};
+use crate::{
+    app::features::{profile::entities::FollowCounts, user::entities::User},
+    error::AppError,
+    schema::{follows, users},
+};
 use chrono::NaiveDateTime;
 use diesel::{dsl::Eq, prelude::*};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 #[derive(Queryable, Associations, Clone, Serialize, Deserialize)]
@@ -52,6 +57,84 @@ impl Follow {
         let result = t.get_results::<Uuid>(conn)?;
         Ok(result)
     }
+
+    // Which of the given users the follower follows.
+    pub fn fetch_followed_ids(
+        conn: &mut PgConnection,
+        follower_id: &Uuid,
+        followee_ids: &[Uuid],
+    ) -> Result<HashSet<Uuid>, AppError> {
+        let ids = follows::table
+            .filter(Follow::with_follower(follower_id))
+            .filter(follows::followee_id.eq_any(followee_ids))
+            .select(follows::followee_id)
+            .load::<Uuid>(conn)?;
+        Ok(ids.into_iter().collect())
+    }
+
+    // The users following the followee, most recent first.
+    pub fn fetch_followers(
+        conn: &mut PgConnection,
+        followee_id: &Uuid,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<User>, AppError> {
+        let list = follows::table
+            .inner_join(users::table.on(users::id.eq(follows::follower_id)))
+            .filter(Follow::with_followee(followee_id))
+            .order((follows::created_at.desc(), users::id.asc()))
+            .select(User::as_select())
+            .offset(offset)
+            .limit(limit)
+            .load::<User>(conn)?;
+        Ok(list)
+    }
+
+    // The users the follower follows, most recent first.
+    pub fn fetch_following(
+        conn: &mut PgConnection,
+        follower_id: &Uuid,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<User>, AppError> {
+        let list = follows::table
+            .inner_join(users::table.on(users::id.eq(follows::followee_id)))
+            .filter(Follow::with_follower(follower_id))
+            .order((follows::created_at.desc(), users::id.asc()))
+            .select(User::as_select())
+            .offset(offset)
+            .limit(limit)
+            .load::<User>(conn)?;
+        Ok(list)
+    }
+
+    // Followers and followees of each user, zero for users without any.
+    pub fn fetch_counts(
+        conn: &mut PgConnection,
+        user_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, FollowCounts>, AppError> {
+        let followers = follows::table
+            .filter(follows::followee_id.eq_any(user_ids))
+            .group_by(follows::followee_id)
+            .select((follows::followee_id, diesel::dsl::count_star()))
+            .load::<(Uuid, i64)>(conn)?;
+        let following = follows::table
+            .filter(follows::follower_id.eq_any(user_ids))
+            .group_by(follows::follower_id)
+            .select((follows::follower_id, diesel::dsl::count_star()))
+            .load::<(Uuid, i64)>(conn)?;
+        let mut counts = user_ids
+            .iter()
+            .map(|id| (*id, FollowCounts::default()))
+            .collect::<HashMap<_, _>>();
+        for (id, count) in followers {
+            counts.entry(id).or_default().followers_count = count;
+        }
+        for (id, count) in following {
+            counts.entry(id).or_default().following_count = count;
+        }
+        Ok(counts)
+    }
 }
 
 #[derive(Insertable)]
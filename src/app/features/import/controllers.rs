@@ -0,0 +1,50 @@
+use actix_multipart::Multipart;
+use actix_web::{web, HttpRequest};
+use futures_util::StreamExt;
+use serde_json::json;
+
+use crate::{
+    app::drivers::middlewares::{auth, state::AppState},
+    constants::IMPORT_MAX_UPLOAD_BYTES,
+    error::AppError,
+    utils::api::ApiResponse,
+};
+
+use super::entities::UploadedFile;
+
+pub async fn import(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    mut payload: Multipart,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let mut uploads = vec![];
+    let mut total_bytes = 0;
+    while let Some(field) = payload.next().await {
+        let mut field = field?;
+        // Plain form fields carry no file name and are ignored.
+        let name = match field
+            .content_disposition()
+            .and_then(|disposition| disposition.get_filename())
+        {
+            Some(name) => name.to_owned(),
+            None => continue,
+        };
+        let mut data = vec![];
+        while let Some(chunk) = field.next().await {
+            let chunk = chunk?;
+            total_bytes += chunk.len();
+            if total_bytes > IMPORT_MAX_UPLOAD_BYTES {
+                return Err(AppError::UnprocessableEntity(json!({
+                    "error": format!("The upload is larger than {} bytes.", IMPORT_MAX_UPLOAD_BYTES)
+                })));
+            }
+            data.extend_from_slice(&chunk);
+        }
+        uploads.push(UploadedFile { name, data });
+    }
+    state
+        .di_container
+        .import_usecase
+        .import_articles(current_user, uploads)
+}
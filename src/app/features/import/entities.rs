@@ -0,0 +1,149 @@
+use std::collections::HashSet;
+
+use chrono::NaiveDateTime;
+use serde::Deserialize;
+
+use crate::{
+    app::features::article::{entities::Article, repositories::SingleArticle},
+    constants::IMPORT_MAX_FILE_BYTES,
+    utils::{converter, date, front_matter},
+};
+
+const MARKDOWN_EXTENSIONS: [&str; 2] = [".md", ".markdown"];
+
+pub struct UploadedFile {
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+impl UploadedFile {
+    pub fn is_zip(&self) -> bool {
+        self.name.to_lowercase().ends_with(".zip")
+    }
+}
+
+pub fn is_markdown(file_name: &str) -> bool {
+    let file_name = file_name.to_lowercase();
+    MARKDOWN_EXTENSIONS
+        .iter()
+        .any(|extension| file_name.ends_with(extension))
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+pub struct FrontMatter {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub tags: Option<FrontMatterTags>,
+    #[serde(alias = "date", alias = "publishedAt", alias = "published_at")]
+    pub published: Option<String>,
+    pub slug: Option<String>,
+}
+
+// Tags may be written as a YAML list or as a comma separated string.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum FrontMatterTags {
+    List(Vec<String>),
+    Text(String),
+}
+
+impl FrontMatterTags {
+    fn into_names(self) -> Vec<String> {
+        let names = match self {
+            FrontMatterTags::List(list) => list,
+            FrontMatterTags::Text(text) => text.split(',').map(str::to_owned).collect(),
+        };
+        let mut seen = HashSet::new();
+        names
+            .into_iter()
+            .map(|name| name.trim().to_owned())
+            .filter(|name| !name.is_empty() && seen.insert(name.clone()))
+            .collect()
+    }
+}
+
+pub struct ImportedArticle {
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub tag_list: Vec<String>,
+    pub published_at: Option<NaiveDateTime>,
+}
+
+impl ImportedArticle {
+    // Reads one Markdown file, collecting every validation error instead of stopping at the first.
+    pub fn parse(data: &[u8]) -> Result<Self, Vec<String>> {
+        if data.len() > IMPORT_MAX_FILE_BYTES {
+            return Err(vec![format!(
+                "file is larger than {} bytes.",
+                IMPORT_MAX_FILE_BYTES
+            )]);
+        }
+        let document =
+            std::str::from_utf8(data).map_err(|_| vec!["file is not valid UTF-8.".to_owned()])?;
+        let (yaml, body) = front_matter::split(document);
+        let front_matter = match yaml {
+            Some(yaml) if !yaml.trim().is_empty() => serde_yaml::from_str::<FrontMatter>(yaml)
+                .map_err(|err| vec![format!("front matter is not valid YAML: {}", err)])?,
+            _ => FrontMatter::default(),
+        };
+
+        let mut errors = vec![];
+        let title = front_matter
+            .title
+            .map(|title| title.trim().to_owned())
+            .unwrap_or_default();
+        if title.is_empty() {
+            errors.push("title can't be blank.".to_owned());
+        }
+        let body = body.trim().to_owned();
+        if body.is_empty() {
+            errors.push("body can't be blank.".to_owned());
+        }
+        let slug = front_matter
+            .slug
+            .map(|slug| converter::to_kebab(slug.trim()))
+            .filter(|slug| !slug.is_empty())
+            .unwrap_or_else(|| Article::convert_title_to_slug(&title));
+        let published_at = match front_matter.published {
+            Some(published) => {
+                let parsed = date::parse_date_time(published.trim());
+                if parsed.is_none() {
+                    errors.push(format!(
+                        "published date {} must look like 2024-01-31 or 2024-01-31T09:00:00Z.",
+                        published
+                    ));
+                }
+                parsed
+            }
+            None => None,
+        };
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(Self {
+            slug,
+            title,
+            description: front_matter.description.unwrap_or_default(),
+            body,
+            tag_list: front_matter
+                .tags
+                .map(FrontMatterTags::into_names)
+                .unwrap_or_default(),
+            published_at,
+        })
+    }
+}
+
+pub enum ImportOutcome {
+    Created(Box<SingleArticle>),
+    Failed(Vec<String>),
+}
+
+pub struct ImportFileReport {
+    pub file: String,
+    pub outcome: ImportOutcome,
+}
@@ -0,0 +1,89 @@
+use actix_web::HttpResponse;
+use serde::{Deserialize, Serialize};
+
+use crate::app::features::article::presenters::ArticleContent;
+
+use super::entities::{ImportFileReport, ImportOutcome};
+
+#[derive(Deserialize, Serialize)]
+pub struct ImportReportResponse {
+    pub import: ImportReportContent,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportReportContent {
+    pub created_count: usize,
+    pub failed_count: usize,
+    pub files: Vec<ImportFileContent>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ImportFileContent {
+    pub file: String,
+    pub status: ImportStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub article: Option<ArticleContent>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportStatus {
+    Created,
+    Failed,
+}
+
+impl From<Vec<ImportFileReport>> for ImportReportResponse {
+    fn from(list: Vec<ImportFileReport>) -> Self {
+        let files = list
+            .into_iter()
+            .map(|report| match report.outcome {
+                ImportOutcome::Created(article) => ImportFileContent {
+                    file: report.file,
+                    status: ImportStatus::Created,
+                    article: Some(ArticleContent::from(*article)),
+                    errors: vec![],
+                },
+                ImportOutcome::Failed(errors) => ImportFileContent {
+                    file: report.file,
+                    status: ImportStatus::Failed,
+                    article: None,
+                    errors,
+                },
+            })
+            .collect::<Vec<_>>();
+        let created_count = files
+            .iter()
+            .filter(|file| file.status == ImportStatus::Created)
+            .count();
+        Self {
+            import: ImportReportContent {
+                created_count,
+                failed_count: files.len() - created_count,
+                files,
+            },
+        }
+    }
+}
+
+pub trait ImportPresenter: Send + Sync + 'static {
+    fn to_report_json(&self, list: Vec<ImportFileReport>) -> HttpResponse;
+}
+
+#[derive(Clone)]
+pub struct ImportPresenterImpl {}
+
+impl ImportPresenterImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl ImportPresenter for ImportPresenterImpl {
+    fn to_report_json(&self, list: Vec<ImportFileReport>) -> HttpResponse {
+        let res = ImportReportResponse::from(list);
+        HttpResponse::Ok().json(res)
+    }
+}
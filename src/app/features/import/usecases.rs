@@ -0,0 +1,179 @@
+use std::{collections::HashSet, sync::Arc};
+
+use actix_web::HttpResponse;
+use serde_json::json;
+
+use crate::{
+    app::features::{
+        article::{
+            entities::ReadingMetadata,
+            repositories::{ArticleRepository, CreateArticleRepositoryInput},
+            usecases::RelatedArticlesCache,
+        },
+        user::entities::User,
+    },
+    constants::{
+        IMPORT_MAX_ARCHIVE_ENTRIES, IMPORT_MAX_FILES, IMPORT_MAX_FILE_BYTES,
+        IMPORT_MAX_UNPACKED_BYTES,
+    },
+    error::AppError,
+    utils::archive::{self, ZipLimits},
+};
+
+use super::{
+    entities::{is_markdown, ImportFileReport, ImportOutcome, ImportedArticle, UploadedFile},
+    presenters::ImportPresenter,
+};
+
+type ParsedFile = (String, Result<ImportedArticle, Vec<String>>);
+
+#[derive(Clone)]
+pub struct ImportUsecase {
+    article_repository: Arc<dyn ArticleRepository>,
+    import_presenter: Arc<dyn ImportPresenter>,
+    related_articles_cache: RelatedArticlesCache,
+}
+
+impl ImportUsecase {
+    pub fn new(
+        article_repository: Arc<dyn ArticleRepository>,
+        import_presenter: Arc<dyn ImportPresenter>,
+        related_articles_cache: RelatedArticlesCache,
+    ) -> Self {
+        Self {
+            article_repository,
+            import_presenter,
+            related_articles_cache,
+        }
+    }
+
+    pub fn import_articles(
+        &self,
+        current_user: User,
+        uploads: Vec<UploadedFile>,
+    ) -> Result<HttpResponse, AppError> {
+        current_user.ensure_not_held()?;
+        let mut files = Self::parse_uploads(uploads);
+        if files.is_empty() {
+            return Err(AppError::UnprocessableEntity(
+                json!({"error": "Upload at least one Markdown file or ZIP archive."}),
+            ));
+        }
+        if files.len() > IMPORT_MAX_FILES {
+            return Err(AppError::UnprocessableEntity(
+                json!({"error": format!("At most {} files can be imported at once.", IMPORT_MAX_FILES)}),
+            ));
+        }
+
+        let mut slugs = HashSet::new();
+        for (_, parsed) in files.iter_mut() {
+            if let Ok(article) = parsed {
+                if !slugs.insert(article.slug.clone()) {
+                    *parsed = Err(vec![format!(
+                        "slug {} is used by another file in this import.",
+                        article.slug
+                    )]);
+                }
+            }
+        }
+
+        let inputs = files
+            .iter()
+            .filter_map(|(_, parsed)| parsed.as_ref().ok())
+            .map(|article| CreateArticleRepositoryInput {
+                slug: article.slug.clone(),
+                title: article.title.clone(),
+                description: article.description.clone(),
+                body: article.body.clone(),
+                reading_metadata: ReadingMetadata::from_body(&article.body),
+                tag_name_list: Some(article.tag_list.clone()),
+                published_at: article.published_at,
+                meta_title: None,
+                meta_description: None,
+                canonical_url: None,
+                cover_image: None,
+                current_user: current_user.clone(),
+            })
+            .collect::<Vec<_>>();
+        let mut created = self.article_repository.create_articles(inputs)?.into_iter();
+
+        let report = files
+            .into_iter()
+            .map(|(file, parsed)| {
+                let outcome = match parsed.map(|_| created.next()) {
+                    Ok(Some(Ok(article))) => ImportOutcome::Created(Box::new(article)),
+                    Ok(Some(Err(err))) => ImportOutcome::Failed(vec![Self::error_message(err)]),
+                    Ok(None) => ImportOutcome::Failed(vec!["file was not imported.".to_owned()]),
+                    Err(errors) => ImportOutcome::Failed(errors),
+                };
+                ImportFileReport { file, outcome }
+            })
+            .collect::<Vec<_>>();
+        if report
+            .iter()
+            .any(|item| matches!(item.outcome, ImportOutcome::Created(_)))
+        {
+            self.related_articles_cache.clear();
+        }
+        let res = self.import_presenter.to_report_json(report);
+        Ok(res)
+    }
+
+    // Unpacks ZIP archives and parses every Markdown file, keeping the upload order.
+    // The archives of one import share a budget of entries and unpacked bytes.
+    fn parse_uploads(uploads: Vec<UploadedFile>) -> Vec<ParsedFile> {
+        let mut files = vec![];
+        let mut limits = ZipLimits {
+            max_entries: IMPORT_MAX_ARCHIVE_ENTRIES,
+            max_entry_bytes: IMPORT_MAX_FILE_BYTES,
+            max_total_bytes: IMPORT_MAX_UNPACKED_BYTES,
+        };
+        for upload in uploads {
+            if upload.is_zip() {
+                match archive::read_zip(&upload.data, &limits) {
+                    Ok(entries) => {
+                        limits.max_entries -= entries.len();
+                        limits.max_total_bytes -=
+                            entries.iter().map(|entry| entry.data.len()).sum::<usize>();
+                        files.extend(
+                            entries
+                                .into_iter()
+                                .filter(|entry| is_markdown(&entry.name))
+                                .filter(|entry| !entry.name.starts_with("__MACOSX/"))
+                                .map(|entry| {
+                                    (
+                                        format!("{}/{}", upload.name, entry.name),
+                                        ImportedArticle::parse(&entry.data),
+                                    )
+                                }),
+                        )
+                    }
+                    Err(err) => files.push((upload.name, Err(vec![err.to_string()]))),
+                }
+            } else if is_markdown(&upload.name) {
+                let parsed = ImportedArticle::parse(&upload.data);
+                files.push((upload.name, parsed));
+            } else {
+                files.push((
+                    upload.name,
+                    Err(vec![
+                        "only Markdown (.md, .markdown) files and ZIP archives can be imported."
+                            .to_owned(),
+                    ]),
+                ));
+            }
+        }
+        files
+    }
+
+    fn error_message(err: AppError) -> String {
+        match err {
+            AppError::UnprocessableEntity(value) => value
+                .get("error")
+                .and_then(|message| message.as_str())
+                .map(str::to_owned)
+                .unwrap_or_else(|| value.to_string()),
+            err => err.to_string(),
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use chrono::{NaiveDateTime, Utc};
+use diesel::prelude::*;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::{
+    app::features::{
+        block::entities::Block,
+        comment::entities::Comment,
+        notification::entities::{CreateNotification, Notification, NotificationKind},
+        user::entities::User,
+    },
+    error::AppError,
+    schema::{mentions, users},
+    utils::mention,
+};
+
+#[derive(Identifiable, Queryable, Debug, Clone)]
+#[diesel(table_name = mentions)]
+pub struct Mention {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub article_id: Uuid,
+    pub comment_id: Option<Uuid>,
+    pub created_at: NaiveDateTime,
+    // Set while the user is edited out of the text.
+    pub removed_at: Option<NaiveDateTime>,
+}
+
+// The text a mention is written in: the body of an article or of one of its comments.
+#[derive(Clone, Copy, Debug)]
+pub enum MentionSource {
+    Article { article_id: Uuid },
+    Comment { article_id: Uuid, comment_id: Uuid },
+}
+
+impl MentionSource {
+    fn article_id(&self) -> Uuid {
+        match self {
+            MentionSource::Article { article_id } => *article_id,
+            MentionSource::Comment { article_id, .. } => *article_id,
+        }
+    }
+
+    fn comment_id(&self) -> Option<Uuid> {
+        match self {
+            MentionSource::Article { .. } => None,
+            MentionSource::Comment { comment_id, .. } => Some(*comment_id),
+        }
+    }
+}
+
+impl Mention {
+    // Brings the mentions of the source in line with `text`, notifying the users
+    // mentioned in it for the first time unless `notify` is off. Users blocking
+    // the author aren't notified. Usernames nobody goes by are ignored.
+    pub fn record(
+        conn: &mut PgConnection,
+        source: MentionSource,
+        author: &User,
+        text: &str,
+        notify: bool,
+    ) -> Result<(), AppError> {
+        let mut user_ids = vec![];
+        for username in mention::usernames(text) {
+            match User::find_by_username(conn, &username) {
+                Ok(user) => user_ids.push(user.id),
+                Err(AppError::NotFound(_)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        let added = Self::sync(conn, source, &user_ids)?;
+        if !notify {
+            return Ok(());
+        }
+        Self::notify(conn, source, &author.id, added)
+    }
+
+    // Once a moderator releases a held comment, notifies the users it mentions
+    // that weren't notified before it was held.
+    pub fn notify_released(conn: &mut PgConnection, comment: &Comment) -> Result<(), AppError> {
+        let notified_ids = Notification::fetch_user_ids_by_comment_id(
+            conn,
+            NotificationKind::Mention,
+            &comment.id,
+        )?;
+        let user_ids = mentions::table
+            .filter(mentions::comment_id.eq(comment.id))
+            .filter(mentions::removed_at.is_null())
+            .filter(mentions::user_id.ne_all(notified_ids))
+            .select(mentions::user_id)
+            .load::<Uuid>(conn)?;
+        let source = MentionSource::Comment {
+            article_id: comment.article_id,
+            comment_id: comment.id,
+        };
+        Self::notify(conn, source, &comment.author_id, user_ids)
+    }
+
+    // Users blocking the author aren't notified, nor is the author.
+    fn notify(
+        conn: &mut PgConnection,
+        source: MentionSource,
+        author_id: &Uuid,
+        user_ids: Vec<Uuid>,
+    ) -> Result<(), AppError> {
+        let blocker_ids = Block::fetch_blocker_ids(conn, &user_ids, author_id)?;
+        let records = user_ids
+            .into_iter()
+            .filter(|user_id| user_id != author_id && !blocker_ids.contains(user_id))
+            .map(|user_id| CreateNotification {
+                user_id,
+                actor_id: *author_id,
+                kind: NotificationKind::Mention.as_str(),
+                article_id: Some(source.article_id()),
+                comment_id: source.comment_id(),
+            })
+            .collect::<Vec<_>>();
+        Notification::create_list(conn, &records)
+    }
+
+    // Replaces the mentions of the source with `user_ids`, returning the users
+    // that were never mentioned there before. Mentions edited out are only marked
+    // removed, so editing a user out and back in doesn't count as new.
+    fn sync(
+        conn: &mut PgConnection,
+        source: MentionSource,
+        user_ids: &[Uuid],
+    ) -> Result<Vec<Uuid>, AppError> {
+        let mut query = mentions::table
+            .filter(mentions::article_id.eq(source.article_id()))
+            .into_boxed();
+        query = match source.comment_id() {
+            Some(comment_id) => query.filter(mentions::comment_id.eq(comment_id)),
+            None => query.filter(mentions::comment_id.is_null()),
+        };
+        let existing = query.load::<Self>(conn)?;
+        let removed = existing
+            .iter()
+            .filter(|mention| mention.removed_at.is_none())
+            .filter(|mention| !user_ids.contains(&mention.user_id))
+            .map(|mention| mention.id)
+            .collect::<Vec<_>>();
+        if !removed.is_empty() {
+            diesel::update(mentions::table.filter(mentions::id.eq_any(removed)))
+                .set(mentions::removed_at.eq(Some(Utc::now().naive_utc())))
+                .execute(conn)?;
+        }
+        let restored = existing
+            .iter()
+            .filter(|mention| mention.removed_at.is_some())
+            .filter(|mention| user_ids.contains(&mention.user_id))
+            .map(|mention| mention.id)
+            .collect::<Vec<_>>();
+        if !restored.is_empty() {
+            diesel::update(mentions::table.filter(mentions::id.eq_any(restored)))
+                .set(mentions::removed_at.eq(None::<NaiveDateTime>))
+                .execute(conn)?;
+        }
+        let added = user_ids
+            .iter()
+            .filter(|user_id| !existing.iter().any(|mention| mention.user_id == **user_id))
+            .copied()
+            .collect::<Vec<_>>();
+        let records = added
+            .iter()
+            .map(|user_id| CreateMention {
+                user_id: *user_id,
+                article_id: source.article_id(),
+                comment_id: source.comment_id(),
+            })
+            .collect::<Vec<_>>();
+        if !records.is_empty() {
+            diesel::insert_into(mentions::table)
+                .values(&records)
+                .execute(conn)?;
+        }
+        Ok(added)
+    }
+
+    // Usernames mentioned in the body of each article, their comments left out.
+    pub fn fetch_usernames_by_article_ids(
+        conn: &mut PgConnection,
+        article_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, HashSet<String>>, AppError> {
+        let list = mentions::table
+            .inner_join(users::table)
+            .filter(mentions::article_id.eq_any(article_ids))
+            .filter(mentions::comment_id.is_null())
+            .filter(mentions::removed_at.is_null())
+            .select((mentions::article_id, users::username))
+            .load::<(Uuid, String)>(conn)?;
+        let mut map = HashMap::<Uuid, HashSet<String>>::new();
+        for (article_id, username) in list {
+            map.entry(article_id).or_default().insert(username);
+        }
+        Ok(map)
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = mentions)]
+pub struct CreateMention {
+    pub user_id: Uuid,
+    pub article_id: Uuid,
+    pub comment_id: Option<Uuid>,
+}
@@ -1,8 +1,25 @@
+pub mod analytics;
 pub mod article;
+pub mod block;
+pub mod bookmark;
+pub mod coauthor;
 pub mod comment;
+pub mod export;
 pub mod favorite;
 pub mod follow;
 pub mod healthcheck;
+pub mod import;
+pub mod mention;
+pub mod mute;
+pub mod notification;
 pub mod profile;
+pub mod reaction;
+pub mod report;
+pub mod seo;
+pub mod series;
+pub mod spam;
 pub mod tag;
+pub mod translation;
+pub mod trash;
+pub mod upload;
 pub mod user;
@@ -0,0 +1,56 @@
+use crate::{error::AppError, schema::mutes};
+use diesel::{dsl::Eq, prelude::*};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+// A mute only hides the muted user's content from the muter's feed and comment lists.
+pub struct Mute;
+
+type WithMuter<T> = Eq<mutes::muter_id, T>;
+
+impl Mute {
+    fn with_muter(muter_id: &Uuid) -> WithMuter<&Uuid> {
+        mutes::muter_id.eq(muter_id)
+    }
+}
+
+impl Mute {
+    // Muting twice is a no-op.
+    pub fn create(conn: &mut PgConnection, params: &CreateMute) -> Result<(), AppError> {
+        diesel::insert_into(mutes::table)
+            .values(params)
+            .on_conflict_do_nothing()
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub fn delete(
+        conn: &mut PgConnection,
+        muter_id: &Uuid,
+        muted_id: &Uuid,
+    ) -> Result<(), AppError> {
+        let t = mutes::table
+            .filter(Self::with_muter(muter_id))
+            .filter(mutes::muted_id.eq(muted_id));
+        diesel::delete(t).execute(conn)?;
+        Ok(())
+    }
+
+    pub fn fetch_muted_ids(
+        conn: &mut PgConnection,
+        muter_id: &Uuid,
+    ) -> Result<HashSet<Uuid>, AppError> {
+        let ids = mutes::table
+            .filter(Self::with_muter(muter_id))
+            .select(mutes::muted_id)
+            .load::<Uuid>(conn)?;
+        Ok(ids.into_iter().collect())
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = mutes)]
+pub struct CreateMute {
+    pub muter_id: Uuid,
+    pub muted_id: Uuid,
+}
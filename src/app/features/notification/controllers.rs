@@ -0,0 +1,62 @@
+use actix_web::{web, HttpRequest};
+use serde::Deserialize;
+
+use crate::{
+    app::drivers::middlewares::{auth, state::AppState},
+    constants::NOTIFICATIONS_PAGE_LIMIT,
+    utils::{api::ApiResponse, uuid},
+};
+
+use super::usecases::FetchNotificationsUsecaseInput;
+
+type NotificationIdSlug = String;
+
+#[derive(Deserialize)]
+pub struct NotificationsListQueryParameter {
+    unread: Option<bool>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+pub async fn index(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    params: web::Query<NotificationsListQueryParameter>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let offset = params.offset.unwrap_or(0).max(0);
+    let limit = params
+        .limit
+        .unwrap_or(20)
+        .clamp(1, NOTIFICATIONS_PAGE_LIMIT);
+    state
+        .di_container
+        .notification_usecase
+        .fetch_notifications(FetchNotificationsUsecaseInput {
+            current_user_id: current_user.id,
+            unread_only: params.unread.unwrap_or(false),
+            offset,
+            limit,
+        })
+}
+
+pub async fn read(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<NotificationIdSlug>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let notification_id = uuid::parse(&path.into_inner())?;
+    state
+        .di_container
+        .notification_usecase
+        .mark_read(current_user.id, notification_id)
+}
+
+pub async fn read_all(state: web::Data<AppState>, req: HttpRequest) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    state
+        .di_container
+        .notification_usecase
+        .mark_all_read(current_user.id)
+}
@@ -0,0 +1,151 @@
+use chrono::{NaiveDateTime, Utc};
+use diesel::{dsl::Eq, prelude::*};
+use uuid::Uuid;
+
+use crate::{
+    app::features::{article::entities::Article, user::entities::User},
+    error::AppError,
+    schema::{articles, notifications, users},
+};
+
+type WithUserId<T> = Eq<notifications::user_id, T>;
+
+#[derive(Identifiable, Queryable, Debug, Clone)]
+#[diesel(table_name = notifications)]
+pub struct Notification {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub actor_id: Uuid,
+    pub kind: String,
+    pub article_id: Option<Uuid>,
+    pub comment_id: Option<Uuid>,
+    pub read_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl Notification {
+    fn with_user_id(user_id: &Uuid) -> WithUserId<&Uuid> {
+        notifications::user_id.eq(user_id)
+    }
+}
+
+impl Notification {
+    pub fn create_list(
+        conn: &mut PgConnection,
+        records: &[CreateNotification],
+    ) -> Result<(), AppError> {
+        if records.is_empty() {
+            return Ok(());
+        }
+        diesel::insert_into(notifications::table)
+            .values(records)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    // Newest first, with who caused each one and the article it is about.
+    pub fn fetch_page(
+        conn: &mut PgConnection,
+        user_id: &Uuid,
+        unread_only: bool,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<(Self, User, Option<Article>)>, AppError> {
+        let mut query = notifications::table
+            .inner_join(users::table.on(users::id.eq(notifications::actor_id)))
+            .left_join(articles::table)
+            .filter(Self::with_user_id(user_id))
+            .select((
+                notifications::all_columns,
+                User::as_select(),
+                articles::all_columns.nullable(),
+            ))
+            .into_boxed();
+        if unread_only {
+            query = query.filter(notifications::read_at.is_null());
+        }
+        let list = query
+            .order((notifications::created_at.desc(), notifications::id.desc()))
+            .offset(offset)
+            .limit(limit)
+            .load::<(Self, User, Option<Article>)>(conn)?;
+        Ok(list)
+    }
+
+    pub fn count(
+        conn: &mut PgConnection,
+        user_id: &Uuid,
+        unread_only: bool,
+    ) -> Result<i64, AppError> {
+        let mut query = notifications::table
+            .filter(Self::with_user_id(user_id))
+            .into_boxed();
+        if unread_only {
+            query = query.filter(notifications::read_at.is_null());
+        }
+        let count = query.count().get_result::<i64>(conn)?;
+        Ok(count)
+    }
+
+    // Users already notified of `kind` about the comment.
+    pub fn fetch_user_ids_by_comment_id(
+        conn: &mut PgConnection,
+        kind: NotificationKind,
+        comment_id: &Uuid,
+    ) -> Result<Vec<Uuid>, AppError> {
+        let ids = notifications::table
+            .filter(notifications::kind.eq(kind.as_str()))
+            .filter(notifications::comment_id.eq(comment_id))
+            .select(notifications::user_id)
+            .load::<Uuid>(conn)?;
+        Ok(ids)
+    }
+
+    pub fn mark_read(conn: &mut PgConnection, user_id: &Uuid, id: &Uuid) -> Result<(), AppError> {
+        let t = notifications::table
+            .filter(Self::with_user_id(user_id))
+            .filter(notifications::id.eq(id));
+        // The first read is the one that counts.
+        let count = diesel::update(t.filter(notifications::read_at.is_null()))
+            .set(notifications::read_at.eq(Some(Utc::now().naive_utc())))
+            .execute(conn)?;
+        if count == 0 {
+            t.select(notifications::id).first::<Uuid>(conn)?;
+        }
+        Ok(())
+    }
+
+    pub fn mark_all_read(conn: &mut PgConnection, user_id: &Uuid) -> Result<(), AppError> {
+        let t = notifications::table
+            .filter(Self::with_user_id(user_id))
+            .filter(notifications::read_at.is_null());
+        diesel::update(t)
+            .set(notifications::read_at.eq(Some(Utc::now().naive_utc())))
+            .execute(conn)?;
+        Ok(())
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = notifications)]
+pub struct CreateNotification {
+    pub user_id: Uuid,
+    pub actor_id: Uuid,
+    pub kind: &'static str,
+    pub article_id: Option<Uuid>,
+    pub comment_id: Option<Uuid>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NotificationKind {
+    // Someone mentioned the user in an article or a comment.
+    Mention,
+}
+
+impl NotificationKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationKind::Mention => "mention",
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use actix_web::HttpResponse;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::utils::date::Iso8601;
+
+use super::repositories::NotificationsList;
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultipleNotificationsResponse {
+    pub notifications: Vec<NotificationContent>,
+    pub notifications_count: i64,
+    pub unread_count: i64,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationContent {
+    pub id: Uuid,
+    pub kind: String,
+    pub read: bool,
+    pub created_at: Iso8601,
+    pub actor: NotificationActorContent,
+    // Null once the article is deleted.
+    pub article: Option<NotificationArticleContent>,
+    pub comment_id: Option<Uuid>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct NotificationActorContent {
+    pub username: String,
+    pub image: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct NotificationArticleContent {
+    pub slug: String,
+    pub title: String,
+}
+
+impl From<(NotificationsList, i64, i64)> for MultipleNotificationsResponse {
+    fn from((list, notifications_count, unread_count): (NotificationsList, i64, i64)) -> Self {
+        let notifications = list
+            .into_iter()
+            .map(|(notification, actor, article)| NotificationContent {
+                id: notification.id,
+                kind: notification.kind,
+                read: notification.read_at.is_some(),
+                created_at: Iso8601(notification.created_at),
+                actor: NotificationActorContent {
+                    username: actor.username,
+                    image: actor.image,
+                },
+                article: article
+                    .filter(|article| article.deleted_at.is_none())
+                    .map(|article| NotificationArticleContent {
+                        slug: article.slug,
+                        title: article.title,
+                    }),
+                comment_id: notification.comment_id,
+            })
+            .collect();
+        Self {
+            notifications,
+            notifications_count,
+            unread_count,
+        }
+    }
+}
+
+pub trait NotificationPresenter: Send + Sync + 'static {
+    fn to_multi_json(&self, list: NotificationsList, count: i64, unread_count: i64)
+        -> HttpResponse;
+    fn to_http_res(&self) -> HttpResponse;
+}
+
+#[derive(Clone)]
+pub struct NotificationPresenterImpl {}
+impl NotificationPresenterImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl NotificationPresenter for NotificationPresenterImpl {
+    fn to_multi_json(
+        &self,
+        list: NotificationsList,
+        count: i64,
+        unread_count: i64,
+    ) -> HttpResponse {
+        let res = MultipleNotificationsResponse::from((list, count, unread_count));
+        HttpResponse::Ok().json(res)
+    }
+
+    fn to_http_res(&self) -> HttpResponse {
+        HttpResponse::Ok().json("OK")
+    }
+}
@@ -0,0 +1,69 @@
+use uuid::Uuid;
+
+use crate::{
+    app::features::{article::entities::Article, user::entities::User},
+    error::AppError,
+    utils::db::DbPool,
+};
+
+use super::entities::Notification;
+
+type UnreadCount = i64;
+type NotificationsCount = i64;
+pub type NotificationsList = Vec<(Notification, User, Option<Article>)>;
+
+pub trait NotificationRepository: Send + Sync + 'static {
+    fn fetch_notifications(
+        &self,
+        input: FetchNotificationsRepositoryInput,
+    ) -> Result<(NotificationsList, NotificationsCount, UnreadCount), AppError>;
+    fn mark_read(&self, current_user_id: Uuid, notification_id: Uuid) -> Result<(), AppError>;
+    fn mark_all_read(&self, current_user_id: Uuid) -> Result<(), AppError>;
+}
+
+#[derive(Clone)]
+pub struct NotificationRepositoryImpl {
+    pool: DbPool,
+}
+
+impl NotificationRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl NotificationRepository for NotificationRepositoryImpl {
+    fn fetch_notifications(
+        &self,
+        input: FetchNotificationsRepositoryInput,
+    ) -> Result<(NotificationsList, NotificationsCount, UnreadCount), AppError> {
+        let conn = &mut self.pool.get()?;
+        let list = Notification::fetch_page(
+            conn,
+            &input.current_user_id,
+            input.unread_only,
+            input.offset,
+            input.limit,
+        )?;
+        let count = Notification::count(conn, &input.current_user_id, input.unread_only)?;
+        let unread_count = Notification::count(conn, &input.current_user_id, true)?;
+        Ok((list, count, unread_count))
+    }
+
+    fn mark_read(&self, current_user_id: Uuid, notification_id: Uuid) -> Result<(), AppError> {
+        let conn = &mut self.pool.get()?;
+        Notification::mark_read(conn, &current_user_id, &notification_id)
+    }
+
+    fn mark_all_read(&self, current_user_id: Uuid) -> Result<(), AppError> {
+        let conn = &mut self.pool.get()?;
+        Notification::mark_all_read(conn, &current_user_id)
+    }
+}
+
+pub struct FetchNotificationsRepositoryInput {
+    pub current_user_id: Uuid,
+    pub unread_only: bool,
+    pub offset: i64,
+    pub limit: i64,
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use actix_web::HttpResponse;
+use uuid::Uuid;
+
+use crate::error::AppError;
+
+use super::{
+    presenters::NotificationPresenter,
+    repositories::{FetchNotificationsRepositoryInput, NotificationRepository},
+};
+
+#[derive(Clone)]
+pub struct NotificationUsecase {
+    notification_repository: Arc<dyn NotificationRepository>,
+    notification_presenter: Arc<dyn NotificationPresenter>,
+}
+
+impl NotificationUsecase {
+    pub fn new(
+        notification_repository: Arc<dyn NotificationRepository>,
+        notification_presenter: Arc<dyn NotificationPresenter>,
+    ) -> Self {
+        Self {
+            notification_repository,
+            notification_presenter,
+        }
+    }
+
+    pub fn fetch_notifications(
+        &self,
+        input: FetchNotificationsUsecaseInput,
+    ) -> Result<HttpResponse, AppError> {
+        let (list, count, unread_count) = self.notification_repository.fetch_notifications(
+            FetchNotificationsRepositoryInput {
+                current_user_id: input.current_user_id,
+                unread_only: input.unread_only,
+                offset: input.offset,
+                limit: input.limit,
+            },
+        )?;
+        let res = self
+            .notification_presenter
+            .to_multi_json(list, count, unread_count);
+        Ok(res)
+    }
+
+    pub fn mark_read(
+        &self,
+        current_user_id: Uuid,
+        notification_id: Uuid,
+    ) -> Result<HttpResponse, AppError> {
+        self.notification_repository
+            .mark_read(current_user_id, notification_id)?;
+        let res = self.notification_presenter.to_http_res();
+        Ok(res)
+    }
+
+    pub fn mark_all_read(&self, current_user_id: Uuid) -> Result<HttpResponse, AppError> {
+        self.notification_repository
+            .mark_all_read(current_user_id)?;
+        let res = self.notification_presenter.to_http_res();
+        Ok(res)
+    }
+}
+
+pub struct FetchNotificationsUsecaseInput {
+    pub current_user_id: Uuid,
+    pub unread_only: bool,
+    pub offset: i64,
+    pub limit: i64,
+}
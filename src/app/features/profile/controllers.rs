@@ -1,12 +1,44 @@
 use actix_web::{web, HttpRequest};
+use serde::Deserialize;
 
 use crate::{
     app::drivers::middlewares::{auth, state::AppState},
+    constants::FOLLOWS_PAGE_LIMIT,
     utils::api::ApiResponse,
 };
 
+use super::{repositories::FollowDirection, usecases::FetchFollowsUsecaseInput};
+
 type UsernameSlug = String;
 
+#[derive(Deserialize)]
+pub struct FollowsListQueryParameter {
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+fn fetch_follows(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    username: String,
+    params: FollowsListQueryParameter,
+    direction: FollowDirection,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req).ok();
+    let offset = params.offset.unwrap_or(0).max(0);
+    let limit = params.limit.unwrap_or(20).clamp(1, FOLLOWS_PAGE_LIMIT);
+    state
+        .di_container
+        .profile_usecase
+        .fetch_follows(FetchFollowsUsecaseInput {
+            current_user,
+            username,
+            direction,
+            offset,
+            limit,
+        })
+}
+
 pub async fn show(
     state: web::Data<AppState>,
     req: HttpRequest,
@@ -45,3 +77,85 @@ pub async fn unfollow(
         .profile_usecase
         .unfollow_user(&current_user, &target_username)
 }
+
+pub async fn block(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<UsernameSlug>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let target_username = path.into_inner();
+    state
+        .di_container
+        .profile_usecase
+        .set_blocked(&current_user, &target_username, true)
+}
+
+pub async fn unblock(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<UsernameSlug>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let target_username = path.into_inner();
+    state
+        .di_container
+        .profile_usecase
+        .set_blocked(&current_user, &target_username, false)
+}
+
+pub async fn mute(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<UsernameSlug>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let target_username = path.into_inner();
+    state
+        .di_container
+        .profile_usecase
+        .set_muted(&current_user, &target_username, true)
+}
+
+pub async fn unmute(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<UsernameSlug>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let target_username = path.into_inner();
+    state
+        .di_container
+        .profile_usecase
+        .set_muted(&current_user, &target_username, false)
+}
+
+pub async fn followers(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<UsernameSlug>,
+    params: web::Query<FollowsListQueryParameter>,
+) -> ApiResponse {
+    fetch_follows(
+        state,
+        req,
+        path.into_inner(),
+        params.into_inner(),
+        FollowDirection::Followers,
+    )
+}
+
+pub async fn following(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<UsernameSlug>,
+    params: web::Query<FollowsListQueryParameter>,
+) -> ApiResponse {
+    fetch_follows(
+        state,
+        req,
+        path.into_inner(),
+        params.into_inner(),
+        FollowDirection::Following,
+    )
+}
@@ -8,3 +8,9 @@ pub struct Profile {
     pub image: Option<String>,
     pub following: bool,
 }
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FollowCounts {
+    pub followers_count: i64,
+    pub following_count: i64,
+}
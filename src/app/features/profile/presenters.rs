@@ -1,7 +1,7 @@
 use actix_web::HttpResponse;
 use serde::Serialize;
 
-use super::entities::Profile as ProfileEntity;
+use super::repositories::{ProfileWithCounts, ProfilesList};
 
 #[derive(Serialize)]
 pub struct ProfileResponse {
@@ -9,27 +9,55 @@ pub struct ProfileResponse {
 }
 
 #[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultipleProfilesResponse {
+    pub profiles: Vec<ProfileContent>,
+    pub profiles_count: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ProfileContent {
     pub username: String,
     pub bio: Option<String>,
     pub image: Option<String>,
     pub following: bool,
+    pub followers_count: i64,
+    pub following_count: i64,
 }
 
-impl From<ProfileEntity> for ProfileResponse {
-    fn from(profile_entity: ProfileEntity) -> Self {
-        let profile = ProfileContent {
+impl From<ProfileWithCounts> for ProfileContent {
+    fn from((profile_entity, counts): ProfileWithCounts) -> Self {
+        Self {
             username: profile_entity.username,
             bio: profile_entity.bio,
             image: profile_entity.image,
             following: profile_entity.following,
-        };
+            followers_count: counts.followers_count,
+            following_count: counts.following_count,
+        }
+    }
+}
+
+impl From<ProfileWithCounts> for ProfileResponse {
+    fn from(item: ProfileWithCounts) -> Self {
+        let profile = ProfileContent::from(item);
         ProfileResponse { profile }
     }
 }
 
+impl From<(ProfilesList, i64)> for MultipleProfilesResponse {
+    fn from((list, profiles_count): (ProfilesList, i64)) -> Self {
+        Self {
+            profiles: list.into_iter().map(ProfileContent::from).collect(),
+            profiles_count,
+        }
+    }
+}
+
 pub trait ProfilePresenter: Send + Sync + 'static {
-    fn to_json(&self, entity: ProfileEntity) -> HttpResponse;
+    fn to_json(&self, item: ProfileWithCounts) -> HttpResponse;
+    fn to_multi_json(&self, list: ProfilesList, count: i64) -> HttpResponse;
 }
 
 #[derive(Clone)]
@@ -41,8 +69,13 @@ impl ProfilePresenterImpl {
 }
 
 impl ProfilePresenter for ProfilePresenterImpl {
-    fn to_json(&self, entity: ProfileEntity) -> HttpResponse {
-        let response = ProfileResponse::from(entity);
+    fn to_json(&self, item: ProfileWithCounts) -> HttpResponse {
+        let response = ProfileResponse::from(item);
+        HttpResponse::Ok().json(response)
+    }
+
+    fn to_multi_json(&self, list: ProfilesList, count: i64) -> HttpResponse {
+        let response = MultipleProfilesResponse::from((list, count));
         HttpResponse::Ok().json(response)
     }
 }
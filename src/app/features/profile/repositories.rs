@@ -1,13 +1,27 @@
-use crate::{app::features::user::entities::User, error::AppError, utils::db::DbPool};
+use std::collections::HashSet;
 
-use super::entities::Profile;
+use crate::{
+    app::features::{follow::entities::Follow, user::entities::User},
+    error::AppError,
+    utils::db::DbPool,
+};
+
+use super::entities::{FollowCounts, Profile};
+
+type ProfilesCount = i64;
+pub type ProfileWithCounts = (Profile, FollowCounts);
+pub type ProfilesList = Vec<ProfileWithCounts>;
 
 pub trait ProfileRepository: Send + Sync + 'static {
     fn fetch_profile_by_name(
         &self,
         current_user: &User,
         username: &str,
-    ) -> Result<Profile, AppError>;
+    ) -> Result<ProfileWithCounts, AppError>;
+    fn fetch_follows(
+        &self,
+        input: FetchFollowsRepositoryInput,
+    ) -> Result<(ProfilesList, ProfilesCount), AppError>;
 }
 
 #[derive(Clone)]
@@ -26,12 +40,70 @@ impl ProfileRepository for ProfileRepositoryImpl {
         &self,
         current_user: &User,
         username: &str,
-    ) -> Result<Profile, AppError> {
+    ) -> Result<ProfileWithCounts, AppError> {
+        let conn = &mut self.pool.get()?;
+        let followee = User::find_by_username(conn, username)?;
+        let counts = Follow::fetch_counts(conn, &[followee.id])?
+            .remove(&followee.id)
+            .unwrap_or_default();
+        let profile = followee.to_profile(conn, &Some(current_user.clone()));
+        Ok((profile, counts))
+    }
+
+    fn fetch_follows(
+        &self,
+        input: FetchFollowsRepositoryInput,
+    ) -> Result<(ProfilesList, ProfilesCount), AppError> {
         let conn = &mut self.pool.get()?;
-        let profile = {
-            let followee = User::find_by_username(conn, username)?;
-            current_user.fetch_profile(conn, &followee.id)?
+        let user = User::find_by_username(conn, &input.username)?;
+        let user_counts = Follow::fetch_counts(conn, &[user.id])?
+            .remove(&user.id)
+            .unwrap_or_default();
+        let (users, count) = match input.direction {
+            FollowDirection::Followers => (
+                Follow::fetch_followers(conn, &user.id, input.offset, input.limit)?,
+                user_counts.followers_count,
+            ),
+            FollowDirection::Following => (
+                Follow::fetch_following(conn, &user.id, input.offset, input.limit)?,
+                user_counts.following_count,
+            ),
+        };
+        let user_ids = users.iter().map(|user| user.id).collect::<Vec<_>>();
+        let followed_ids = match &input.current_user {
+            Some(current_user) => Follow::fetch_followed_ids(conn, &current_user.id, &user_ids)?,
+            None => HashSet::new(),
         };
-        Ok(profile)
+        let mut counts = Follow::fetch_counts(conn, &user_ids)?;
+        let list = users
+            .into_iter()
+            .map(|user| {
+                let counts = counts.remove(&user.id).unwrap_or_default();
+                let profile = Profile {
+                    following: followed_ids.contains(&user.id),
+                    username: user.username,
+                    bio: user.bio,
+                    image: user.image,
+                };
+                (profile, counts)
+            })
+            .collect();
+        Ok((list, count))
     }
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FollowDirection {
+    // The users following the profile.
+    Followers,
+    // The users the profile follows.
+    Following,
+}
+
+pub struct FetchFollowsRepositoryInput {
+    pub current_user: Option<User>,
+    pub username: String,
+    pub direction: FollowDirection,
+    pub offset: i64,
+    pub limit: i64,
+}
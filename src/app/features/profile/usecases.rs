@@ -1,4 +1,7 @@
-use super::{presenters::ProfilePresenter, repositories::ProfileRepository};
+use super::{
+    presenters::ProfilePresenter,
+    repositories::{FetchFollowsRepositoryInput, FollowDirection, ProfileRepository},
+};
 use crate::{
     app::features::user::{entities::User, repositories::UserRepository},
     error::AppError,
@@ -33,10 +36,23 @@ impl ProfileUsecase {
         current_user: &User,
         username: &str,
     ) -> Result<Type, AppError> {
-        let profile = self
+        let item = self
             .profile_repository
             .fetch_profile_by_name(current_user, username)?;
-        Ok(self.presenter.to_json(profile))
+        Ok(self.presenter.to_json(item))
+    }
+
+    pub fn fetch_follows(&self, input: FetchFollowsUsecaseInput) -> Result<Type, AppError> {
+        let (list, count) = self
+            .profile_repository
+            .fetch_follows(FetchFollowsRepositoryInput {
+                current_user: input.current_user,
+                username: input.username,
+                direction: input.direction,
+                offset: input.offset,
+                limit: input.limit,
+            })?;
+        Ok(self.presenter.to_multi_json(list, count))
     }
 
     pub fn follow_user(
@@ -44,10 +60,12 @@ impl ProfileUsecase {
         current_user: &User,
         target_username: &str,
     ) -> Result<HttpResponse, AppError> {
-        let profile = self
-            .user_repository
+        self.user_repository
             .follow_user(current_user, target_username)?;
-        Ok(self.presenter.to_json(profile))
+        let item = self
+            .profile_repository
+            .fetch_profile_by_name(current_user, target_username)?;
+        Ok(self.presenter.to_json(item))
     }
 
     pub fn unfollow_user(
@@ -55,9 +73,47 @@ impl ProfileUsecase {
         current_user: &User,
         targer_username: &str,
     ) -> Result<HttpResponse, AppError> {
-        let profile = self
-            .user_repository
+        self.user_repository
             .unfollow_user(current_user, targer_username)?;
-        Ok(self.presenter.to_json(profile))
+        let item = self
+            .profile_repository
+            .fetch_profile_by_name(current_user, targer_username)?;
+        Ok(self.presenter.to_json(item))
+    }
+
+    pub fn set_blocked(
+        &self,
+        current_user: &User,
+        target_username: &str,
+        blocked: bool,
+    ) -> Result<HttpResponse, AppError> {
+        self.user_repository
+            .set_blocked(current_user, target_username, blocked)?;
+        let item = self
+            .profile_repository
+            .fetch_profile_by_name(current_user, target_username)?;
+        Ok(self.presenter.to_json(item))
     }
+
+    pub fn set_muted(
+        &self,
+        current_user: &User,
+        target_username: &str,
+        muted: bool,
+    ) -> Result<HttpResponse, AppError> {
+        self.user_repository
+            .set_muted(current_user, target_username, muted)?;
+        let item = self
+            .profile_repository
+            .fetch_profile_by_name(current_user, target_username)?;
+        Ok(self.presenter.to_json(item))
+    }
+}
+
+pub struct FetchFollowsUsecaseInput {
+    pub current_user: Option<User>,
+    pub username: String,
+    pub direction: FollowDirection,
+    pub offset: i64,
+    pub limit: i64,
 }
@@ -0,0 +1,78 @@
+use actix_web::{web, HttpRequest};
+
+use crate::{
+    app::drivers::middlewares::{auth, state::AppState},
+    utils::{api::ApiResponse, uuid},
+};
+
+type ArticleIdSlug = String;
+type CommentIdSlug = String;
+type Emoji = String;
+
+pub async fn index(state: web::Data<AppState>) -> ApiResponse {
+    state.di_container.reaction_usecase.fetch_available()
+}
+
+pub async fn react_article(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(ArticleIdSlug, Emoji)>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let (article_title_slug, emoji) = path.into_inner();
+    state.di_container.reaction_usecase.set_article_reaction(
+        article_title_slug,
+        current_user.id,
+        emoji,
+        true,
+    )
+}
+
+pub async fn unreact_article(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(ArticleIdSlug, Emoji)>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let (article_title_slug, emoji) = path.into_inner();
+    state.di_container.reaction_usecase.set_article_reaction(
+        article_title_slug,
+        current_user.id,
+        emoji,
+        false,
+    )
+}
+
+pub async fn react_comment(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(ArticleIdSlug, CommentIdSlug, Emoji)>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let (article_title_slug, comment_id, emoji) = path.into_inner();
+    let comment_id = uuid::parse(&comment_id)?;
+    state.di_container.reaction_usecase.set_comment_reaction(
+        article_title_slug,
+        comment_id,
+        current_user.id,
+        emoji,
+        true,
+    )
+}
+
+pub async fn unreact_comment(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(ArticleIdSlug, CommentIdSlug, Emoji)>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let (article_title_slug, comment_id, emoji) = path.into_inner();
+    let comment_id = uuid::parse(&comment_id)?;
+    state.di_container.reaction_usecase.set_comment_reaction(
+        article_title_slug,
+        comment_id,
+        current_user.id,
+        emoji,
+        false,
+    )
+}
@@ -0,0 +1,192 @@
+use chrono::NaiveDateTime;
+use diesel::{dsl, prelude::*, PgConnection};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::{
+    error::AppError,
+    schema::{article_reactions, comment_reactions},
+};
+
+#[derive(Insertable)]
+#[diesel(table_name = article_reactions)]
+pub struct CreateArticleReaction<'a> {
+    pub article_id: Uuid,
+    pub user_id: Uuid,
+    pub emoji: &'a str,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = comment_reactions)]
+pub struct CreateCommentReaction<'a> {
+    pub comment_id: Uuid,
+    pub user_id: Uuid,
+    pub emoji: &'a str,
+}
+
+pub struct ArticleReaction;
+
+impl ArticleReaction {
+    // Reacting twice with the same emoji keeps the first reaction.
+    pub fn create(conn: &mut PgConnection, record: &CreateArticleReaction) -> Result<(), AppError> {
+        diesel::insert_into(article_reactions::table)
+            .values(record)
+            .on_conflict_do_nothing()
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub fn delete(
+        conn: &mut PgConnection,
+        article_id: &Uuid,
+        user_id: &Uuid,
+        emoji: &str,
+    ) -> Result<(), AppError> {
+        let t = article_reactions::table
+            .filter(article_reactions::article_id.eq(article_id))
+            .filter(article_reactions::user_id.eq(user_id))
+            .filter(article_reactions::emoji.eq(emoji));
+        diesel::delete(t).execute(conn)?;
+        Ok(())
+    }
+
+    // The reactions on each article, with those of `viewer_id` marked. Articles
+    // without any are left out.
+    pub fn fetch_infos(
+        conn: &mut PgConnection,
+        article_ids: &[Uuid],
+        viewer_id: Option<&Uuid>,
+    ) -> Result<HashMap<Uuid, ReactionInfo>, AppError> {
+        let counts = article_reactions::table
+            .filter(article_reactions::article_id.eq_any(article_ids))
+            .group_by((article_reactions::article_id, article_reactions::emoji))
+            .select((
+                article_reactions::article_id,
+                article_reactions::emoji,
+                dsl::count_star(),
+                dsl::min(article_reactions::created_at),
+            ))
+            .load::<ReactionCountRow>(conn)?;
+        let own = match viewer_id {
+            Some(viewer_id) => article_reactions::table
+                .filter(article_reactions::article_id.eq_any(article_ids))
+                .filter(article_reactions::user_id.eq(viewer_id))
+                .select((article_reactions::article_id, article_reactions::emoji))
+                .load::<(Uuid, String)>(conn)?,
+            None => vec![],
+        };
+        Ok(ReactionInfo::group(counts, own))
+    }
+
+    pub fn fetch_info(
+        conn: &mut PgConnection,
+        article_id: &Uuid,
+        viewer_id: Option<&Uuid>,
+    ) -> Result<ReactionInfo, AppError> {
+        let mut infos = Self::fetch_infos(conn, &[*article_id], viewer_id)?;
+        Ok(infos.remove(article_id).unwrap_or_default())
+    }
+}
+
+pub struct CommentReaction;
+
+impl CommentReaction {
+    // Reacting twice with the same emoji keeps the first reaction.
+    pub fn create(conn: &mut PgConnection, record: &CreateCommentReaction) -> Result<(), AppError> {
+        diesel::insert_into(comment_reactions::table)
+            .values(record)
+            .on_conflict_do_nothing()
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub fn delete(
+        conn: &mut PgConnection,
+        comment_id: &Uuid,
+        user_id: &Uuid,
+        emoji: &str,
+    ) -> Result<(), AppError> {
+        let t = comment_reactions::table
+            .filter(comment_reactions::comment_id.eq(comment_id))
+            .filter(comment_reactions::user_id.eq(user_id))
+            .filter(comment_reactions::emoji.eq(emoji));
+        diesel::delete(t).execute(conn)?;
+        Ok(())
+    }
+
+    // The reactions on each comment, with those of `viewer_id` marked. Comments
+    // without any are left out.
+    pub fn fetch_infos(
+        conn: &mut PgConnection,
+        comment_ids: &[Uuid],
+        viewer_id: Option<&Uuid>,
+    ) -> Result<HashMap<Uuid, ReactionInfo>, AppError> {
+        let counts = comment_reactions::table
+            .filter(comment_reactions::comment_id.eq_any(comment_ids))
+            .group_by((comment_reactions::comment_id, comment_reactions::emoji))
+            .select((
+                comment_reactions::comment_id,
+                comment_reactions::emoji,
+                dsl::count_star(),
+                dsl::min(comment_reactions::created_at),
+            ))
+            .load::<ReactionCountRow>(conn)?;
+        let own = match viewer_id {
+            Some(viewer_id) => comment_reactions::table
+                .filter(comment_reactions::comment_id.eq_any(comment_ids))
+                .filter(comment_reactions::user_id.eq(viewer_id))
+                .select((comment_reactions::comment_id, comment_reactions::emoji))
+                .load::<(Uuid, String)>(conn)?,
+            None => vec![],
+        };
+        Ok(ReactionInfo::group(counts, own))
+    }
+
+    pub fn fetch_info(
+        conn: &mut PgConnection,
+        comment_id: &Uuid,
+        viewer_id: Option<&Uuid>,
+    ) -> Result<ReactionInfo, AppError> {
+        let mut infos = Self::fetch_infos(conn, &[*comment_id], viewer_id)?;
+        Ok(infos.remove(comment_id).unwrap_or_default())
+    }
+}
+
+type ReactionCountRow = (Uuid, String, i64, Option<NaiveDateTime>);
+
+#[derive(Clone, Debug, Default)]
+pub struct ReactionInfo {
+    // In the order each emoji was first used, so counts changing don't reshuffle them.
+    pub reactions: Vec<ReactionCount>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ReactionCount {
+    pub emoji: String,
+    pub count: i64,
+    pub reacted: bool,
+}
+
+impl ReactionInfo {
+    fn group(
+        mut counts: Vec<ReactionCountRow>,
+        own: Vec<(Uuid, String)>,
+    ) -> HashMap<Uuid, ReactionInfo> {
+        counts.sort_by(|a, b| a.3.cmp(&b.3).then_with(|| a.1.cmp(&b.1)));
+        let own = own.into_iter().collect::<HashSet<_>>();
+        let mut infos: HashMap<Uuid, ReactionInfo> = HashMap::new();
+        for (target_id, emoji, count, _) in counts {
+            let reacted = own.contains(&(target_id, emoji.clone()));
+            infos
+                .entry(target_id)
+                .or_default()
+                .reactions
+                .push(ReactionCount {
+                    emoji,
+                    count,
+                    reacted,
+                });
+        }
+        infos
+    }
+}
@@ -0,0 +1,65 @@
+use actix_web::HttpResponse;
+use serde::{Deserialize, Serialize};
+
+use super::entities::{ReactionCount, ReactionInfo};
+
+#[derive(Deserialize, Serialize)]
+pub struct ReactionContent {
+    pub emoji: String,
+    pub count: i64,
+    // Whether the viewer is one of those who reacted with it.
+    pub reacted: bool,
+}
+
+impl From<ReactionCount> for ReactionContent {
+    fn from(item: ReactionCount) -> Self {
+        Self {
+            emoji: item.emoji,
+            count: item.count,
+            reacted: item.reacted,
+        }
+    }
+}
+
+impl ReactionContent {
+    pub fn list(info: ReactionInfo) -> Vec<Self> {
+        info.reactions.into_iter().map(Self::from).collect()
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ReactionsResponse {
+    pub reactions: Vec<ReactionContent>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct AvailableReactionsResponse {
+    pub reactions: Vec<String>,
+}
+
+pub trait ReactionPresenter: Send + Sync + 'static {
+    fn to_json(&self, info: ReactionInfo) -> HttpResponse;
+    fn to_available_json(&self, emojis: Vec<String>) -> HttpResponse;
+}
+
+#[derive(Clone)]
+pub struct ReactionPresenterImpl {}
+impl ReactionPresenterImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl ReactionPresenter for ReactionPresenterImpl {
+    fn to_json(&self, info: ReactionInfo) -> HttpResponse {
+        let res = ReactionsResponse {
+            reactions: ReactionContent::list(info),
+        };
+        HttpResponse::Ok().json(res)
+    }
+
+    fn to_available_json(&self, emojis: Vec<String>) -> HttpResponse {
+        let res = AvailableReactionsResponse { reactions: emojis };
+        HttpResponse::Ok().json(res)
+    }
+}
@@ -0,0 +1,100 @@
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    app::features::{article::entities::Article, comment::entities::Comment},
+    error::AppError,
+    utils::db::DbPool,
+};
+
+use super::entities::{
+    ArticleReaction, CommentReaction, CreateArticleReaction, CreateCommentReaction, ReactionInfo,
+};
+
+pub trait ReactionRepository: Send + Sync + 'static {
+    // Adds or takes back a reaction, returning the reactions on the article after it.
+    fn set_article_reaction(
+        &self,
+        input: SetArticleReactionRepositoryInput,
+    ) -> Result<ReactionInfo, AppError>;
+    fn set_comment_reaction(
+        &self,
+        input: SetCommentReactionRepositoryInput,
+    ) -> Result<ReactionInfo, AppError>;
+}
+
+#[derive(Clone)]
+pub struct ReactionRepositoryImpl {
+    pool: DbPool,
+}
+
+impl ReactionRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl ReactionRepository for ReactionRepositoryImpl {
+    fn set_article_reaction(
+        &self,
+        input: SetArticleReactionRepositoryInput,
+    ) -> Result<ReactionInfo, AppError> {
+        let conn = &mut self.pool.get()?;
+        let article = Article::fetch_by_slug(conn, &input.article_title_slug)?;
+        if input.reacted {
+            ArticleReaction::create(
+                conn,
+                &CreateArticleReaction {
+                    article_id: article.id,
+                    user_id: input.current_user_id,
+                    emoji: &input.emoji,
+                },
+            )?;
+        } else {
+            ArticleReaction::delete(conn, &article.id, &input.current_user_id, &input.emoji)?;
+        }
+        ArticleReaction::fetch_info(conn, &article.id, Some(&input.current_user_id))
+    }
+
+    fn set_comment_reaction(
+        &self,
+        input: SetCommentReactionRepositoryInput,
+    ) -> Result<ReactionInfo, AppError> {
+        let conn = &mut self.pool.get()?;
+        let article = Article::fetch_by_slug(conn, &input.article_title_slug)?;
+        let comment = Comment::fetch_on_article(conn, &input.comment_id, &article.id)?;
+        if input.reacted && comment.held_at.is_some() {
+            return Err(AppError::UnprocessableEntity(json!({
+                "error": "Comments awaiting moderation can't be reacted to."
+            })));
+        }
+        if input.reacted {
+            CommentReaction::create(
+                conn,
+                &CreateCommentReaction {
+                    comment_id: comment.id,
+                    user_id: input.current_user_id,
+                    emoji: &input.emoji,
+                },
+            )?;
+        } else {
+            CommentReaction::delete(conn, &comment.id, &input.current_user_id, &input.emoji)?;
+        }
+        CommentReaction::fetch_info(conn, &comment.id, Some(&input.current_user_id))
+    }
+}
+
+pub struct SetArticleReactionRepositoryInput {
+    pub article_title_slug: String,
+    pub current_user_id: Uuid,
+    pub emoji: String,
+    pub reacted: bool,
+}
+
+pub struct SetCommentReactionRepositoryInput {
+    pub article_title_slug: String,
+    pub comment_id: Uuid,
+    pub current_user_id: Uuid,
+    pub emoji: String,
+    pub reacted: bool,
+}
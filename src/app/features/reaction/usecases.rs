@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use actix_web::HttpResponse;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::error::AppError;
+
+use super::{
+    presenters::ReactionPresenter,
+    repositories::{
+        ReactionRepository, SetArticleReactionRepositoryInput, SetCommentReactionRepositoryInput,
+    },
+};
+
+#[derive(Clone)]
+pub struct ReactionUsecase {
+    reaction_repository: Arc<dyn ReactionRepository>,
+    reaction_presenter: Arc<dyn ReactionPresenter>,
+    // The emojis readers may react with, in the order clients should offer them.
+    emojis: Arc<Vec<String>>,
+}
+
+impl ReactionUsecase {
+    pub fn new(
+        reaction_repository: Arc<dyn ReactionRepository>,
+        reaction_presenter: Arc<dyn ReactionPresenter>,
+        emojis: Vec<String>,
+    ) -> Self {
+        Self {
+            reaction_repository,
+            reaction_presenter,
+            emojis: Arc::new(emojis),
+        }
+    }
+
+    fn check_emoji(&self, emoji: &str) -> Result<(), AppError> {
+        if !self.emojis.iter().any(|allowed| allowed == emoji) {
+            return Err(AppError::UnprocessableEntity(json!({
+                "error": format!("reaction must be one of {}.", self.emojis.join(" "))
+            })));
+        }
+        Ok(())
+    }
+
+    pub fn fetch_available(&self) -> Result<HttpResponse, AppError> {
+        let res = self
+            .reaction_presenter
+            .to_available_json(self.emojis.to_vec());
+        Ok(res)
+    }
+
+    pub fn set_article_reaction(
+        &self,
+        article_title_slug: String,
+        current_user_id: Uuid,
+        emoji: String,
+        reacted: bool,
+    ) -> Result<HttpResponse, AppError> {
+        // Taking back a reaction that left the set is still allowed.
+        if reacted {
+            self.check_emoji(&emoji)?;
+        }
+        let info =
+            self.reaction_repository
+                .set_article_reaction(SetArticleReactionRepositoryInput {
+                    article_title_slug,
+                    current_user_id,
+                    emoji,
+                    reacted,
+                })?;
+        let res = self.reaction_presenter.to_json(info);
+        Ok(res)
+    }
+
+    pub fn set_comment_reaction(
+        &self,
+        article_title_slug: String,
+        comment_id: Uuid,
+        current_user_id: Uuid,
+        emoji: String,
+        reacted: bool,
+    ) -> Result<HttpResponse, AppError> {
+        if reacted {
+            self.check_emoji(&emoji)?;
+        }
+        let info =
+            self.reaction_repository
+                .set_comment_reaction(SetCommentReactionRepositoryInput {
+                    article_title_slug,
+                    comment_id,
+                    current_user_id,
+                    emoji,
+                    reacted,
+                })?;
+        let res = self.reaction_presenter.to_json(info);
+        Ok(res)
+    }
+}
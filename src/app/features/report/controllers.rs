@@ -0,0 +1,211 @@
+use actix_web::{web, HttpRequest};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::{
+    app::drivers::middlewares::{auth, state::AppState},
+    constants::{REPORTS_PAGE_LIMIT, REPORT_DETAILS_MAX_CHARS},
+    error::AppError,
+    utils::{api::ApiResponse, uuid},
+};
+
+use super::{
+    entities::{ModerationActionKind, ReportReason, ReportStatus, ReportTarget},
+    repositories::ReportSubject,
+    requests,
+    usecases::FetchReportsUsecaseInput,
+};
+
+type ArticleIdSlug = String;
+type CommentIdSlug = String;
+type Username = String;
+type ReportIdSlug = String;
+
+fn create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    subject: ReportSubject,
+    form: web::Json<requests::CreateReportRequest>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let form = form.into_inner().report;
+    let reason = ReportReason::parse(&form.reason).ok_or_else(|| {
+        let reasons = ReportReason::ALL.map(|reason| reason.as_str()).join(", ");
+        AppError::UnprocessableEntity(json!({
+            "error": format!("reason must be one of {}.", reasons)
+        }))
+    })?;
+    let details = form
+        .details
+        .map(|details| details.trim().to_owned())
+        .filter(|details| !details.is_empty());
+    match &details {
+        None if reason == ReportReason::Other => {
+            return Err(AppError::UnprocessableEntity(
+                json!({"error": "details are required when the reason is other."}),
+            ));
+        }
+        Some(details) if details.chars().count() > REPORT_DETAILS_MAX_CHARS => {
+            return Err(AppError::UnprocessableEntity(json!({
+                "error": format!("details can't be longer than {} characters.", REPORT_DETAILS_MAX_CHARS)
+            })));
+        }
+        _ => {}
+    }
+    state
+        .di_container
+        .report_usecase
+        .create_report(current_user, subject, reason, details)
+}
+
+pub async fn report_article(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ArticleIdSlug>,
+    form: web::Json<requests::CreateReportRequest>,
+) -> ApiResponse {
+    let subject = ReportSubject::Article {
+        slug: path.into_inner(),
+    };
+    create(state, req, subject, form)
+}
+
+pub async fn report_comment(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(ArticleIdSlug, CommentIdSlug)>,
+    form: web::Json<requests::CreateReportRequest>,
+) -> ApiResponse {
+    let (slug, comment_id) = path.into_inner();
+    let comment_id = uuid::parse(&comment_id)?;
+    let subject = ReportSubject::Comment { slug, comment_id };
+    create(state, req, subject, form)
+}
+
+pub async fn report_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<Username>,
+    form: web::Json<requests::CreateReportRequest>,
+) -> ApiResponse {
+    let subject = ReportSubject::User {
+        username: path.into_inner(),
+    };
+    create(state, req, subject, form)
+}
+
+#[derive(Deserialize)]
+pub struct ReportsListQueryParameter {
+    status: Option<String>,
+    target: Option<String>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+pub async fn index(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    params: web::Query<ReportsListQueryParameter>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let status = match params.status.as_deref() {
+        None | Some("") | Some("pending") => None,
+        Some(status) => Some(ReportStatus::parse(status).ok_or_else(|| {
+            AppError::UnprocessableEntity(json!({
+                "error": "status must be pending, open, claimed, resolved or dismissed."
+            }))
+        })?),
+    };
+    let target = match params.target.as_deref() {
+        None | Some("") => None,
+        Some(target) => Some(ReportTarget::parse(target).ok_or_else(|| {
+            AppError::UnprocessableEntity(
+                json!({"error": "target must be article, comment or user."}),
+            )
+        })?),
+    };
+    let offset = params.offset.unwrap_or(0).max(0);
+    let limit = params.limit.unwrap_or(20).clamp(1, REPORTS_PAGE_LIMIT);
+    state
+        .di_container
+        .report_usecase
+        .fetch_reports(FetchReportsUsecaseInput {
+            current_user,
+            status,
+            target,
+            offset,
+            limit,
+        })
+}
+
+pub async fn show(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ReportIdSlug>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let report_id = uuid::parse(&path.into_inner())?;
+    state
+        .di_container
+        .report_usecase
+        .fetch_report(&current_user, report_id)
+}
+
+pub async fn claim(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ReportIdSlug>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let report_id = uuid::parse(&path.into_inner())?;
+    state
+        .di_container
+        .report_usecase
+        .claim_report(&current_user, report_id)
+}
+
+fn close(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ReportIdSlug>,
+    form: Option<web::Json<requests::ModerateReportRequest>>,
+    action: ModerationActionKind,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let report_id = uuid::parse(&path.into_inner())?;
+    let note = form
+        .and_then(|form| form.into_inner().report.note)
+        .map(|note| note.trim().to_owned())
+        .filter(|note| !note.is_empty());
+    state
+        .di_container
+        .report_usecase
+        .close_report(current_user, report_id, action, note)
+}
+
+pub async fn resolve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ReportIdSlug>,
+    form: Option<web::Json<requests::ModerateReportRequest>>,
+) -> ApiResponse {
+    close(state, req, path, form, ModerationActionKind::Resolve)
+}
+
+pub async fn dismiss(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ReportIdSlug>,
+    form: Option<web::Json<requests::ModerateReportRequest>>,
+) -> ApiResponse {
+    close(state, req, path, form, ModerationActionKind::Dismiss)
+}
+
+pub async fn remove(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ReportIdSlug>,
+    form: Option<web::Json<requests::ModerateReportRequest>>,
+) -> ApiResponse {
+    close(state, req, path, form, ModerationActionKind::Remove)
+}
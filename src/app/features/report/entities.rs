@@ -0,0 +1,505 @@
+use chrono::{NaiveDateTime, Utc};
+use diesel::{
+    associations::{Associations, Identifiable},
+    deserialize::Queryable,
+    dsl::{And, Eq, EqAny},
+    pg::Pg,
+    prelude::*,
+    PgConnection,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    app::features::user::entities::User,
+    error::AppError,
+    schema::{articles, comments, moderation_actions, reports},
+};
+
+type WithId<T> = Eq<reports::id, T>;
+type WithTarget<'a> = And<Eq<reports::target_type, &'static str>, Eq<reports::target_id, &'a Uuid>>;
+type Pending = EqAny<reports::status, [&'static str; 2]>;
+type QueueQuery<'a> = reports::BoxedQuery<'a, Pg>;
+
+#[derive(Identifiable, Queryable, Debug, Clone)]
+#[diesel(table_name = reports)]
+pub struct Report {
+    pub id: Uuid,
+    // None for reports the spam filter files on the content it holds.
+    pub reporter_id: Option<Uuid>,
+    pub target_type: String,
+    pub target_id: Uuid,
+    pub reason: String,
+    pub details: Option<String>,
+    pub status: String,
+    pub moderator_id: Option<Uuid>,
+    pub resolution: Option<String>,
+    pub resolved_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl Report {
+    fn with_id(id: &Uuid) -> WithId<&Uuid> {
+        reports::id.eq(id)
+    }
+
+    fn with_target(target: ReportTarget, target_id: &Uuid) -> WithTarget<'_> {
+        reports::target_type
+            .eq(target.as_str())
+            .and(reports::target_id.eq(target_id))
+    }
+
+    // Reports still waiting on a moderator, claimed or not.
+    fn pending() -> Pending {
+        reports::status.eq_any([ReportStatus::Open.as_str(), ReportStatus::Claimed.as_str()])
+    }
+
+    fn queue(status: Option<ReportStatus>, target: Option<ReportTarget>) -> QueueQuery<'static> {
+        let mut query = reports::table.into_boxed();
+        query = match status {
+            Some(status) => query.filter(reports::status.eq(status.as_str())),
+            None => query.filter(Self::pending()),
+        };
+        if let Some(target) = target {
+            query = query.filter(reports::target_type.eq(target.as_str()));
+        }
+        query
+    }
+}
+
+impl Report {
+    pub fn create(conn: &mut PgConnection, record: &CreateReport) -> Result<Self, AppError> {
+        let item = diesel::insert_into(reports::table)
+            .values(record)
+            .get_result::<Self>(conn)?;
+        Ok(item)
+    }
+
+    // None when the reporter already has a pending report on the same content,
+    // as a reader has at most one of those.
+    pub fn create_unless_pending(
+        conn: &mut PgConnection,
+        record: &CreateReport,
+    ) -> Result<Option<Self>, AppError> {
+        let item = diesel::insert_into(reports::table)
+            .values(record)
+            .on_conflict_do_nothing()
+            .get_result::<Self>(conn)
+            .optional()?;
+        Ok(item)
+    }
+
+    // Oldest first, so the queue is worked through in the order it filled up.
+    // Without a status only pending reports are listed.
+    pub fn fetch_queue(
+        conn: &mut PgConnection,
+        status: Option<ReportStatus>,
+        target: Option<ReportTarget>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<Self>, AppError> {
+        let list = Self::queue(status, target)
+            .order((reports::created_at.asc(), reports::id.asc()))
+            .offset(offset)
+            .limit(limit)
+            .load::<Self>(conn)?;
+        Ok(list)
+    }
+
+    pub fn count_queue(
+        conn: &mut PgConnection,
+        status: Option<ReportStatus>,
+        target: Option<ReportTarget>,
+    ) -> Result<i64, AppError> {
+        let count = Self::queue(status, target)
+            .count()
+            .get_result::<i64>(conn)?;
+        Ok(count)
+    }
+
+    pub fn find(conn: &mut PgConnection, id: &Uuid) -> Result<Self, AppError> {
+        let item = reports::table
+            .filter(Self::with_id(id))
+            .first::<Self>(conn)?;
+        Ok(item)
+    }
+
+    // Loads the report, holding the row until the transaction ends.
+    pub fn lock(conn: &mut PgConnection, id: &Uuid) -> Result<Self, AppError> {
+        let item = reports::table
+            .filter(Self::with_id(id))
+            .for_update()
+            .first::<Self>(conn)?;
+        Ok(item)
+    }
+
+    pub fn claim(
+        conn: &mut PgConnection,
+        id: &Uuid,
+        moderator_id: &Uuid,
+    ) -> Result<Self, AppError> {
+        let item = diesel::update(reports::table.filter(Self::with_id(id)))
+            .set((
+                reports::status.eq(ReportStatus::Claimed.as_str()),
+                reports::moderator_id.eq(Some(moderator_id)),
+            ))
+            .get_result::<Self>(conn)?;
+        Ok(item)
+    }
+
+    pub fn close(
+        conn: &mut PgConnection,
+        id: &Uuid,
+        params: &CloseReport,
+    ) -> Result<Self, AppError> {
+        let item = diesel::update(reports::table.filter(Self::with_id(id)))
+            .set((
+                reports::status.eq(params.status.as_str()),
+                reports::moderator_id.eq(Some(params.moderator_id)),
+                reports::resolution.eq(params.resolution),
+                reports::resolved_at.eq(Some(Utc::now().naive_utc())),
+            ))
+            .get_result::<Self>(conn)?;
+        Ok(item)
+    }
+
+    // Closes the other pending reports on the same content, returning their ids.
+    pub fn close_pending_on_target(
+        conn: &mut PgConnection,
+        report: &Report,
+        params: &CloseReport,
+    ) -> Result<Vec<Uuid>, AppError> {
+        let t = reports::table
+            .filter(reports::target_type.eq(&report.target_type))
+            .filter(reports::target_id.eq(&report.target_id))
+            .filter(reports::id.ne(&report.id))
+            .filter(Self::pending());
+        let ids = diesel::update(t)
+            .set((
+                reports::status.eq(params.status.as_str()),
+                reports::moderator_id.eq(Some(params.moderator_id)),
+                reports::resolution.eq(params.resolution),
+                reports::resolved_at.eq(Some(Utc::now().naive_utc())),
+            ))
+            .returning(reports::id)
+            .get_results::<Uuid>(conn)?;
+        Ok(ids)
+    }
+
+    // Whether a moderator took the content down, in which case its authors may
+    // not bring it back from the trash.
+    pub fn is_removed(
+        conn: &mut PgConnection,
+        target: ReportTarget,
+        target_id: &Uuid,
+    ) -> Result<bool, AppError> {
+        let t = moderation_actions::table
+            .inner_join(reports::table)
+            .filter(Self::with_target(target, target_id))
+            .filter(moderation_actions::action.eq(ModerationActionKind::Remove.as_str()));
+        let exists = diesel::select(diesel::dsl::exists(t)).get_result::<bool>(conn)?;
+        Ok(exists)
+    }
+
+    // Bodies of the comments moderators took down as spam, newest first.
+    pub fn fetch_removed_spam_comment_bodies(
+        conn: &mut PgConnection,
+        limit: i64,
+    ) -> Result<Vec<String>, AppError> {
+        let removed_ids = moderation_actions::table
+            .inner_join(reports::table)
+            .filter(moderation_actions::action.eq(ModerationActionKind::Remove.as_str()))
+            .filter(reports::target_type.eq(ReportTarget::Comment.as_str()))
+            .filter(reports::reason.eq(ReportReason::Spam.as_str()))
+            .select(reports::target_id);
+        let list = comments::table
+            .filter(comments::id.eq_any(removed_ids))
+            .order(comments::created_at.desc())
+            .select(comments::body)
+            .limit(limit)
+            .load::<String>(conn)?;
+        Ok(list)
+    }
+
+    pub fn is_filed_by_spam_filter(&self) -> bool {
+        self.reporter_id.is_none()
+    }
+
+    pub fn target(&self) -> Option<ReportTarget> {
+        ReportTarget::parse(&self.target_type)
+    }
+
+    pub fn status(&self) -> ReportStatus {
+        ReportStatus::parse(&self.status).unwrap_or_default()
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = reports)]
+pub struct CreateReport {
+    pub reporter_id: Option<Uuid>,
+    pub target_type: &'static str,
+    pub target_id: Uuid,
+    pub reason: &'static str,
+    pub details: Option<String>,
+}
+
+pub struct CloseReport<'a> {
+    pub status: ReportStatus,
+    pub moderator_id: &'a Uuid,
+    pub resolution: Option<&'a str>,
+}
+
+// One entry of the audit trail, written for every step a moderator takes on a report.
+#[derive(Identifiable, Queryable, Associations, Debug, Clone)]
+#[diesel(belongs_to(Report, foreign_key = report_id))]
+#[diesel(table_name = moderation_actions)]
+pub struct ModerationAction {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub moderator_id: Option<Uuid>,
+    pub action: String,
+    pub note: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+impl ModerationAction {
+    pub fn create(
+        conn: &mut PgConnection,
+        records: &[CreateModerationAction],
+    ) -> Result<(), AppError> {
+        diesel::insert_into(moderation_actions::table)
+            .values(records)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    pub fn fetch_by_report_id(
+        conn: &mut PgConnection,
+        report_id: &Uuid,
+    ) -> Result<Vec<Self>, AppError> {
+        let list = moderation_actions::table
+            .filter(moderation_actions::report_id.eq(report_id))
+            .order(moderation_actions::created_at.asc())
+            .load::<Self>(conn)?;
+        Ok(list)
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = moderation_actions)]
+pub struct CreateModerationAction {
+    pub report_id: Uuid,
+    pub moderator_id: Uuid,
+    pub action: &'static str,
+    pub note: Option<String>,
+}
+
+// What a report points at, shown to moderators next to the report.
+#[derive(Clone, Debug)]
+pub enum ReportedContent {
+    Article {
+        slug: String,
+        title: String,
+        removed: bool,
+    },
+    Comment {
+        article_slug: String,
+        body: String,
+        removed: bool,
+    },
+    User {
+        username: String,
+    },
+}
+
+impl ReportedContent {
+    // Keyed by the target id; content deleted for good is left out.
+    pub fn fetch_for(
+        conn: &mut PgConnection,
+        list: &[Report],
+    ) -> Result<HashMap<Uuid, Self>, AppError> {
+        let ids_of = |target: ReportTarget| {
+            list.iter()
+                .filter(|report| report.target() == Some(target))
+                .map(|report| report.target_id)
+                .collect::<Vec<_>>()
+        };
+        let mut contents = HashMap::new();
+        let article_list = articles::table
+            .filter(articles::id.eq_any(ids_of(ReportTarget::Article)))
+            .select((
+                articles::id,
+                articles::slug,
+                articles::title,
+                articles::deleted_at.is_not_null(),
+            ))
+            .load::<(Uuid, String, String, bool)>(conn)?;
+        for (id, slug, title, removed) in article_list {
+            contents.insert(
+                id,
+                Self::Article {
+                    slug,
+                    title,
+                    removed,
+                },
+            );
+        }
+        let comment_list = comments::table
+            .inner_join(articles::table)
+            .filter(comments::id.eq_any(ids_of(ReportTarget::Comment)))
+            .select((
+                comments::id,
+                articles::slug,
+                comments::body,
+                comments::deleted_at.is_not_null(),
+            ))
+            .load::<(Uuid, String, String, bool)>(conn)?;
+        for (id, article_slug, body, removed) in comment_list {
+            contents.insert(
+                id,
+                Self::Comment {
+                    article_slug,
+                    body,
+                    removed,
+                },
+            );
+        }
+        for (id, username) in User::fetch_usernames(conn, &ids_of(ReportTarget::User))? {
+            contents.insert(id, Self::User { username });
+        }
+        Ok(contents)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportTarget {
+    Article,
+    Comment,
+    User,
+}
+
+impl ReportTarget {
+    pub fn parse(target: &str) -> Option<Self> {
+        match target {
+            "article" => Some(ReportTarget::Article),
+            "comment" => Some(ReportTarget::Comment),
+            "user" => Some(ReportTarget::User),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportTarget::Article => "article",
+            ReportTarget::Comment => "comment",
+            ReportTarget::User => "user",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportReason {
+    Spam,
+    Harassment,
+    Hate,
+    Violence,
+    Sexual,
+    Misinformation,
+    Other,
+}
+
+impl ReportReason {
+    pub const ALL: [ReportReason; 7] = [
+        ReportReason::Spam,
+        ReportReason::Harassment,
+        ReportReason::Hate,
+        ReportReason::Violence,
+        ReportReason::Sexual,
+        ReportReason::Misinformation,
+        ReportReason::Other,
+    ];
+
+    pub fn parse(reason: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|candidate| candidate.as_str() == reason)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportReason::Spam => "spam",
+            ReportReason::Harassment => "harassment",
+            ReportReason::Hate => "hate",
+            ReportReason::Violence => "violence",
+            ReportReason::Sexual => "sexual",
+            ReportReason::Misinformation => "misinformation",
+            ReportReason::Other => "other",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ReportStatus {
+    #[default]
+    Open,
+    Claimed,
+    Resolved,
+    Dismissed,
+}
+
+impl ReportStatus {
+    pub fn parse(status: &str) -> Option<Self> {
+        match status {
+            "open" => Some(ReportStatus::Open),
+            "claimed" => Some(ReportStatus::Claimed),
+            "resolved" => Some(ReportStatus::Resolved),
+            "dismissed" => Some(ReportStatus::Dismissed),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportStatus::Open => "open",
+            ReportStatus::Claimed => "claimed",
+            ReportStatus::Resolved => "resolved",
+            ReportStatus::Dismissed => "dismissed",
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        matches!(self, ReportStatus::Open | ReportStatus::Claimed)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModerationActionKind {
+    Claim,
+    Resolve,
+    Dismiss,
+    // Resolves the report and takes the reported content down, holding the
+    // account when a user was reported.
+    Remove,
+}
+
+impl ModerationActionKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModerationActionKind::Claim => "claim",
+            ModerationActionKind::Resolve => "resolve",
+            ModerationActionKind::Dismiss => "dismiss",
+            ModerationActionKind::Remove => "remove",
+        }
+    }
+
+    // The status a report ends up in, `Claim` leaving it pending.
+    pub fn status(&self) -> ReportStatus {
+        match self {
+            ModerationActionKind::Claim => ReportStatus::Claimed,
+            ModerationActionKind::Resolve | ModerationActionKind::Remove => ReportStatus::Resolved,
+            ModerationActionKind::Dismiss => ReportStatus::Dismissed,
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use actix_web::HttpResponse;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::utils::date::Iso8601;
+
+use super::{
+    entities::{Report, ReportedContent},
+    repositories::{ModerationActionEntry, ReportDetail, ReportEntry},
+};
+
+// What the reporter gets back; who handles the report stays with the moderators.
+#[derive(Deserialize, Serialize)]
+pub struct SingleReportResponse {
+    pub report: ReportContent,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportContent {
+    pub id: Uuid,
+    pub target_type: String,
+    pub target_id: Uuid,
+    pub reason: String,
+    pub details: Option<String>,
+    pub status: String,
+    pub created_at: Iso8601,
+}
+
+impl From<Report> for ReportContent {
+    fn from(report: Report) -> Self {
+        Self {
+            id: report.id,
+            target_type: report.target_type,
+            target_id: report.target_id,
+            reason: report.reason,
+            details: report.details,
+            status: report.status,
+            created_at: Iso8601(report.created_at),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultipleQueuedReportsResponse {
+    pub reports: Vec<QueuedReportContent>,
+    pub reports_count: i64,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct SingleQueuedReportResponse {
+    pub report: QueuedReportContent,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedReportContent {
+    #[serde(flatten)]
+    pub report: ReportContent,
+    // Null once the reported content is deleted for good.
+    pub target: Option<ReportedContentContent>,
+    // Null for reports filed by the spam filter.
+    pub reporter: Option<String>,
+    pub moderator: Option<String>,
+    pub resolution: Option<String>,
+    pub resolved_at: Option<Iso8601>,
+    pub updated_at: Iso8601,
+    // The audit trail, only listed for a single report.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<ModerationActionContent>>,
+}
+
+impl From<ReportEntry> for QueuedReportContent {
+    fn from(entry: ReportEntry) -> Self {
+        let resolution = entry.report.resolution.clone();
+        let resolved_at = entry.report.resolved_at.map(Iso8601);
+        let updated_at = Iso8601(entry.report.updated_at);
+        Self {
+            report: ReportContent::from(entry.report),
+            target: entry.content.map(ReportedContentContent::from),
+            reporter: entry.reporter,
+            moderator: entry.moderator,
+            resolution,
+            resolved_at,
+            updated_at,
+            actions: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportedContentContent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub article_slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    pub removed: bool,
+}
+
+impl From<ReportedContent> for ReportedContentContent {
+    fn from(content: ReportedContent) -> Self {
+        let empty = Self {
+            slug: None,
+            title: None,
+            article_slug: None,
+            body: None,
+            username: None,
+            removed: false,
+        };
+        match content {
+            ReportedContent::Article {
+                slug,
+                title,
+                removed,
+            } => Self {
+                slug: Some(slug),
+                title: Some(title),
+                removed,
+                ..empty
+            },
+            ReportedContent::Comment {
+                article_slug,
+                body,
+                removed,
+            } => Self {
+                article_slug: Some(article_slug),
+                body: Some(body),
+                removed,
+                ..empty
+            },
+            ReportedContent::User { username } => Self {
+                username: Some(username),
+                ..empty
+            },
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModerationActionContent {
+    pub action: String,
+    pub moderator: Option<String>,
+    pub note: Option<String>,
+    pub created_at: Iso8601,
+}
+
+impl From<ModerationActionEntry> for ModerationActionContent {
+    fn from((action, moderator): ModerationActionEntry) -> Self {
+        Self {
+            action: action.action,
+            moderator,
+            note: action.note,
+            created_at: Iso8601(action.created_at),
+        }
+    }
+}
+
+impl From<ReportDetail> for SingleQueuedReportResponse {
+    fn from((entry, actions): ReportDetail) -> Self {
+        let mut report = QueuedReportContent::from(entry);
+        report.actions = Some(
+            actions
+                .into_iter()
+                .map(ModerationActionContent::from)
+                .collect(),
+        );
+        Self { report }
+    }
+}
+
+pub trait ReportPresenter: Send + Sync + 'static {
+    fn to_single_json(&self, report: Report) -> HttpResponse;
+    fn to_queue_json(&self, list: Vec<ReportEntry>, count: i64) -> HttpResponse;
+    fn to_detail_json(&self, detail: ReportDetail) -> HttpResponse;
+}
+
+#[derive(Clone)]
+pub struct ReportPresenterImpl {}
+impl ReportPresenterImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl ReportPresenter for ReportPresenterImpl {
+    fn to_single_json(&self, report: Report) -> HttpResponse {
+        let res = SingleReportResponse {
+            report: ReportContent::from(report),
+        };
+        HttpResponse::Created().json(res)
+    }
+
+    fn to_queue_json(&self, list: Vec<ReportEntry>, count: i64) -> HttpResponse {
+        let res = MultipleQueuedReportsResponse {
+            reports: list.into_iter().map(QueuedReportContent::from).collect(),
+            reports_count: count,
+        };
+        HttpResponse::Ok().json(res)
+    }
+
+    fn to_detail_json(&self, detail: ReportDetail) -> HttpResponse {
+        let res = SingleQueuedReportResponse::from(detail);
+        HttpResponse::Ok().json(res)
+    }
+}
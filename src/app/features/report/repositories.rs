@@ -0,0 +1,313 @@
+use diesel::prelude::*;
+use serde_json::json;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    app::features::{
+        article::entities::Article, coauthor::entities::ArticleAuthor, comment::entities::Comment,
+        mention::entities::Mention, user::entities::User,
+    },
+    error::AppError,
+    utils::db::DbPool,
+};
+
+use super::entities::{
+    CloseReport, CreateModerationAction, CreateReport, ModerationAction, ModerationActionKind,
+    Report, ReportReason, ReportStatus, ReportTarget, ReportedContent,
+};
+
+type ReportsCount = i64;
+
+// A report as moderators see it, with the usernames of the people involved.
+pub struct ReportEntry {
+    pub report: Report,
+    pub reporter: Option<String>,
+    pub moderator: Option<String>,
+    pub content: Option<ReportedContent>,
+}
+
+pub type ModerationActionEntry = (ModerationAction, Option<String>);
+pub type ReportDetail = (ReportEntry, Vec<ModerationActionEntry>);
+
+pub trait ReportRepository: Send + Sync + 'static {
+    // Any signed-in user may report content, once until a moderator handles it.
+    fn create_report(&self, input: CreateReportRepositoryInput) -> Result<Report, AppError>;
+    fn fetch_reports(
+        &self,
+        input: FetchReportsRepositoryInput,
+    ) -> Result<(Vec<ReportEntry>, ReportsCount), AppError>;
+    fn fetch_report(&self, current_user: &User, report_id: Uuid) -> Result<ReportDetail, AppError>;
+    fn claim_report(&self, current_user: &User, report_id: Uuid) -> Result<ReportDetail, AppError>;
+    // Resolves or dismisses the report, taking the content down for `Remove`.
+    // Content held by the spam filter is released otherwise.
+    fn close_report(&self, input: CloseReportRepositoryInput) -> Result<ReportDetail, AppError>;
+}
+
+#[derive(Clone)]
+pub struct ReportRepositoryImpl {
+    pool: DbPool,
+}
+
+impl ReportRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    fn ensure_moderator(user: &User) -> Result<(), AppError> {
+        if !user.is_moderator {
+            return Err(AppError::Forbidden(
+                json!({"error": "Only moderators can handle reports."}),
+            ));
+        }
+        Ok(())
+    }
+
+    fn to_entries(
+        conn: &mut PgConnection,
+        list: Vec<Report>,
+    ) -> Result<Vec<ReportEntry>, AppError> {
+        let user_ids = list
+            .iter()
+            .flat_map(|report| report.reporter_id.into_iter().chain(report.moderator_id))
+            .collect::<Vec<_>>();
+        let usernames = User::fetch_usernames(conn, &user_ids)?;
+        let mut contents = ReportedContent::fetch_for(conn, &list)?;
+        let entries = list
+            .into_iter()
+            .map(|report| ReportEntry {
+                reporter: report
+                    .reporter_id
+                    .and_then(|id| usernames.get(&id).cloned()),
+                moderator: report
+                    .moderator_id
+                    .and_then(|id| usernames.get(&id).cloned()),
+                content: contents.remove(&report.target_id),
+                report,
+            })
+            .collect();
+        Ok(entries)
+    }
+
+    fn to_detail(conn: &mut PgConnection, report: Report) -> Result<ReportDetail, AppError> {
+        let actions = ModerationAction::fetch_by_report_id(conn, &report.id)?;
+        let moderator_ids = actions
+            .iter()
+            .filter_map(|action| action.moderator_id)
+            .collect::<Vec<_>>();
+        let usernames: HashMap<Uuid, String> = User::fetch_usernames(conn, &moderator_ids)?;
+        let actions = actions
+            .into_iter()
+            .map(|action| {
+                let moderator = action
+                    .moderator_id
+                    .and_then(|id| usernames.get(&id).cloned());
+                (action, moderator)
+            })
+            .collect();
+        let entry = Self::to_entries(conn, vec![report])?
+            .pop()
+            .ok_or(AppError::InternalServerError)?;
+        Ok((entry, actions))
+    }
+
+    // Takes the reported content down; content that is already gone is left as is.
+    fn remove_content(conn: &mut PgConnection, report: &Report) -> Result<(), AppError> {
+        match report.target() {
+            Some(ReportTarget::Article) => Article::remove(conn, &report.target_id),
+            Some(ReportTarget::Comment) => Comment::delete(conn, &report.target_id),
+            Some(ReportTarget::User) => User::remove(conn, &report.target_id),
+            None => Err(AppError::InternalServerError),
+        }
+    }
+
+    // Publishes the content the spam filter held, once a moderator found it fine.
+    fn release_content(conn: &mut PgConnection, report: &Report) -> Result<(), AppError> {
+        match report.target() {
+            Some(ReportTarget::Comment) => {
+                let comment = Comment::release(conn, &report.target_id)?;
+                Mention::notify_released(conn, &comment)
+            }
+            Some(ReportTarget::User) => User::release(conn, &report.target_id),
+            Some(ReportTarget::Article) => Ok(()),
+            None => Err(AppError::InternalServerError),
+        }
+    }
+}
+
+impl ReportRepository for ReportRepositoryImpl {
+    fn create_report(&self, input: CreateReportRepositoryInput) -> Result<Report, AppError> {
+        let conn = &mut self.pool.get()?;
+        let reporter = input.reporter;
+        let (target, target_id, is_own) = match &input.subject {
+            ReportSubject::Article { slug } => {
+                let article = Article::fetch_by_slug(conn, slug)?;
+                let is_own = ArticleAuthor::fetch_role(conn, &article.id, &reporter.id)?.is_some();
+                (ReportTarget::Article, article.id, is_own)
+            }
+            ReportSubject::Comment { slug, comment_id } => {
+                let article = Article::fetch_by_slug(conn, slug)?;
+                let comment = Comment::fetch_on_article(conn, comment_id, &article.id)?;
+                let is_own = comment.author_id == reporter.id;
+                (ReportTarget::Comment, comment.id, is_own)
+            }
+            ReportSubject::User { username } => {
+                let user = User::find_by_username(conn, username)?;
+                let is_own = user.id == reporter.id;
+                (ReportTarget::User, user.id, is_own)
+            }
+        };
+        if is_own {
+            return Err(AppError::UnprocessableEntity(
+                json!({"error": "You can't report your own content."}),
+            ));
+        }
+        let report = Report::create_unless_pending(
+            conn,
+            &CreateReport {
+                reporter_id: Some(reporter.id),
+                target_type: target.as_str(),
+                target_id,
+                reason: input.reason.as_str(),
+                details: input.details,
+            },
+        )?;
+        report.ok_or_else(|| {
+            AppError::UnprocessableEntity(json!({
+                "error": "You already reported this and it is waiting for a moderator."
+            }))
+        })
+    }
+
+    fn fetch_reports(
+        &self,
+        input: FetchReportsRepositoryInput,
+    ) -> Result<(Vec<ReportEntry>, ReportsCount), AppError> {
+        Self::ensure_moderator(&input.current_user)?;
+        let conn = &mut self.pool.get()?;
+        let list =
+            Report::fetch_queue(conn, input.status, input.target, input.offset, input.limit)?;
+        let count = Report::count_queue(conn, input.status, input.target)?;
+        let entries = Self::to_entries(conn, list)?;
+        Ok((entries, count))
+    }
+
+    fn fetch_report(&self, current_user: &User, report_id: Uuid) -> Result<ReportDetail, AppError> {
+        Self::ensure_moderator(current_user)?;
+        let conn = &mut self.pool.get()?;
+        let report = Report::find(conn, &report_id)?;
+        Self::to_detail(conn, report)
+    }
+
+    fn claim_report(&self, current_user: &User, report_id: Uuid) -> Result<ReportDetail, AppError> {
+        Self::ensure_moderator(current_user)?;
+        let conn = &mut self.pool.get()?;
+        let report = conn.transaction(|conn| {
+            let report = Report::lock(conn, &report_id)?;
+            match report.status() {
+                ReportStatus::Open => {}
+                ReportStatus::Claimed if report.moderator_id == Some(current_user.id) => {
+                    return Ok(report);
+                }
+                ReportStatus::Claimed => {
+                    return Err(AppError::UnprocessableEntity(
+                        json!({"error": "The report is already claimed by another moderator."}),
+                    ));
+                }
+                status => {
+                    return Err(AppError::UnprocessableEntity(json!({
+                        "error": format!("The report is already {}.", status.as_str())
+                    })));
+                }
+            }
+            let report = Report::claim(conn, &report.id, &current_user.id)?;
+            ModerationAction::create(
+                conn,
+                &[CreateModerationAction {
+                    report_id: report.id,
+                    moderator_id: current_user.id,
+                    action: ModerationActionKind::Claim.as_str(),
+                    note: None,
+                }],
+            )?;
+            Ok::<_, AppError>(report)
+        })?;
+        Self::to_detail(conn, report)
+    }
+
+    fn close_report(&self, input: CloseReportRepositoryInput) -> Result<ReportDetail, AppError> {
+        let moderator = input.current_user;
+        Self::ensure_moderator(&moderator)?;
+        let conn = &mut self.pool.get()?;
+        let report = conn.transaction(|conn| {
+            let report = Report::lock(conn, &input.report_id)?;
+            let status = report.status();
+            if !status.is_pending() {
+                return Err(AppError::UnprocessableEntity(json!({
+                    "error": format!("The report is already {}.", status.as_str())
+                })));
+            }
+            if status == ReportStatus::Claimed && report.moderator_id != Some(moderator.id) {
+                return Err(AppError::Forbidden(
+                    json!({"error": "The report is claimed by another moderator."}),
+                ));
+            }
+            let params = CloseReport {
+                status: input.action.status(),
+                moderator_id: &moderator.id,
+                resolution: input.note.as_deref(),
+            };
+            let mut audit = vec![CreateModerationAction {
+                report_id: report.id,
+                moderator_id: moderator.id,
+                action: input.action.as_str(),
+                note: input.note.clone(),
+            }];
+            // Once the content is down, the other reports on it are settled too.
+            if input.action == ModerationActionKind::Remove {
+                Self::remove_content(conn, &report)?;
+                let others = Report::close_pending_on_target(conn, &report, &params)?;
+                audit.extend(others.into_iter().map(|report_id| CreateModerationAction {
+                    report_id,
+                    moderator_id: moderator.id,
+                    action: ModerationActionKind::Resolve.as_str(),
+                    note: Some(format!("Content removed through report {}.", report.id)),
+                }));
+            } else if report.is_filed_by_spam_filter() {
+                Self::release_content(conn, &report)?;
+            }
+            let report = Report::close(conn, &report.id, &params)?;
+            ModerationAction::create(conn, &audit)?;
+            Ok(report)
+        })?;
+        Self::to_detail(conn, report)
+    }
+}
+
+pub enum ReportSubject {
+    Article { slug: String },
+    Comment { slug: String, comment_id: Uuid },
+    User { username: String },
+}
+
+pub struct CreateReportRepositoryInput {
+    pub reporter: User,
+    pub subject: ReportSubject,
+    pub reason: ReportReason,
+    pub details: Option<String>,
+}
+
+pub struct FetchReportsRepositoryInput {
+    pub current_user: User,
+    pub status: Option<ReportStatus>,
+    pub target: Option<ReportTarget>,
+    pub offset: i64,
+    pub limit: i64,
+}
+
+pub struct CloseReportRepositoryInput {
+    pub current_user: User,
+    pub report_id: Uuid,
+    pub action: ModerationActionKind,
+    pub note: Option<String>,
+}
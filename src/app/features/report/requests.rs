@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct CreateReportRequest {
+    pub report: CreateReportInner,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct CreateReportInner {
+    pub reason: String,
+    pub details: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ModerateReportRequest {
+    pub report: ModerateReportInner,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ModerateReportInner {
+    pub note: Option<String>,
+}
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use actix_web::HttpResponse;
+use uuid::Uuid;
+
+use crate::{app::features::user::entities::User, error::AppError};
+
+use super::{
+    entities::{ModerationActionKind, ReportReason, ReportStatus, ReportTarget},
+    presenters::ReportPresenter,
+    repositories::{
+        CloseReportRepositoryInput, CreateReportRepositoryInput, FetchReportsRepositoryInput,
+        ReportRepository, ReportSubject,
+    },
+};
+
+#[derive(Clone)]
+pub struct ReportUsecase {
+    report_repository: Arc<dyn ReportRepository>,
+    report_presenter: Arc<dyn ReportPresenter>,
+}
+
+impl ReportUsecase {
+    pub fn new(
+        report_repository: Arc<dyn ReportRepository>,
+        report_presenter: Arc<dyn ReportPresenter>,
+    ) -> Self {
+        Self {
+            report_repository,
+            report_presenter,
+        }
+    }
+
+    pub fn create_report(
+        &self,
+        reporter: User,
+        subject: ReportSubject,
+        reason: ReportReason,
+        details: Option<String>,
+    ) -> Result<HttpResponse, AppError> {
+        let report = self
+            .report_repository
+            .create_report(CreateReportRepositoryInput {
+                reporter,
+                subject,
+                reason,
+                details,
+            })?;
+        let res = self.report_presenter.to_single_json(report);
+        Ok(res)
+    }
+
+    pub fn fetch_reports(&self, input: FetchReportsUsecaseInput) -> Result<HttpResponse, AppError> {
+        let (list, count) = self
+            .report_repository
+            .fetch_reports(FetchReportsRepositoryInput {
+                current_user: input.current_user,
+                status: input.status,
+                target: input.target,
+                offset: input.offset,
+                limit: input.limit,
+            })?;
+        let res = self.report_presenter.to_queue_json(list, count);
+        Ok(res)
+    }
+
+    pub fn fetch_report(
+        &self,
+        current_user: &User,
+        report_id: Uuid,
+    ) -> Result<HttpResponse, AppError> {
+        let detail = self
+            .report_repository
+            .fetch_report(current_user, report_id)?;
+        let res = self.report_presenter.to_detail_json(detail);
+        Ok(res)
+    }
+
+    pub fn claim_report(
+        &self,
+        current_user: &User,
+        report_id: Uuid,
+    ) -> Result<HttpResponse, AppError> {
+        let detail = self
+            .report_repository
+            .claim_report(current_user, report_id)?;
+        let res = self.report_presenter.to_detail_json(detail);
+        Ok(res)
+    }
+
+    pub fn close_report(
+        &self,
+        current_user: User,
+        report_id: Uuid,
+        action: ModerationActionKind,
+        note: Option<String>,
+    ) -> Result<HttpResponse, AppError> {
+        let detail = self
+            .report_repository
+            .close_report(CloseReportRepositoryInput {
+                current_user,
+                report_id,
+                action,
+                note,
+            })?;
+        let res = self.report_presenter.to_detail_json(detail);
+        Ok(res)
+    }
+}
+
+pub struct FetchReportsUsecaseInput {
+    pub current_user: User,
+    pub status: Option<ReportStatus>,
+    pub target: Option<ReportTarget>,
+    pub offset: i64,
+    pub limit: i64,
+}
@@ -0,0 +1,16 @@
+use actix_web::web;
+
+use crate::{app::drivers::middlewares::state::AppState, utils::api::ApiResponse};
+
+type ArticleTitleSlug = String;
+
+pub async fn article_meta(
+    state: web::Data<AppState>,
+    path: web::Path<ArticleTitleSlug>,
+) -> ApiResponse {
+    let article_title_slug = path.into_inner();
+    state
+        .di_container
+        .seo_usecase
+        .fetch_article_meta(article_title_slug)
+}
@@ -0,0 +1,51 @@
+use chrono::NaiveDateTime;
+
+use crate::{app::features::article::repositories::SingleArticle, utils::seo};
+
+// Metadata of an article page, with the article's own title and description
+// standing in for the SEO fields that were left empty.
+pub struct ArticleMeta {
+    pub title: String,
+    pub description: String,
+    pub canonical_url: Option<String>,
+    pub image: Option<String>,
+    pub published_at: NaiveDateTime,
+    pub modified_at: NaiveDateTime,
+    pub authors: Vec<MetaAuthor>,
+    pub tags: Vec<String>,
+}
+
+pub struct MetaAuthor {
+    pub name: String,
+    pub url: Option<String>,
+}
+
+impl ArticleMeta {
+    // `site_url` is where the frontend lives, `public_url` where this API serves uploads.
+    pub fn new(item: SingleArticle, site_url: &str, public_url: &str) -> Self {
+        let (article, _, _, tag_list, authors, _, _) = item;
+        let site_url = site_url.trim_end_matches('/');
+        let page_url =
+            |path: String| (!site_url.is_empty()).then(|| format!("{}{}", site_url, path));
+        Self {
+            title: seo::non_blank(article.meta_title).unwrap_or(article.title),
+            description: seo::non_blank(article.meta_description).unwrap_or(article.description),
+            canonical_url: article
+                .canonical_url
+                .or_else(|| page_url(format!("/article/{}", article.slug))),
+            image: article
+                .cover_image
+                .map(|image| seo::absolute_url(public_url, &image)),
+            published_at: article.created_at,
+            modified_at: article.updated_at,
+            authors: authors
+                .into_iter()
+                .map(|author| MetaAuthor {
+                    url: page_url(format!("/profile/{}", author.profile.username)),
+                    name: author.profile.username,
+                })
+                .collect(),
+            tags: tag_list.into_iter().map(|tag| tag.name).collect(),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod controllers;
+pub mod entities;
+pub mod presenters;
+pub mod usecases;
@@ -0,0 +1,140 @@
+use actix_web::HttpResponse;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+
+use crate::utils::date::Iso8601;
+
+use super::entities::ArticleMeta;
+
+#[derive(Deserialize, Serialize)]
+pub struct ArticleMetaResponse {
+    pub meta: ArticleMetaContent,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleMetaContent {
+    pub title: String,
+    pub description: String,
+    pub canonical_url: Option<String>,
+    pub image: Option<String>,
+    pub open_graph: OpenGraphContent,
+    pub twitter: TwitterCardContent,
+    pub json_ld: JsonValue,
+}
+
+// Keys are the `property` names of the `<meta>` tags to render.
+#[derive(Deserialize, Serialize)]
+pub struct OpenGraphContent {
+    #[serde(rename = "og:type")]
+    pub og_type: String,
+    #[serde(rename = "og:title")]
+    pub title: String,
+    #[serde(rename = "og:description")]
+    pub description: String,
+    #[serde(rename = "og:url", skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(rename = "og:image", skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(rename = "article:published_time")]
+    pub published_time: Iso8601,
+    #[serde(rename = "article:modified_time")]
+    pub modified_time: Iso8601,
+    #[serde(rename = "article:author")]
+    pub authors: Vec<String>,
+    #[serde(rename = "article:tag")]
+    pub tags: Vec<String>,
+}
+
+// Keys are the `name` attributes of the `<meta>` tags to render.
+#[derive(Deserialize, Serialize)]
+pub struct TwitterCardContent {
+    #[serde(rename = "twitter:card")]
+    pub card: String,
+    #[serde(rename = "twitter:title")]
+    pub title: String,
+    #[serde(rename = "twitter:description")]
+    pub description: String,
+    #[serde(rename = "twitter:image", skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+}
+
+impl From<ArticleMeta> for ArticleMetaResponse {
+    fn from(meta: ArticleMeta) -> Self {
+        let json_ld = json!({
+            "@context": "https://schema.org",
+            "@type": "Article",
+            "headline": meta.title,
+            "description": meta.description,
+            "image": meta.image.iter().collect::<Vec<_>>(),
+            "datePublished": Iso8601(meta.published_at),
+            "dateModified": Iso8601(meta.modified_at),
+            "author": meta
+                .authors
+                .iter()
+                .map(|author| match &author.url {
+                    Some(url) => json!({"@type": "Person", "name": author.name, "url": url}),
+                    None => json!({"@type": "Person", "name": author.name}),
+                })
+                .collect::<Vec<_>>(),
+            "keywords": meta.tags.join(", "),
+            "mainEntityOfPage": meta.canonical_url,
+        });
+        let twitter = TwitterCardContent {
+            card: if meta.image.is_some() {
+                "summary_large_image".to_owned()
+            } else {
+                "summary".to_owned()
+            },
+            title: meta.title.clone(),
+            description: meta.description.clone(),
+            image: meta.image.clone(),
+        };
+        let open_graph = OpenGraphContent {
+            og_type: "article".to_owned(),
+            title: meta.title.clone(),
+            description: meta.description.clone(),
+            url: meta.canonical_url.clone(),
+            image: meta.image.clone(),
+            published_time: Iso8601(meta.published_at),
+            modified_time: Iso8601(meta.modified_at),
+            authors: meta
+                .authors
+                .into_iter()
+                .map(|author| author.url.unwrap_or(author.name))
+                .collect(),
+            tags: meta.tags,
+        };
+        Self {
+            meta: ArticleMetaContent {
+                title: meta.title,
+                description: meta.description,
+                canonical_url: meta.canonical_url,
+                image: meta.image,
+                open_graph,
+                twitter,
+                json_ld,
+            },
+        }
+    }
+}
+
+pub trait SeoPresenter: Send + Sync + 'static {
+    fn to_meta_json(&self, meta: ArticleMeta) -> HttpResponse;
+}
+
+#[derive(Clone)]
+pub struct SeoPresenterImpl {}
+
+impl SeoPresenterImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl SeoPresenter for SeoPresenterImpl {
+    fn to_meta_json(&self, meta: ArticleMeta) -> HttpResponse {
+        let res = ArticleMetaResponse::from(meta);
+        HttpResponse::Ok().json(res)
+    }
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use actix_web::HttpResponse;
+
+use crate::{app::features::article::repositories::ArticleRepository, error::AppError};
+
+use super::{entities::ArticleMeta, presenters::SeoPresenter};
+
+#[derive(Clone)]
+pub struct SeoUsecase {
+    article_repository: Arc<dyn ArticleRepository>,
+    seo_presenter: Arc<dyn SeoPresenter>,
+    site_url: String,
+    public_url: String,
+}
+
+impl SeoUsecase {
+    pub fn new(
+        article_repository: Arc<dyn ArticleRepository>,
+        seo_presenter: Arc<dyn SeoPresenter>,
+        site_url: String,
+        public_url: String,
+    ) -> Self {
+        Self {
+            article_repository,
+            seo_presenter,
+            site_url,
+            public_url,
+        }
+    }
+
+    pub fn fetch_article_meta(&self, article_title_slug: String) -> Result<HttpResponse, AppError> {
+        let item = self
+            .article_repository
+            .fetch_article_by_slug(article_title_slug, &None)?;
+        let meta = ArticleMeta::new(item, &self.site_url, &self.public_url);
+        let res = self.seo_presenter.to_meta_json(meta);
+        Ok(res)
+    }
+}
@@ -0,0 +1,76 @@
+use actix_web::{web, HttpRequest};
+
+use crate::{
+    app::drivers::middlewares::{auth, state::AppState},
+    utils::api::ApiResponse,
+};
+
+use super::{
+    requests,
+    usecases::{CreateSeriesUsecaseInput, UpdateSeriesUsecaseInput},
+};
+
+type SeriesSlug = String;
+
+pub async fn create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    form: web::Json<requests::CreateSeriesRequest>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    state
+        .di_container
+        .series_usecase
+        .create_series(CreateSeriesUsecaseInput {
+            current_user,
+            title: form.series.title.clone(),
+            description: form.series.description.clone(),
+            article_slugs: form.series.articles.clone().unwrap_or_default(),
+        })
+}
+
+pub async fn show(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<SeriesSlug>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req).ok();
+    let series_slug = path.into_inner();
+    state
+        .di_container
+        .series_usecase
+        .fetch_series(&series_slug, &current_user)
+}
+
+pub async fn update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<SeriesSlug>,
+    form: web::Json<requests::UpdateSeriesRequest>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let series_slug = path.into_inner();
+    state
+        .di_container
+        .series_usecase
+        .update_series(UpdateSeriesUsecaseInput {
+            current_user,
+            series_slug,
+            title: form.series.title.clone(),
+            description: form.series.description.clone(),
+            article_slugs: form.series.articles.clone(),
+        })
+}
+
+pub async fn delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<SeriesSlug>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let series_slug = path.into_inner();
+    state
+        .di_container
+        .series_usecase
+        .delete_series(&series_slug, &current_user)
+}
@@ -0,0 +1,219 @@
+use chrono::NaiveDateTime;
+use diesel::dsl::Eq;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    app::features::{article::entities::Article, user::entities::User},
+    error::AppError,
+    schema::{articles, series, series_articles},
+    utils::converter,
+};
+
+type WithSlug<T> = Eq<series::slug, T>;
+type WithSeriesId<T> = Eq<series_articles::series_id, T>;
+
+#[derive(Identifiable, Queryable, Associations, Serialize, Deserialize, Debug, Clone)]
+#[diesel(belongs_to(User, foreign_key = author_id))]
+#[diesel(table_name = series)]
+pub struct Series {
+    pub id: Uuid,
+    pub author_id: Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl Series {
+    fn with_slug(slug: &str) -> WithSlug<&str> {
+        series::slug.eq(slug)
+    }
+
+    pub fn convert_title_to_slug(title: &str) -> String {
+        converter::to_kebab(title)
+    }
+}
+
+impl Series {
+    pub fn create(conn: &mut PgConnection, record: &CreateSeries) -> Result<Self, AppError> {
+        let item = diesel::insert_into(series::table)
+            .values(record)
+            .get_result::<Self>(conn)?;
+        Ok(item)
+    }
+
+    pub fn update(
+        conn: &mut PgConnection,
+        series_id: &Uuid,
+        record: &UpdateSeries,
+    ) -> Result<Self, AppError> {
+        let t = series::table.find(series_id);
+        let item = diesel::update(t).set(record).get_result::<Self>(conn)?;
+        Ok(item)
+    }
+
+    pub fn delete(conn: &mut PgConnection, series_id: &Uuid) -> Result<(), AppError> {
+        diesel::delete(series::table.find(series_id)).execute(conn)?;
+        Ok(())
+    }
+
+    pub fn fetch_by_slug_with_author(
+        conn: &mut PgConnection,
+        slug: &str,
+    ) -> Result<(Self, User), AppError> {
+        use crate::schema::users;
+
+        let t = series::table
+            .inner_join(users::table)
+            .filter(Self::with_slug(slug));
+        let result = t.get_result::<(Self, User)>(conn)?;
+        Ok(result)
+    }
+
+    pub fn fetch_articles(&self, conn: &mut PgConnection) -> Result<Vec<Article>, AppError> {
+        let t = series_articles::table
+            .inner_join(articles::table)
+            .filter(SeriesArticle::with_series_id(&self.id))
+            .filter(Article::not_deleted())
+            .order(series_articles::position.asc())
+            .select(articles::all_columns);
+        let list = t.load::<Article>(conn)?;
+        Ok(list)
+    }
+}
+
+#[derive(Identifiable, Queryable, Insertable, Associations, Debug, Clone)]
+#[diesel(belongs_to(Series, foreign_key = series_id))]
+#[diesel(belongs_to(Article, foreign_key = article_id))]
+#[diesel(primary_key(series_id, article_id))]
+#[diesel(table_name = series_articles)]
+pub struct SeriesArticle {
+    pub series_id: Uuid,
+    pub article_id: Uuid,
+    pub position: i32,
+}
+
+impl SeriesArticle {
+    fn with_series_id(series_id: &Uuid) -> WithSeriesId<&Uuid> {
+        series_articles::series_id.eq(series_id)
+    }
+}
+
+impl SeriesArticle {
+    // Replaces the membership of a series, numbering articles from 1 in the given order.
+    pub fn replace_all(
+        conn: &mut PgConnection,
+        series_id: &Uuid,
+        article_ids: &[Uuid],
+    ) -> Result<(), AppError> {
+        diesel::delete(series_articles::table.filter(Self::with_series_id(series_id)))
+            .execute(conn)?;
+        let records = article_ids
+            .iter()
+            .enumerate()
+            .map(|(index, article_id)| SeriesArticle {
+                series_id: *series_id,
+                article_id: *article_id,
+                position: index as i32 + 1,
+            })
+            .collect::<Vec<_>>();
+        diesel::insert_into(series_articles::table)
+            .values(&records)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    // The first of the articles that already belongs to another series, with
+    // the title of that series, as an article is part of at most one series.
+    pub fn fetch_other_membership(
+        conn: &mut PgConnection,
+        series_id: &Uuid,
+        article_ids: &[Uuid],
+    ) -> Result<Option<(String, String)>, AppError> {
+        let membership = series_articles::table
+            .inner_join(series::table)
+            .inner_join(articles::table)
+            .filter(series_articles::article_id.eq_any(article_ids))
+            .filter(series_articles::series_id.ne(series_id))
+            .select((articles::slug, series::title))
+            .first::<(String, String)>(conn)
+            .optional()?;
+        Ok(membership)
+    }
+
+    pub fn fetch_navigation(
+        conn: &mut PgConnection,
+        article_id: &Uuid,
+    ) -> Result<Option<SeriesNavigation>, AppError> {
+        let membership = series_articles::table
+            .inner_join(series::table)
+            .filter(series_articles::article_id.eq(article_id))
+            .select((series::all_columns, series_articles::position))
+            .first::<(Series, i32)>(conn)
+            .optional()?;
+        let (series, position) = match membership {
+            Some(membership) => membership,
+            None => return Ok(None),
+        };
+        let siblings = series_articles::table
+            .inner_join(articles::table)
+            .filter(Self::with_series_id(&series.id))
+            .filter(Article::not_deleted())
+            .order(series_articles::position.asc())
+            .select((series_articles::position, articles::slug, articles::title))
+            .load::<(i32, String, String)>(conn)?;
+        let total = siblings.len() as i64;
+        let link = |(_, slug, title): &(i32, String, String)| SeriesArticleLink {
+            slug: slug.to_owned(),
+            title: title.to_owned(),
+        };
+        let previous = siblings
+            .iter()
+            .rev()
+            .find(|(p, _, _)| *p < position)
+            .map(link);
+        let next = siblings.iter().find(|(p, _, _)| *p > position).map(link);
+        Ok(Some(SeriesNavigation {
+            series,
+            position,
+            total,
+            previous,
+            next,
+        }))
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = series)]
+pub struct CreateSeries {
+    pub author_id: Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+}
+
+#[derive(AsChangeset)]
+#[diesel(table_name = series)]
+pub struct UpdateSeries {
+    pub slug: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SeriesArticleLink {
+    pub slug: String,
+    pub title: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct SeriesNavigation {
+    pub series: Series,
+    pub position: i32,
+    pub total: i64,
+    pub previous: Option<SeriesArticleLink>,
+    pub next: Option<SeriesArticleLink>,
+}
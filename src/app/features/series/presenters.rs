@@ -0,0 +1,131 @@
+use actix_web::HttpResponse;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::features::{
+        article::{entities::Article, presenters::AuthorContent},
+        profile::entities::Profile,
+    },
+    utils::date::Iso8601,
+};
+
+use super::entities::{Series, SeriesArticleLink, SeriesNavigation};
+
+#[derive(Deserialize, Serialize)]
+pub struct SingleSeriesResponse {
+    pub series: SeriesContent,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeriesContent {
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub articles: Vec<SeriesArticleContent>,
+    pub created_at: Iso8601,
+    pub updated_at: Iso8601,
+    pub author: AuthorContent,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct SeriesArticleContent {
+    pub position: i32,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+}
+
+impl From<(Series, Profile, Vec<Article>)> for SingleSeriesResponse {
+    fn from((series, profile, article_list): (Series, Profile, Vec<Article>)) -> Self {
+        Self {
+            series: SeriesContent {
+                slug: series.slug,
+                title: series.title,
+                description: series.description,
+                articles: article_list
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, article)| SeriesArticleContent {
+                        position: index as i32 + 1,
+                        slug: article.slug,
+                        title: article.title,
+                        description: article.description,
+                    })
+                    .collect(),
+                created_at: Iso8601(series.created_at),
+                updated_at: Iso8601(series.updated_at),
+                author: AuthorContent {
+                    username: profile.username,
+                    bio: profile.bio,
+                    image: profile.image,
+                    following: profile.following,
+                },
+            },
+        }
+    }
+}
+
+// Series block embedded in `ArticleContent`.
+#[derive(Deserialize, Serialize)]
+pub struct SeriesNavigationContent {
+    pub slug: String,
+    pub title: String,
+    pub position: i32,
+    pub total: i64,
+    pub previous: Option<SeriesLinkContent>,
+    pub next: Option<SeriesLinkContent>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct SeriesLinkContent {
+    pub slug: String,
+    pub title: String,
+}
+
+impl From<SeriesArticleLink> for SeriesLinkContent {
+    fn from(link: SeriesArticleLink) -> Self {
+        Self {
+            slug: link.slug,
+            title: link.title,
+        }
+    }
+}
+
+impl From<SeriesNavigation> for SeriesNavigationContent {
+    fn from(navigation: SeriesNavigation) -> Self {
+        Self {
+            slug: navigation.series.slug,
+            title: navigation.series.title,
+            position: navigation.position,
+            total: navigation.total,
+            previous: navigation.previous.map(SeriesLinkContent::from),
+            next: navigation.next.map(SeriesLinkContent::from),
+        }
+    }
+}
+
+pub trait SeriesPresenter: Send + Sync + 'static {
+    fn to_single_json(&self, item: (Series, Profile, Vec<Article>)) -> HttpResponse;
+    fn to_http_res(&self) -> HttpResponse;
+}
+
+#[derive(Clone)]
+pub struct SeriesPresenterImpl {}
+
+impl SeriesPresenterImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl SeriesPresenter for SeriesPresenterImpl {
+    fn to_single_json(&self, item: (Series, Profile, Vec<Article>)) -> HttpResponse {
+        let res = SingleSeriesResponse::from(item);
+        HttpResponse::Ok().json(res)
+    }
+
+    fn to_http_res(&self) -> HttpResponse {
+        HttpResponse::Ok().json(())
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+
+use diesel::prelude::*;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    app::features::{article::entities::Article, profile::entities::Profile, user::entities::User},
+    error::AppError,
+    schema::articles,
+    utils::db::DbPool,
+};
+
+use super::entities::{CreateSeries, Series, SeriesArticle, SeriesNavigation, UpdateSeries};
+
+type SeriesWithArticles = (Series, Profile, Vec<Article>);
+
+pub trait SeriesRepository: Send + Sync + 'static {
+    fn create_series(
+        &self,
+        input: CreateSeriesRepositoryInput,
+    ) -> Result<SeriesWithArticles, AppError>;
+    fn fetch_series(
+        &self,
+        series_slug: &str,
+        current_user: &Option<User>,
+    ) -> Result<SeriesWithArticles, AppError>;
+    fn update_series(
+        &self,
+        input: UpdateSeriesRepositoryInput,
+    ) -> Result<SeriesWithArticles, AppError>;
+    fn delete_series(&self, series_slug: &str, current_user: &User) -> Result<(), AppError>;
+    fn fetch_navigation(&self, article_id: &Uuid) -> Result<Option<SeriesNavigation>, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SeriesRepositoryImpl {
+    pool: DbPool,
+}
+
+impl SeriesRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    // Resolves article slugs to ids in the given order. Every article must be
+    // written by the series author and listed only once.
+    fn resolve_article_ids(
+        conn: &mut PgConnection,
+        author_id: &Uuid,
+        article_slugs: &[String],
+    ) -> Result<Vec<Uuid>, AppError> {
+        let found = articles::table
+            .filter(articles::author_id.eq(author_id))
+            .filter(articles::slug.eq_any(article_slugs))
+            .filter(Article::not_deleted())
+            .select((articles::slug, articles::id))
+            .load::<(String, Uuid)>(conn)?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        let mut ids: Vec<Uuid> = vec![];
+        for slug in article_slugs {
+            match found.get(slug) {
+                Some(article_id) if !ids.contains(article_id) => ids.push(*article_id),
+                Some(_) => {
+                    return Err(AppError::UnprocessableEntity(
+                        json!({"error": format!("Article {} is listed more than once.", slug)}),
+                    ))
+                }
+                None => {
+                    return Err(AppError::UnprocessableEntity(
+                        json!({"error": format!("Article {} was not found among your articles.", slug)}),
+                    ))
+                }
+            }
+        }
+        Ok(ids)
+    }
+
+    fn fetch_owned(
+        conn: &mut PgConnection,
+        series_slug: &str,
+        current_user: &User,
+    ) -> Result<Series, AppError> {
+        let (series, _) = Series::fetch_by_slug_with_author(conn, series_slug)?;
+        if series.author_id != current_user.id {
+            return Err(AppError::Forbidden(
+                json!({"error": "Only the author can change this series."}),
+            ));
+        }
+        Ok(series)
+    }
+
+    fn replace_articles(
+        conn: &mut PgConnection,
+        series_id: &Uuid,
+        article_ids: &[Uuid],
+    ) -> Result<(), AppError> {
+        if let Some((article_slug, series_title)) =
+            SeriesArticle::fetch_other_membership(conn, series_id, article_ids)?
+        {
+            return Err(AppError::UnprocessableEntity(json!({
+                "error": format!(
+                    "Article {} already belongs to series {}.",
+                    article_slug, series_title
+                )
+            })));
+        }
+        SeriesArticle::replace_all(conn, series_id, article_ids)
+    }
+}
+
+impl SeriesRepository for SeriesRepositoryImpl {
+    fn create_series(
+        &self,
+        input: CreateSeriesRepositoryInput,
+    ) -> Result<SeriesWithArticles, AppError> {
+        let conn = &mut self.pool.get()?;
+        conn.transaction(|conn| {
+            let article_ids =
+                Self::resolve_article_ids(conn, &input.current_user.id, &input.article_slugs)?;
+            let series = Series::create(
+                conn,
+                &CreateSeries {
+                    author_id: input.current_user.id,
+                    slug: input.slug,
+                    title: input.title,
+                    description: input.description,
+                },
+            )?;
+            Self::replace_articles(conn, &series.id, &article_ids)?;
+            let article_list = series.fetch_articles(conn)?;
+            let profile = input.current_user.to_profile(conn, &None);
+            Ok((series, profile, article_list))
+        })
+    }
+
+    fn fetch_series(
+        &self,
+        series_slug: &str,
+        current_user: &Option<User>,
+    ) -> Result<SeriesWithArticles, AppError> {
+        let conn = &mut self.pool.get()?;
+        let (series, author) = Series::fetch_by_slug_with_author(conn, series_slug)?;
+        let article_list = series.fetch_articles(conn)?;
+        let profile = author.to_profile(conn, current_user);
+        Ok((series, profile, article_list))
+    }
+
+    fn update_series(
+        &self,
+        input: UpdateSeriesRepositoryInput,
+    ) -> Result<SeriesWithArticles, AppError> {
+        let conn = &mut self.pool.get()?;
+        conn.transaction(|conn| {
+            let series = Self::fetch_owned(conn, &input.series_slug, &input.current_user)?;
+            let series = if input.title.is_some() || input.description.is_some() {
+                Series::update(
+                    conn,
+                    &series.id,
+                    &UpdateSeries {
+                        slug: input.slug,
+                        title: input.title,
+                        description: input.description,
+                    },
+                )?
+            } else {
+                series
+            };
+            if let Some(article_slugs) = &input.article_slugs {
+                let article_ids =
+                    Self::resolve_article_ids(conn, &input.current_user.id, article_slugs)?;
+                Self::replace_articles(conn, &series.id, &article_ids)?;
+            }
+            let article_list = series.fetch_articles(conn)?;
+            let profile = input.current_user.to_profile(conn, &None);
+            Ok((series, profile, article_list))
+        })
+    }
+
+    fn delete_series(&self, series_slug: &str, current_user: &User) -> Result<(), AppError> {
+        let conn = &mut self.pool.get()?;
+        let series = Self::fetch_owned(conn, series_slug, current_user)?;
+        Series::delete(conn, &series.id)
+    }
+
+    fn fetch_navigation(&self, article_id: &Uuid) -> Result<Option<SeriesNavigation>, AppError> {
+        let conn = &mut self.pool.get()?;
+        SeriesArticle::fetch_navigation(conn, article_id)
+    }
+}
+
+pub struct CreateSeriesRepositoryInput {
+    pub current_user: User,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub article_slugs: Vec<String>,
+}
+
+pub struct UpdateSeriesRepositoryInput {
+    pub current_user: User,
+    pub series_slug: String,
+    pub slug: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub article_slugs: Option<Vec<String>>,
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct CreateSeriesRequest {
+    pub series: CreateSeriesInner,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct CreateSeriesInner {
+    pub title: String,
+    pub description: String,
+    pub articles: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct UpdateSeriesRequest {
+    pub series: UpdateSeriesInner,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct UpdateSeriesInner {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub articles: Option<Vec<String>>,
+}
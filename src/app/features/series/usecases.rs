@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use actix_web::HttpResponse;
+
+use crate::{app::features::user::entities::User, error::AppError};
+
+use super::{
+    entities::Series,
+    presenters::SeriesPresenter,
+    repositories::{CreateSeriesRepositoryInput, SeriesRepository, UpdateSeriesRepositoryInput},
+};
+
+#[derive(Clone)]
+pub struct SeriesUsecase {
+    series_repository: Arc<dyn SeriesRepository>,
+    series_presenter: Arc<dyn SeriesPresenter>,
+}
+
+impl SeriesUsecase {
+    pub fn new(
+        series_repository: Arc<dyn SeriesRepository>,
+        series_presenter: Arc<dyn SeriesPresenter>,
+    ) -> Self {
+        Self {
+            series_repository,
+            series_presenter,
+        }
+    }
+
+    pub fn create_series(&self, input: CreateSeriesUsecaseInput) -> Result<HttpResponse, AppError> {
+        let slug = Series::convert_title_to_slug(&input.title);
+        let result = self
+            .series_repository
+            .create_series(CreateSeriesRepositoryInput {
+                current_user: input.current_user,
+                slug,
+                title: input.title,
+                description: input.description,
+                article_slugs: input.article_slugs,
+            })?;
+        let res = self.series_presenter.to_single_json(result);
+        Ok(res)
+    }
+
+    pub fn fetch_series(
+        &self,
+        series_slug: &str,
+        current_user: &Option<User>,
+    ) -> Result<HttpResponse, AppError> {
+        let result = self
+            .series_repository
+            .fetch_series(series_slug, current_user)?;
+        let res = self.series_presenter.to_single_json(result);
+        Ok(res)
+    }
+
+    pub fn update_series(&self, input: UpdateSeriesUsecaseInput) -> Result<HttpResponse, AppError> {
+        let slug = input
+            .title
+            .as_ref()
+            .map(|title| Series::convert_title_to_slug(title));
+        let result = self
+            .series_repository
+            .update_series(UpdateSeriesRepositoryInput {
+                current_user: input.current_user,
+                series_slug: input.series_slug,
+                slug,
+                title: input.title,
+                description: input.description,
+                article_slugs: input.article_slugs,
+            })?;
+        let res = self.series_presenter.to_single_json(result);
+        Ok(res)
+    }
+
+    pub fn delete_series(
+        &self,
+        series_slug: &str,
+        current_user: &User,
+    ) -> Result<HttpResponse, AppError> {
+        self.series_repository
+            .delete_series(series_slug, current_user)?;
+        let res = self.series_presenter.to_http_res();
+        Ok(res)
+    }
+}
+
+pub struct CreateSeriesUsecaseInput {
+    pub current_user: User,
+    pub title: String,
+    pub description: String,
+    pub article_slugs: Vec<String>,
+}
+
+pub struct UpdateSeriesUsecaseInput {
+    pub current_user: User,
+    pub series_slug: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub article_slugs: Option<Vec<String>>,
+}
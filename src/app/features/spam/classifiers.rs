@@ -0,0 +1,144 @@
+use std::{sync::Arc, time::Instant};
+
+use chrono::{Duration, Utc};
+
+use crate::{
+    app::features::{comment::entities::Comment, report::entities::Report, user::entities::User},
+    constants::{
+        SPAM_BAYES_MIN_DOCS, SPAM_BAYES_THRESHOLD, SPAM_BAYES_TRAINING_LIMIT, SPAM_LINKS_MIN,
+        SPAM_LINK_DENSITY_MAX, SPAM_MODEL_TTL_SECS, SPAM_NEW_ACCOUNT_COMMENTS_LIMIT,
+        SPAM_NEW_ACCOUNT_HOURS, SPAM_REPEATED_COMMENTS_LIMIT, SPAM_REPEAT_WINDOW_HOURS,
+        SPAM_SIGNUPS_PER_DOMAIN_LIMIT, SPAM_SIGNUP_WINDOW_MINUTES, SPAM_VELOCITY_WINDOW_MINUTES,
+    },
+    error::AppError,
+    utils::{bayes::NaiveBayes, cache::MemoryCache, db::DbPool},
+};
+
+use super::entities::{SpamSignal, SpamSubject, SpamVerdict};
+
+pub trait SpamClassifier: Send + Sync + 'static {
+    fn classify(&self, subject: &SpamSubject) -> Result<SpamVerdict, AppError>;
+}
+
+#[derive(Clone)]
+pub struct TrainedModel {
+    trained_at: Instant,
+    model: Arc<NaiveBayes>,
+}
+
+pub type SpamModelCache = MemoryCache<(), TrainedModel>;
+
+// Heuristics on the content and on the recent activity of its author, plus a
+// naive Bayes model trained on the comments of this instance: the ones
+// moderators removed as spam against the ones that are published.
+#[derive(Clone)]
+pub struct HeuristicSpamClassifier {
+    pool: DbPool,
+    model_cache: SpamModelCache,
+}
+
+impl HeuristicSpamClassifier {
+    pub fn new(pool: DbPool, model_cache: SpamModelCache) -> Self {
+        Self { pool, model_cache }
+    }
+
+    fn link_density(body: &str) -> Option<SpamSignal> {
+        let words = body.split_whitespace().collect::<Vec<_>>();
+        let links = words
+            .iter()
+            .filter(|word| {
+                word.contains("http://") || word.contains("https://") || word.starts_with("www.")
+            })
+            .count();
+        let density = links as f64 / words.len().max(1) as f64;
+        (links >= SPAM_LINKS_MIN && density > SPAM_LINK_DENSITY_MAX).then_some(
+            SpamSignal::LinkDensity {
+                links,
+                words: words.len(),
+            },
+        )
+    }
+
+    // Retrained once the model is older than its time to live. Until both
+    // classes have enough examples the model stays out of the verdict.
+    fn model(&self, conn: &mut diesel::PgConnection) -> Result<Arc<NaiveBayes>, AppError> {
+        if let Some(trained) = self.model_cache.get(&()) {
+            if trained.trained_at.elapsed().as_secs() < SPAM_MODEL_TTL_SECS {
+                return Ok(trained.model);
+            }
+        }
+        let spam = Report::fetch_removed_spam_comment_bodies(conn, SPAM_BAYES_TRAINING_LIMIT)?;
+        let ham = Comment::fetch_recent_bodies(conn, SPAM_BAYES_TRAINING_LIMIT)?;
+        let model = Arc::new(NaiveBayes::train(&spam, &ham));
+        self.model_cache.insert(
+            (),
+            TrainedModel {
+                trained_at: Instant::now(),
+                model: model.clone(),
+            },
+        );
+        Ok(model)
+    }
+
+    fn classify_comment(&self, author: &User, body: &str) -> Result<SpamVerdict, AppError> {
+        let conn = &mut self.pool.get()?;
+        let now = Utc::now().naive_utc();
+        let mut signals = vec![];
+        signals.extend(Self::link_density(body));
+
+        let count = Comment::count_same_body_by_author_since(
+            conn,
+            &author.id,
+            body,
+            now - Duration::hours(SPAM_REPEAT_WINDOW_HOURS),
+        )?;
+        if count >= SPAM_REPEATED_COMMENTS_LIMIT {
+            signals.push(SpamSignal::RepeatedContent { count });
+        }
+
+        if author.created_at > now - Duration::hours(SPAM_NEW_ACCOUNT_HOURS) {
+            let count = Comment::count_by_author_since(
+                conn,
+                &author.id,
+                now - Duration::minutes(SPAM_VELOCITY_WINDOW_MINUTES),
+            )?;
+            if count >= SPAM_NEW_ACCOUNT_COMMENTS_LIMIT {
+                signals.push(SpamSignal::AccountVelocity { count });
+            }
+        }
+
+        let model = self.model(conn)?;
+        if model.spam_docs() >= SPAM_BAYES_MIN_DOCS && model.ham_docs() >= SPAM_BAYES_MIN_DOCS {
+            let probability = model.spam_probability(body);
+            if probability >= SPAM_BAYES_THRESHOLD {
+                signals.push(SpamSignal::NaiveBayes { probability });
+            }
+        }
+        Ok(SpamVerdict { signals })
+    }
+
+    fn classify_signup(&self, email: &str) -> Result<SpamVerdict, AppError> {
+        let conn = &mut self.pool.get()?;
+        let mut signals = vec![];
+        if let Some((_, domain)) = email.rsplit_once('@') {
+            let since = Utc::now().naive_utc() - Duration::minutes(SPAM_SIGNUP_WINDOW_MINUTES);
+            let count = User::count_signups_by_email_domain(conn, domain, since)?;
+            if count >= SPAM_SIGNUPS_PER_DOMAIN_LIMIT {
+                signals.push(SpamSignal::SignupVelocity {
+                    domain: domain.to_lowercase(),
+                    count,
+                });
+            }
+        }
+        Ok(SpamVerdict { signals })
+    }
+}
+
+impl SpamClassifier for HeuristicSpamClassifier {
+    fn classify(&self, subject: &SpamSubject) -> Result<SpamVerdict, AppError> {
+        match subject {
+            SpamSubject::Comment { author, body } => self.classify_comment(author, body),
+            SpamSubject::Signup { email } => self.classify_signup(email),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::app::features::user::entities::User;
+
+// What gets checked before it is stored.
+pub enum SpamSubject<'a> {
+    Comment { author: &'a User, body: &'a str },
+    Signup { email: &'a str },
+}
+
+// Why a classifier took something for spam.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SpamSignal {
+    LinkDensity { links: usize, words: usize },
+    RepeatedContent { count: i64 },
+    AccountVelocity { count: i64 },
+    SignupVelocity { domain: String, count: i64 },
+    NaiveBayes { probability: f64 },
+}
+
+impl SpamSignal {
+    pub fn describe(&self) -> String {
+        match self {
+            SpamSignal::LinkDensity { links, words } => {
+                format!("{} links in {} words", links, words)
+            }
+            SpamSignal::RepeatedContent { count } => {
+                format!("the same text was posted {} times before", count)
+            }
+            SpamSignal::AccountVelocity { count } => {
+                format!("a new account posted {} comments in a short time", count)
+            }
+            SpamSignal::SignupVelocity { domain, count } => {
+                format!("{} accounts signed up at {} in a short time", count, domain)
+            }
+            SpamSignal::NaiveBayes { probability } => {
+                format!(
+                    "the spam model rated it {:.0}% likely spam",
+                    probability * 100.0
+                )
+            }
+        }
+    }
+}
+
+// Nothing flagged means the content goes through as usual.
+#[derive(Clone, Debug, Default)]
+pub struct SpamVerdict {
+    pub signals: Vec<SpamSignal>,
+}
+
+impl SpamVerdict {
+    pub fn is_spam(&self) -> bool {
+        !self.signals.is_empty()
+    }
+
+    // Shown to moderators as the details of the report the filter files.
+    pub fn details(&self) -> String {
+        let reasons = self
+            .signals
+            .iter()
+            .map(SpamSignal::describe)
+            .collect::<Vec<_>>()
+            .join("; ");
+        format!("Held by the spam filter: {}.", reasons)
+    }
+}
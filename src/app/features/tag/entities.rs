@@ -10,7 +10,11 @@ use diesel::{
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::{app::features::article::entities::Article, error::AppError, schema::tags};
+use crate::{
+    app::features::article::entities::Article,
+    error::AppError,
+    schema::{articles, tags},
+};
 
 #[derive(
     Identifiable,
@@ -34,8 +38,6 @@ pub struct Tag {
 }
 
 type All<DB> = Select<tags::table, AsSelect<Tag, DB>>;
-type WithName<T> = Eq<tags::name, T>;
-type ByName<T, DB> = Filter<All<DB>, WithName<T>>;
 type WithArticleId<T> = Eq<tags::article_id, T>;
 type ByArticleId<T, DB> = Filter<All<DB>, WithArticleId<T>>;
 
@@ -47,17 +49,6 @@ impl Tag {
         tags::table.select(Tag::as_select())
     }
 
-    fn with_name(name: &str) -> WithName<&str> {
-        tags::name.eq(name)
-    }
-
-    fn by_name<DB>(name: &str) -> ByName<&str, DB>
-    where
-        DB: Backend,
-    {
-        Self::all().filter(Self::with_name(name))
-    }
-
     fn with_article_id(article_id: &Uuid) -> WithArticleId<&Uuid> {
         tags::article_id.eq(article_id)
     }
@@ -79,21 +70,22 @@ impl Tag {
     }
 
     pub fn fetch(conn: &mut PgConnection) -> Result<Vec<Self>, AppError> {
-        let list = tags::table.load::<Self>(conn)?;
+        let list = tags::table
+            .inner_join(articles::table)
+            .filter(Article::not_deleted())
+            .select(tags::all_columns)
+            .load::<Self>(conn)?;
         Ok(list)
     }
 
-    pub fn fetch_article_ids_by_name(
-        conn: &mut PgConnection,
-        tag_name: &str,
-    ) -> Result<Vec<Uuid>, AppError> {
-        let t = Self::by_name(tag_name);
-        let article_ids = t
-            .load::<Tag>(conn)?
-            .iter()
-            .map(|tag| tag.article_id)
-            .collect();
-        Ok(article_ids)
+    // Subquery of the articles tagged with any of the given names.
+    pub fn article_ids_by_names(
+        names: &[String],
+    ) -> tags::BoxedQuery<'_, diesel::pg::Pg, sql_types::Uuid> {
+        tags::table
+            .filter(tags::name.eq_any(names))
+            .select(tags::article_id)
+            .into_boxed()
     }
 
     pub fn create_list(
@@ -107,6 +99,23 @@ impl Tag {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TagMatch {
+    #[default]
+    Any,
+    All,
+}
+
+impl TagMatch {
+    pub fn parse(tag_match: &str) -> Option<Self> {
+        match tag_match {
+            "any" => Some(TagMatch::Any),
+            "all" => Some(TagMatch::All),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Insertable)]
 #[diesel(table_name = tags)]
 pub struct CreateTag<'a> {
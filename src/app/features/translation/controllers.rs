@@ -0,0 +1,55 @@
+use actix_web::{web, HttpRequest};
+
+use crate::{
+    app::drivers::middlewares::{auth, state::AppState},
+    utils::api::ApiResponse,
+};
+
+use super::{requests, usecases::SaveTranslationUsecaseInput};
+
+type ArticleTitleSlug = String;
+type LocaleSlug = String;
+
+pub async fn index(state: web::Data<AppState>, path: web::Path<ArticleTitleSlug>) -> ApiResponse {
+    let article_title_slug = path.into_inner();
+    state
+        .di_container
+        .translation_usecase
+        .fetch_translations(&article_title_slug)
+}
+
+pub async fn save(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(ArticleTitleSlug, LocaleSlug)>,
+    form: web::Json<requests::SaveTranslationRequest>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let (article_title_slug, locale) = path.into_inner();
+    let form = form.into_inner().translation;
+    state
+        .di_container
+        .translation_usecase
+        .save_translation(SaveTranslationUsecaseInput {
+            current_user,
+            article_title_slug,
+            locale,
+            title: form.title,
+            description: form.description.unwrap_or_default(),
+            body: form.body,
+        })
+}
+
+pub async fn delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(ArticleTitleSlug, LocaleSlug)>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let (article_title_slug, locale) = path.into_inner();
+    state.di_container.translation_usecase.delete_translation(
+        current_user,
+        article_title_slug,
+        locale,
+    )
+}
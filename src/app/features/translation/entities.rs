@@ -0,0 +1,146 @@
+use chrono::NaiveDateTime;
+use diesel::dsl::Eq;
+use diesel::pg::upsert::excluded;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    app::features::article::entities::{Article, ReadingMetadata},
+    error::AppError,
+    schema::{article_translations, articles},
+    utils::converter,
+};
+
+type WithArticleId<T> = Eq<article_translations::article_id, T>;
+type WithLocale<T> = Eq<article_translations::locale, T>;
+
+#[derive(Identifiable, Queryable, Associations, Serialize, Deserialize, Debug, Clone)]
+#[diesel(belongs_to(Article, foreign_key = article_id))]
+#[diesel(table_name = article_translations)]
+pub struct ArticleTranslation {
+    pub id: Uuid,
+    pub article_id: Uuid,
+    pub locale: String,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl ArticleTranslation {
+    fn with_article_id(article_id: &Uuid) -> WithArticleId<&Uuid> {
+        article_translations::article_id.eq(article_id)
+    }
+
+    fn with_locale(locale: &str) -> WithLocale<&str> {
+        article_translations::locale.eq(locale)
+    }
+
+    // The locale suffix keeps a translation from taking the slug of the original
+    // article when both share a title.
+    pub fn convert_title_to_slug(title: &str, locale: &str) -> String {
+        format!("{}-{}", converter::to_kebab(title), locale.to_lowercase())
+    }
+
+    // Shows the article with the translated text, reading metadata included.
+    // The slug stays the article's own, as every other endpoint looks it up.
+    pub fn apply_to(&self, article: Article) -> Article {
+        let reading_metadata = ReadingMetadata::from_body(&self.body);
+        Article {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            body: self.body.clone(),
+            word_count: reading_metadata.word_count,
+            reading_minutes: reading_metadata.reading_minutes,
+            table_of_contents: reading_metadata.table_of_contents_json(),
+            ..article
+        }
+    }
+}
+
+impl ArticleTranslation {
+    // Saving a locale that already exists replaces its text.
+    pub fn upsert(
+        conn: &mut PgConnection,
+        record: &CreateArticleTranslation,
+    ) -> Result<Self, AppError> {
+        let item = diesel::insert_into(article_translations::table)
+            .values(record)
+            .on_conflict((
+                article_translations::article_id,
+                article_translations::locale,
+            ))
+            .do_update()
+            .set((
+                article_translations::slug.eq(excluded(article_translations::slug)),
+                article_translations::title.eq(excluded(article_translations::title)),
+                article_translations::description.eq(excluded(article_translations::description)),
+                article_translations::body.eq(excluded(article_translations::body)),
+            ))
+            .get_result::<Self>(conn)?;
+        Ok(item)
+    }
+
+    pub fn delete(
+        conn: &mut PgConnection,
+        article_id: &Uuid,
+        locale: &str,
+    ) -> Result<(), AppError> {
+        let t = article_translations::table
+            .filter(Self::with_article_id(article_id))
+            .filter(Self::with_locale(locale));
+        diesel::delete(t).get_result::<Self>(conn)?;
+        Ok(())
+    }
+
+    pub fn fetch_by_article_id(
+        conn: &mut PgConnection,
+        article_id: &Uuid,
+    ) -> Result<Vec<Self>, AppError> {
+        let list = article_translations::table
+            .filter(Self::with_article_id(article_id))
+            .order(article_translations::locale.asc())
+            .load::<Self>(conn)?;
+        Ok(list)
+    }
+
+    pub fn fetch_by_slug_with_article(
+        conn: &mut PgConnection,
+        slug: &str,
+    ) -> Result<(Self, Article), AppError> {
+        let item = article_translations::table
+            .inner_join(articles::table)
+            .filter(article_translations::slug.eq(slug))
+            .filter(Article::not_deleted())
+            .first::<(Self, Article)>(conn)?;
+        Ok(item)
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = article_translations)]
+pub struct CreateArticleTranslation {
+    pub article_id: Uuid,
+    pub locale: String,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+}
+
+// Languages asked for by the reader: `?lang=` wins over `Accept-Language`, and
+// falls back to the original text rather than to `Accept-Language`.
+pub struct LocalePreference {
+    pub lang: Option<String>,
+    pub accept_language: Vec<String>,
+}
+
+// Which translation an article is shown in, and which ones exist.
+pub struct Localization {
+    pub locale: Option<String>,
+    pub translation_slug: Option<String>,
+    pub locales: Vec<String>,
+}
@@ -0,0 +1,77 @@
+use actix_web::HttpResponse;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::date::Iso8601;
+
+use super::entities::ArticleTranslation;
+
+#[derive(Deserialize, Serialize)]
+pub struct SingleTranslationResponse {
+    pub translation: TranslationContent,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct MultipleTranslationsResponse {
+    pub translations: Vec<TranslationContent>,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslationContent {
+    pub locale: String,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub created_at: Iso8601,
+    pub updated_at: Iso8601,
+}
+
+impl From<ArticleTranslation> for TranslationContent {
+    fn from(translation: ArticleTranslation) -> Self {
+        Self {
+            locale: translation.locale,
+            slug: translation.slug,
+            title: translation.title,
+            description: translation.description,
+            body: translation.body,
+            created_at: Iso8601(translation.created_at),
+            updated_at: Iso8601(translation.updated_at),
+        }
+    }
+}
+
+pub trait TranslationPresenter: Send + Sync + 'static {
+    fn to_single_json(&self, item: ArticleTranslation) -> HttpResponse;
+    fn to_multi_json(&self, list: Vec<ArticleTranslation>) -> HttpResponse;
+    fn to_http_res(&self) -> HttpResponse;
+}
+
+#[derive(Clone)]
+pub struct TranslationPresenterImpl {}
+
+impl TranslationPresenterImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl TranslationPresenter for TranslationPresenterImpl {
+    fn to_single_json(&self, item: ArticleTranslation) -> HttpResponse {
+        let res = SingleTranslationResponse {
+            translation: TranslationContent::from(item),
+        };
+        HttpResponse::Ok().json(res)
+    }
+
+    fn to_multi_json(&self, list: Vec<ArticleTranslation>) -> HttpResponse {
+        let res = MultipleTranslationsResponse {
+            translations: list.into_iter().map(TranslationContent::from).collect(),
+        };
+        HttpResponse::Ok().json(res)
+    }
+
+    fn to_http_res(&self) -> HttpResponse {
+        HttpResponse::Ok().json(())
+    }
+}
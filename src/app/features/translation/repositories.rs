@@ -0,0 +1,137 @@
+use diesel::prelude::*;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    app::features::{
+        article::entities::Article, coauthor::entities::ArticleAuthor, user::entities::User,
+    },
+    error::AppError,
+    utils::db::DbPool,
+};
+
+use super::entities::{ArticleTranslation, CreateArticleTranslation};
+
+pub trait TranslationRepository: Send + Sync + 'static {
+    fn fetch_translations(
+        &self,
+        article_title_slug: &str,
+    ) -> Result<Vec<ArticleTranslation>, AppError>;
+    fn fetch_translations_by_article_id(
+        &self,
+        article_id: &Uuid,
+    ) -> Result<Vec<ArticleTranslation>, AppError>;
+    fn fetch_translation_by_slug(
+        &self,
+        translation_slug: &str,
+    ) -> Result<(ArticleTranslation, Article), AppError>;
+    fn save_translation(
+        &self,
+        input: SaveTranslationRepositoryInput,
+    ) -> Result<ArticleTranslation, AppError>;
+    fn delete_translation(&self, input: DeleteTranslationRepositoryInput) -> Result<(), AppError>;
+}
+
+#[derive(Clone)]
+pub struct TranslationRepositoryImpl {
+    pool: DbPool,
+}
+
+impl TranslationRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    // Owners and editors may translate an article.
+    fn fetch_editable_article(
+        conn: &mut PgConnection,
+        article_title_slug: &str,
+        current_user: &User,
+    ) -> Result<Article, AppError> {
+        let article = Article::fetch_by_slug(conn, article_title_slug)?;
+        match ArticleAuthor::fetch_role(conn, &article.id, &current_user.id)? {
+            Some(_) => Ok(article),
+            None => Err(AppError::Forbidden(
+                json!({"error": "Only an author can translate this article."}),
+            )),
+        }
+    }
+}
+
+impl TranslationRepository for TranslationRepositoryImpl {
+    fn fetch_translations(
+        &self,
+        article_title_slug: &str,
+    ) -> Result<Vec<ArticleTranslation>, AppError> {
+        let conn = &mut self.pool.get()?;
+        let article = Article::fetch_by_slug(conn, article_title_slug)?;
+        ArticleTranslation::fetch_by_article_id(conn, &article.id)
+    }
+
+    fn fetch_translations_by_article_id(
+        &self,
+        article_id: &Uuid,
+    ) -> Result<Vec<ArticleTranslation>, AppError> {
+        let conn = &mut self.pool.get()?;
+        ArticleTranslation::fetch_by_article_id(conn, article_id)
+    }
+
+    fn fetch_translation_by_slug(
+        &self,
+        translation_slug: &str,
+    ) -> Result<(ArticleTranslation, Article), AppError> {
+        let conn = &mut self.pool.get()?;
+        ArticleTranslation::fetch_by_slug_with_article(conn, translation_slug)
+    }
+
+    fn save_translation(
+        &self,
+        input: SaveTranslationRepositoryInput,
+    ) -> Result<ArticleTranslation, AppError> {
+        let conn = &mut self.pool.get()?;
+        let article =
+            Self::fetch_editable_article(conn, &input.article_title_slug, &input.current_user)?;
+        // The article is shown through its translations, so their changes count
+        // as changes of the article for its entity tag.
+        conn.transaction(|conn| {
+            let translation = ArticleTranslation::upsert(
+                conn,
+                &CreateArticleTranslation {
+                    article_id: article.id,
+                    slug: ArticleTranslation::convert_title_to_slug(&input.title, &input.locale),
+                    locale: input.locale,
+                    title: input.title,
+                    description: input.description,
+                    body: input.body,
+                },
+            )?;
+            Article::bump_version(conn, &article.id)?;
+            Ok(translation)
+        })
+    }
+
+    fn delete_translation(&self, input: DeleteTranslationRepositoryInput) -> Result<(), AppError> {
+        let conn = &mut self.pool.get()?;
+        let article =
+            Self::fetch_editable_article(conn, &input.article_title_slug, &input.current_user)?;
+        conn.transaction(|conn| {
+            ArticleTranslation::delete(conn, &article.id, &input.locale)?;
+            Article::bump_version(conn, &article.id)
+        })
+    }
+}
+
+pub struct SaveTranslationRepositoryInput {
+    pub current_user: User,
+    pub article_title_slug: String,
+    pub locale: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+}
+
+pub struct DeleteTranslationRepositoryInput {
+    pub current_user: User,
+    pub article_title_slug: String,
+    pub locale: String,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct SaveTranslationRequest {
+    pub translation: SaveTranslationInner,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct SaveTranslationInner {
+    pub title: String,
+    pub description: Option<String>,
+    pub body: String,
+}
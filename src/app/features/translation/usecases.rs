@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use actix_web::HttpResponse;
+use serde_json::json;
+
+use crate::{app::features::user::entities::User, error::AppError, utils::locale};
+
+use super::{
+    presenters::TranslationPresenter,
+    repositories::{
+        DeleteTranslationRepositoryInput, SaveTranslationRepositoryInput, TranslationRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct TranslationUsecase {
+    translation_repository: Arc<dyn TranslationRepository>,
+    translation_presenter: Arc<dyn TranslationPresenter>,
+}
+
+impl TranslationUsecase {
+    pub fn new(
+        translation_repository: Arc<dyn TranslationRepository>,
+        translation_presenter: Arc<dyn TranslationPresenter>,
+    ) -> Self {
+        Self {
+            translation_repository,
+            translation_presenter,
+        }
+    }
+
+    fn parse_locale(locale: &str) -> Result<String, AppError> {
+        locale::normalize(locale).ok_or_else(|| {
+            AppError::UnprocessableEntity(json!({
+                "error": format!("{} is not a valid locale, use a tag such as en or pt-BR.", locale)
+            }))
+        })
+    }
+
+    pub fn fetch_translations(&self, article_title_slug: &str) -> Result<HttpResponse, AppError> {
+        let list = self
+            .translation_repository
+            .fetch_translations(article_title_slug)?;
+        let res = self.translation_presenter.to_multi_json(list);
+        Ok(res)
+    }
+
+    pub fn save_translation(
+        &self,
+        params: SaveTranslationUsecaseInput,
+    ) -> Result<HttpResponse, AppError> {
+        params.current_user.ensure_not_held()?;
+        let locale = Self::parse_locale(&params.locale)?;
+        let title = params.title.trim().to_owned();
+        if title.is_empty() || params.body.trim().is_empty() {
+            return Err(AppError::UnprocessableEntity(
+                json!({"error": "title and body can't be blank."}),
+            ));
+        }
+        let translation =
+            self.translation_repository
+                .save_translation(SaveTranslationRepositoryInput {
+                    current_user: params.current_user,
+                    article_title_slug: params.article_title_slug,
+                    locale,
+                    title,
+                    description: params.description,
+                    body: params.body,
+                })?;
+        let res = self.translation_presenter.to_single_json(translation);
+        Ok(res)
+    }
+
+    pub fn delete_translation(
+        &self,
+        current_user: User,
+        article_title_slug: String,
+        locale: String,
+    ) -> Result<HttpResponse, AppError> {
+        let locale = Self::parse_locale(&locale)?;
+        self.translation_repository
+            .delete_translation(DeleteTranslationRepositoryInput {
+                current_user,
+                article_title_slug,
+                locale,
+            })?;
+        let res = self.translation_presenter.to_http_res();
+        Ok(res)
+    }
+}
+
+pub struct SaveTranslationUsecaseInput {
+    pub current_user: User,
+    pub article_title_slug: String,
+    pub locale: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+}
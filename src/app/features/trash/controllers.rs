@@ -0,0 +1,41 @@
+use actix_web::{web, HttpRequest};
+
+use crate::{
+    app::drivers::middlewares::{auth, state::AppState},
+    utils::{api::ApiResponse, uuid},
+};
+
+type ArticleTitleSlug = String;
+type CommentIdSlug = String;
+
+pub async fn index(state: web::Data<AppState>, req: HttpRequest) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    state.di_container.trash_usecase.fetch_trash(&current_user)
+}
+
+pub async fn restore_article(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<ArticleTitleSlug>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let article_title_slug = path.into_inner();
+    state
+        .di_container
+        .trash_usecase
+        .restore_article(current_user, article_title_slug)
+}
+
+pub async fn restore_comment(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(ArticleTitleSlug, CommentIdSlug)>,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let (article_title_slug, comment_id) = path.into_inner();
+    let comment_id = uuid::parse(&comment_id)?;
+    state
+        .di_container
+        .trash_usecase
+        .restore_comment(current_user, article_title_slug, comment_id)
+}
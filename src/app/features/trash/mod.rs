@@ -0,0 +1,4 @@
+pub mod controllers;
+pub mod presenters;
+pub mod repositories;
+pub mod usecases;
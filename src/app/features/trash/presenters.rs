@@ -0,0 +1,102 @@
+use actix_web::HttpResponse;
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    app::features::comment::{presenters::SingleCommentResponse, repositories::SingleComment},
+    utils::date::Iso8601,
+};
+
+use super::repositories::Trash;
+
+#[derive(Deserialize, Serialize)]
+pub struct TrashResponse {
+    pub trash: TrashContent,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct TrashContent {
+    pub articles: Vec<TrashedArticleContent>,
+    pub comments: Vec<TrashedCommentContent>,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashedArticleContent {
+    pub slug: String,
+    pub title: String,
+    pub deleted_at: Iso8601,
+    pub purge_at: Iso8601,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashedCommentContent {
+    pub id: Uuid,
+    pub body: String,
+    pub article_slug: String,
+    pub deleted_at: Iso8601,
+    pub purge_at: Iso8601,
+}
+
+impl From<(Trash, Duration)> for TrashResponse {
+    fn from((trash, retention): (Trash, Duration)) -> Self {
+        let articles = trash
+            .articles
+            .into_iter()
+            .filter_map(|article| {
+                let deleted_at = article.deleted_at?;
+                Some(TrashedArticleContent {
+                    slug: article.slug,
+                    title: article.title,
+                    deleted_at: Iso8601(deleted_at),
+                    purge_at: Iso8601(deleted_at + retention),
+                })
+            })
+            .collect();
+        let comments = trash
+            .comments
+            .into_iter()
+            .filter_map(|(comment, article)| {
+                let deleted_at = comment.deleted_at?;
+                Some(TrashedCommentContent {
+                    id: comment.id,
+                    body: comment.body,
+                    article_slug: article.slug,
+                    deleted_at: Iso8601(deleted_at),
+                    purge_at: Iso8601(deleted_at + retention),
+                })
+            })
+            .collect();
+        Self {
+            trash: TrashContent { articles, comments },
+        }
+    }
+}
+
+pub trait TrashPresenter: Send + Sync + 'static {
+    fn to_trash_json(&self, trash: Trash, retention: Duration) -> HttpResponse;
+    fn to_comment_json(&self, item: SingleComment) -> HttpResponse;
+}
+
+#[derive(Clone)]
+pub struct TrashPresenterImpl {}
+
+impl TrashPresenterImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl TrashPresenter for TrashPresenterImpl {
+    fn to_trash_json(&self, trash: Trash, retention: Duration) -> HttpResponse {
+        let res = TrashResponse::from((trash, retention));
+        HttpResponse::Ok().json(res)
+    }
+
+    fn to_comment_json(&self, item: SingleComment) -> HttpResponse {
+        let res = SingleCommentResponse::from(item);
+        HttpResponse::Ok().json(res)
+    }
+}
@@ -0,0 +1,133 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    app::features::{
+        article::entities::Article,
+        comment::{entities::Comment, repositories::SingleComment},
+        reaction::entities::CommentReaction,
+        report::entities::{Report, ReportTarget},
+        user::entities::User,
+    },
+    error::AppError,
+    utils::db::DbPool,
+};
+
+pub trait TrashRepository: Send + Sync + 'static {
+    fn fetch_trash(&self, current_user: &User) -> Result<Trash, AppError>;
+    fn restore_article(
+        &self,
+        current_user: &User,
+        article_title_slug: &str,
+    ) -> Result<Article, AppError>;
+    fn restore_comment(
+        &self,
+        input: RestoreCommentRepositoryInput,
+    ) -> Result<SingleComment, AppError>;
+    fn purge_deleted_before(&self, cutoff: &NaiveDateTime) -> Result<PurgedCount, AppError>;
+}
+
+#[derive(Clone)]
+pub struct TrashRepositoryImpl {
+    pool: DbPool,
+}
+
+impl TrashRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl TrashRepository for TrashRepositoryImpl {
+    fn fetch_trash(&self, current_user: &User) -> Result<Trash, AppError> {
+        let conn = &mut self.pool.get()?;
+        let articles = Article::fetch_deleted_by_owner(conn, &current_user.id)?;
+        let comments = Comment::fetch_deleted_by_author(conn, &current_user.id)?;
+        Ok(Trash { articles, comments })
+    }
+
+    fn restore_article(
+        &self,
+        current_user: &User,
+        article_title_slug: &str,
+    ) -> Result<Article, AppError> {
+        let conn = &mut self.pool.get()?;
+        let article = Article::fetch_deleted_by_slug(conn, article_title_slug, &current_user.id)?;
+        let article = article.ok_or_else(|| {
+            AppError::NotFound(
+                json!({"error": "You have no article with this slug in your trash."}),
+            )
+        })?;
+        if Report::is_removed(conn, ReportTarget::Article, &article.id)? {
+            return Err(AppError::Forbidden(
+                json!({"error": "This article was removed by a moderator."}),
+            ));
+        }
+        if Article::is_slug_taken(conn, &article.slug)? {
+            return Err(AppError::UnprocessableEntity(json!({
+                "error": format!(
+                    "Another article now uses the slug {}. Rename it before restoring this one.",
+                    article.slug
+                )
+            })));
+        }
+        Article::restore(conn, &article.id)
+    }
+
+    fn restore_comment(
+        &self,
+        input: RestoreCommentRepositoryInput,
+    ) -> Result<SingleComment, AppError> {
+        let conn = &mut self.pool.get()?;
+        let article = Article::fetch_by_slug(conn, &input.article_title_slug)?;
+        let comment = Comment::fetch_deleted(conn, &input.comment_id, &input.current_user.id)?;
+        if comment.article_id != article.id {
+            return Err(AppError::NotFound(
+                json!({"error": "The comment doesn't belong to this article."}),
+            ));
+        }
+        if Report::is_removed(conn, ReportTarget::Comment, &comment.id)? {
+            return Err(AppError::Forbidden(
+                json!({"error": "This comment was removed by a moderator."}),
+            ));
+        }
+        let comment = Comment::restore(conn, &comment.id)?;
+        let reply_count = Comment::fetch_reply_counts(conn, &[comment.id])?
+            .get(&comment.id)
+            .copied()
+            .unwrap_or(0);
+        let reaction_info =
+            CommentReaction::fetch_info(conn, &comment.id, Some(&input.current_user.id))?;
+        let profile = input
+            .current_user
+            .fetch_profile(conn, &input.current_user.id)?;
+        Ok((comment, profile, reply_count, reaction_info))
+    }
+
+    fn purge_deleted_before(&self, cutoff: &NaiveDateTime) -> Result<PurgedCount, AppError> {
+        let conn = &mut self.pool.get()?;
+        conn.transaction(|conn| {
+            let comments = Comment::purge_deleted_before(conn, cutoff)?;
+            let articles = Article::purge_deleted_before(conn, cutoff)?;
+            Ok(PurgedCount { articles, comments })
+        })
+    }
+}
+
+pub struct Trash {
+    pub articles: Vec<Article>,
+    pub comments: Vec<(Comment, Article)>,
+}
+
+pub struct PurgedCount {
+    pub articles: usize,
+    pub comments: usize,
+}
+
+pub struct RestoreCommentRepositoryInput {
+    pub current_user: User,
+    pub article_title_slug: String,
+    pub comment_id: Uuid,
+}
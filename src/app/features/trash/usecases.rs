@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use actix_web::HttpResponse;
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::{
+    app::features::{
+        article::{
+            presenters::ArticlePresenter,
+            repositories::{ArticleRepository, FetchArticleRepositoryInput},
+            usecases::RelatedArticlesCache,
+        },
+        user::entities::User,
+    },
+    error::AppError,
+};
+
+use super::{
+    presenters::TrashPresenter,
+    repositories::{PurgedCount, RestoreCommentRepositoryInput, TrashRepository},
+};
+
+#[derive(Clone)]
+pub struct TrashUsecase {
+    trash_repository: Arc<dyn TrashRepository>,
+    trash_presenter: Arc<dyn TrashPresenter>,
+    article_repository: Arc<dyn ArticleRepository>,
+    article_presenter: Arc<dyn ArticlePresenter>,
+    related_articles_cache: RelatedArticlesCache,
+    retention: Duration,
+}
+
+impl TrashUsecase {
+    pub fn new(
+        trash_repository: Arc<dyn TrashRepository>,
+        trash_presenter: Arc<dyn TrashPresenter>,
+        article_repository: Arc<dyn ArticleRepository>,
+        article_presenter: Arc<dyn ArticlePresenter>,
+        related_articles_cache: RelatedArticlesCache,
+        retention: Duration,
+    ) -> Self {
+        Self {
+            trash_repository,
+            trash_presenter,
+            article_repository,
+            article_presenter,
+            related_articles_cache,
+            retention,
+        }
+    }
+
+    pub fn fetch_trash(&self, current_user: &User) -> Result<HttpResponse, AppError> {
+        let trash = self.trash_repository.fetch_trash(current_user)?;
+        let res = self.trash_presenter.to_trash_json(trash, self.retention);
+        Ok(res)
+    }
+
+    pub fn restore_article(
+        &self,
+        current_user: User,
+        article_title_slug: String,
+    ) -> Result<HttpResponse, AppError> {
+        let article = self
+            .trash_repository
+            .restore_article(&current_user, &article_title_slug)?;
+        self.related_articles_cache.clear();
+        let result = self
+            .article_repository
+            .fetch_article(&FetchArticleRepositoryInput {
+                article_id: article.id,
+                current_user,
+            })?;
+        let res = self.article_presenter.to_single_json(result);
+        Ok(res)
+    }
+
+    pub fn restore_comment(
+        &self,
+        current_user: User,
+        article_title_slug: String,
+        comment_id: Uuid,
+    ) -> Result<HttpResponse, AppError> {
+        let result = self
+            .trash_repository
+            .restore_comment(RestoreCommentRepositoryInput {
+                current_user,
+                article_title_slug,
+                comment_id,
+            })?;
+        let res = self.trash_presenter.to_comment_json(result);
+        Ok(res)
+    }
+
+    // Permanently removes everything that has been in the trash longer than the retention period.
+    pub fn purge_expired(&self) -> Result<PurgedCount, AppError> {
+        let cutoff = Utc::now().naive_utc() - self.retention;
+        self.trash_repository.purge_deleted_before(&cutoff)
+    }
+}
@@ -0,0 +1,90 @@
+use actix_multipart::Multipart;
+use actix_web::{web, HttpRequest};
+use futures_util::StreamExt;
+use serde_json::json;
+
+use crate::{
+    app::drivers::middlewares::{auth, state::AppState},
+    constants::UPLOAD_MAX_BYTES,
+    error::AppError,
+    utils::{api::ApiResponse, uuid},
+};
+
+use super::entities::{UploadPurpose, UploadedFile};
+
+type UploadIdSlug = String;
+type VariantSlug = String;
+
+// Expects a `file` field and an optional `purpose` field of `article` or `avatar`.
+pub async fn create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    mut payload: Multipart,
+) -> ApiResponse {
+    let current_user = auth::get_current_user(&req)?;
+    let mut file = None;
+    let mut purpose = None;
+    while let Some(field) = payload.next().await {
+        let mut field = field?;
+        let field_name = field.name().unwrap_or_default().to_owned();
+        let file_name = field
+            .content_disposition()
+            .and_then(|disposition| disposition.get_filename())
+            .map(str::to_owned);
+        let content_type = field
+            .content_type()
+            .map(|mime| mime.essence_str().to_owned());
+        let mut data = vec![];
+        while let Some(chunk) = field.next().await {
+            let chunk = chunk?;
+            if data.len() + chunk.len() > UPLOAD_MAX_BYTES {
+                return Err(AppError::UnprocessableEntity(json!({
+                    "error": format!("The upload is larger than {} bytes.", UPLOAD_MAX_BYTES)
+                })));
+            }
+            data.extend_from_slice(&chunk);
+        }
+        match (field_name.as_str(), file_name) {
+            ("file", Some(name)) => {
+                file = Some(UploadedFile {
+                    name,
+                    content_type,
+                    data,
+                })
+            }
+            ("purpose", None) => purpose = Some(String::from_utf8_lossy(&data).into_owned()),
+            _ => continue,
+        }
+    }
+    let file = file.ok_or_else(|| {
+        AppError::UnprocessableEntity(json!({"error": "Attach the file in a field named file."}))
+    })?;
+    let purpose = UploadPurpose::parse(purpose.as_deref())?;
+    state
+        .di_container
+        .upload_usecase
+        .create_upload(current_user, file, purpose)
+        .await
+}
+
+pub async fn show(state: web::Data<AppState>, path: web::Path<UploadIdSlug>) -> ApiResponse {
+    let upload_id = uuid::parse(&path.into_inner())?;
+    state
+        .di_container
+        .upload_usecase
+        .fetch_file(upload_id, None)
+        .await
+}
+
+pub async fn variant(
+    state: web::Data<AppState>,
+    path: web::Path<(UploadIdSlug, VariantSlug)>,
+) -> ApiResponse {
+    let (upload_id, variant) = path.into_inner();
+    let upload_id = uuid::parse(&upload_id)?;
+    state
+        .di_container
+        .upload_usecase
+        .fetch_file(upload_id, Some(variant))
+        .await
+}
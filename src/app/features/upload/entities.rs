@@ -0,0 +1,345 @@
+use std::io::Cursor;
+
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use image::{
+    error::ImageError,
+    imageops::FilterType,
+    io::{Limits, Reader as ImageReader},
+    DynamicImage, ImageFormat, ImageOutputFormat,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    app::features::user::entities::User,
+    constants::{UPLOAD_MAX_IMAGE_ALLOC_BYTES, UPLOAD_MAX_IMAGE_DIMENSION},
+    error::AppError,
+    schema::uploads,
+    utils::blob_store::BlobStore,
+};
+
+const ORIGINAL: &str = "original";
+
+// Content types accepted for upload, with the file extension used when storing them.
+const IMAGE_TYPES: [(&str, &str, ImageFormat); 4] = [
+    ("image/png", "png", ImageFormat::Png),
+    ("image/jpeg", "jpg", ImageFormat::Jpeg),
+    ("image/gif", "gif", ImageFormat::Gif),
+    ("image/webp", "webp", ImageFormat::WebP),
+];
+const ATTACHMENT_TYPES: [(&str, &str); 3] = [
+    ("application/pdf", "pdf"),
+    ("text/plain", "txt"),
+    ("text/markdown", "md"),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UploadPurpose {
+    Article,
+    Avatar,
+}
+
+impl UploadPurpose {
+    pub fn parse(purpose: Option<&str>) -> Result<Self, AppError> {
+        match purpose.map(str::trim) {
+            None | Some("") | Some("article") => Ok(UploadPurpose::Article),
+            Some("avatar") => Ok(UploadPurpose::Avatar),
+            Some(other) => Err(AppError::UnprocessableEntity(json!({
+                "error": format!("Unknown upload purpose {}, use article or avatar.", other)
+            }))),
+        }
+    }
+
+    fn variants(&self) -> &'static [VariantSpec] {
+        match self {
+            UploadPurpose::Article => &ARTICLE_VARIANTS,
+            UploadPurpose::Avatar => &AVATAR_VARIANTS,
+        }
+    }
+}
+
+pub struct VariantSpec {
+    pub name: &'static str,
+    pub size: u32,
+    // Avatars are cropped to a square, other variants keep their aspect ratio.
+    pub square: bool,
+}
+
+const ARTICLE_VARIANTS: [VariantSpec; 2] = [
+    VariantSpec {
+        name: "thumbnail",
+        size: 320,
+        square: false,
+    },
+    VariantSpec {
+        name: "medium",
+        size: 1024,
+        square: false,
+    },
+];
+const AVATAR_VARIANTS: [VariantSpec; 3] = [
+    VariantSpec {
+        name: "small",
+        size: 64,
+        square: true,
+    },
+    VariantSpec {
+        name: "medium",
+        size: 128,
+        square: true,
+    },
+    VariantSpec {
+        name: "large",
+        size: 256,
+        square: true,
+    },
+];
+
+#[derive(Identifiable, Queryable, Associations, Serialize, Deserialize, Debug, Clone)]
+#[diesel(belongs_to(User, foreign_key = user_id))]
+#[diesel(table_name = uploads)]
+pub struct Upload {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub byte_size: i64,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub variants: serde_json::Value,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl Upload {
+    pub fn create(conn: &mut PgConnection, record: &CreateUpload) -> Result<Self, AppError> {
+        let item = diesel::insert_into(uploads::table)
+            .values(record)
+            .get_result::<Self>(conn)?;
+        Ok(item)
+    }
+
+    pub fn find(conn: &mut PgConnection, id: &Uuid) -> Result<Self, AppError> {
+        let item = uploads::table.find(id).first::<Self>(conn)?;
+        Ok(item)
+    }
+
+    pub fn variant_names(&self) -> Vec<String> {
+        serde_json::from_value(self.variants.clone()).unwrap_or_default()
+    }
+
+    pub fn is_image(&self) -> bool {
+        self.content_type.starts_with("image/")
+    }
+
+    // Resolves the stored blob of the original file or of one of its variants.
+    pub fn stored_file(&self, variant: Option<&str>) -> Option<StoredFile> {
+        match variant {
+            None => Some(StoredFile::original(&self.id, &self.content_type)),
+            Some(name) if self.variant_names().iter().any(|known| known == name) => {
+                Some(StoredFile::variant(&self.id, name, &self.content_type))
+            }
+            Some(_) => None,
+        }
+    }
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = uploads)]
+pub struct CreateUpload {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub byte_size: i64,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub variants: serde_json::Value,
+}
+
+pub struct StoredFile {
+    pub key: String,
+    pub content_type: &'static str,
+}
+
+impl StoredFile {
+    fn original(id: &Uuid, content_type: &str) -> Self {
+        let (content_type, extension) = known_type(content_type);
+        Self {
+            key: format!("uploads/{}/{}.{}", id, ORIGINAL, extension),
+            content_type,
+        }
+    }
+
+    // JPEG variants stay JPEG, every other image is resized to PNG.
+    fn variant(id: &Uuid, name: &str, content_type: &str) -> Self {
+        let (content_type, extension) = match content_type {
+            "image/jpeg" => ("image/jpeg", "jpg"),
+            _ => ("image/png", "png"),
+        };
+        Self {
+            key: format!("uploads/{}/{}.{}", id, name, extension),
+            content_type,
+        }
+    }
+
+    pub fn save(&self, blob_store: &dyn BlobStore, data: &[u8]) -> Result<(), AppError> {
+        blob_store.put(&self.key, data, self.content_type)?;
+        Ok(())
+    }
+}
+
+fn known_type(content_type: &str) -> (&'static str, &'static str) {
+    IMAGE_TYPES
+        .iter()
+        .map(|(known, extension, _)| (*known, *extension))
+        .chain(ATTACHMENT_TYPES.iter().copied())
+        .find(|(known, _)| *known == content_type)
+        .unwrap_or(("application/octet-stream", "bin"))
+}
+
+pub struct UploadedFile {
+    pub name: String,
+    pub content_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+// A validated upload with its resized variants, ready to be stored.
+pub struct ProcessedUpload {
+    pub id: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub data: Vec<u8>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub variants: Vec<(String, Vec<u8>)>,
+}
+
+impl ProcessedUpload {
+    pub fn process(file: UploadedFile, purpose: UploadPurpose) -> Result<Self, AppError> {
+        let content_type = file
+            .content_type
+            .map(|content_type| content_type.to_lowercase())
+            .unwrap_or_default();
+        if file.data.is_empty() {
+            return Err(AppError::UnprocessableEntity(
+                json!({"error": "The uploaded file is empty."}),
+            ));
+        }
+        let image_format = IMAGE_TYPES
+            .iter()
+            .find(|(known, _, _)| *known == content_type)
+            .map(|(_, _, format)| *format);
+        let mut processed = Self {
+            id: Uuid::new_v4(),
+            file_name: file.name,
+            content_type,
+            data: file.data,
+            width: None,
+            height: None,
+            variants: vec![],
+        };
+        match image_format {
+            Some(format) => processed.resize(format, purpose)?,
+            None if purpose == UploadPurpose::Avatar => {
+                return Err(AppError::UnprocessableEntity(
+                    json!({"error": "Avatars must be PNG, JPEG, GIF or WebP images."}),
+                ))
+            }
+            None => processed.check_attachment()?,
+        }
+        Ok(processed)
+    }
+
+    fn resize(&mut self, format: ImageFormat, purpose: UploadPurpose) -> Result<(), AppError> {
+        let mismatch = || {
+            AppError::UnprocessableEntity(json!({
+                "error": format!("The uploaded file is not a valid {} image.", self.content_type)
+            }))
+        };
+        if image::guess_format(&self.data).ok() != Some(format) {
+            return Err(mismatch());
+        }
+        let mut reader = ImageReader::with_format(Cursor::new(&self.data), format);
+        let mut limits = Limits::default();
+        limits.max_image_width = Some(UPLOAD_MAX_IMAGE_DIMENSION);
+        limits.max_image_height = Some(UPLOAD_MAX_IMAGE_DIMENSION);
+        limits.max_alloc = Some(UPLOAD_MAX_IMAGE_ALLOC_BYTES);
+        reader.limits(limits);
+        let image = reader.decode().map_err(|err| match err {
+            ImageError::Limits(_) => AppError::UnprocessableEntity(json!({
+                "error": format!(
+                    "Images can be at most {} pixels wide and high.",
+                    UPLOAD_MAX_IMAGE_DIMENSION
+                )
+            })),
+            _ => mismatch(),
+        })?;
+        self.width = Some(image.width() as i32);
+        self.height = Some(image.height() as i32);
+        let output = match format {
+            ImageFormat::Jpeg => ImageOutputFormat::Jpeg(85),
+            _ => ImageOutputFormat::Png,
+        };
+        for spec in purpose.variants() {
+            let resized = resize(&image, spec);
+            let mut data = Cursor::new(vec![]);
+            resized.write_to(&mut data, output.clone()).map_err(|err| {
+                log::error!("failed to encode {} variant: {}", spec.name, err);
+                AppError::InternalServerError
+            })?;
+            self.variants
+                .push((spec.name.to_owned(), data.into_inner()));
+        }
+        Ok(())
+    }
+
+    fn check_attachment(&self) -> Result<(), AppError> {
+        let valid = match self.content_type.as_str() {
+            "application/pdf" => self.data.starts_with(b"%PDF-"),
+            "text/plain" | "text/markdown" => std::str::from_utf8(&self.data).is_ok(),
+            _ => {
+                let allowed = IMAGE_TYPES
+                    .iter()
+                    .map(|(known, _, _)| *known)
+                    .chain(ATTACHMENT_TYPES.iter().map(|(known, _)| *known))
+                    .collect::<Vec<_>>();
+                return Err(AppError::UnprocessableEntity(json!({
+                    "error": format!(
+                        "Content type {} is not allowed, use one of {}.",
+                        self.content_type,
+                        allowed.join(", ")
+                    )
+                })));
+            }
+        };
+        if !valid {
+            return Err(AppError::UnprocessableEntity(json!({
+                "error": format!("The uploaded file is not a valid {} file.", self.content_type)
+            })));
+        }
+        Ok(())
+    }
+
+    pub fn original(&self) -> StoredFile {
+        StoredFile::original(&self.id, &self.content_type)
+    }
+
+    pub fn variant(&self, name: &str) -> StoredFile {
+        StoredFile::variant(&self.id, name, &self.content_type)
+    }
+}
+
+// Never upscales: images smaller than the variant are only re-encoded.
+fn resize(image: &DynamicImage, spec: &VariantSpec) -> DynamicImage {
+    if spec.square {
+        let side = spec.size.min(image.width()).min(image.height());
+        image.resize_to_fill(side, side, FilterType::Lanczos3)
+    } else if image.width() > spec.size || image.height() > spec.size {
+        image.thumbnail(spec.size, spec.size)
+    } else {
+        image.clone()
+    }
+}
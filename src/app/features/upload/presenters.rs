@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+
+use actix_web::{
+    http::header::{
+        CacheControl, CacheDirective, ContentDisposition, DispositionParam, DispositionType,
+    },
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::utils::date::Iso8601;
+
+use super::entities::{StoredFile, Upload};
+
+// Uploads never change once stored, so clients may cache them forever.
+const MAX_AGE_SECS: u32 = 365 * 24 * 60 * 60;
+
+#[derive(Deserialize, Serialize)]
+pub struct SingleUploadResponse {
+    pub upload: UploadContent,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadContent {
+    pub id: Uuid,
+    pub url: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i32>,
+    pub variants: BTreeMap<String, String>,
+    pub created_at: Iso8601,
+}
+
+impl From<(Upload, &str)> for SingleUploadResponse {
+    fn from((upload, public_url): (Upload, &str)) -> Self {
+        let url = format!(
+            "{}/api/uploads/{}",
+            public_url.trim_end_matches('/'),
+            upload.id
+        );
+        let variants = upload
+            .variant_names()
+            .into_iter()
+            .map(|name| {
+                let variant_url = format!("{}/{}", url, name);
+                (name, variant_url)
+            })
+            .collect();
+        Self {
+            upload: UploadContent {
+                id: upload.id,
+                url,
+                file_name: upload.file_name,
+                content_type: upload.content_type,
+                size: upload.byte_size,
+                width: upload.width,
+                height: upload.height,
+                variants,
+                created_at: Iso8601(upload.created_at),
+            },
+        }
+    }
+}
+
+pub trait UploadPresenter: Send + Sync + 'static {
+    fn to_single_json(&self, upload: Upload, public_url: &str) -> HttpResponse;
+    fn to_file(&self, upload: Upload, file: StoredFile, data: Vec<u8>) -> HttpResponse;
+}
+
+#[derive(Clone)]
+pub struct UploadPresenterImpl {}
+
+impl UploadPresenterImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl UploadPresenter for UploadPresenterImpl {
+    fn to_single_json(&self, upload: Upload, public_url: &str) -> HttpResponse {
+        let res = SingleUploadResponse::from((upload, public_url));
+        HttpResponse::Created().json(res)
+    }
+
+    // Images are shown in place, anything else is downloaded under its original name.
+    fn to_file(&self, upload: Upload, file: StoredFile, data: Vec<u8>) -> HttpResponse {
+        let disposition = ContentDisposition {
+            disposition: if upload.is_image() {
+                DispositionType::Inline
+            } else {
+                DispositionType::Attachment
+            },
+            parameters: vec![DispositionParam::Filename(upload.file_name)],
+        };
+        HttpResponse::Ok()
+            .content_type(file.content_type)
+            .insert_header(disposition)
+            .insert_header(CacheControl(vec![
+                CacheDirective::Public,
+                CacheDirective::MaxAge(MAX_AGE_SECS),
+                CacheDirective::Extension("immutable".to_owned(), None),
+            ]))
+            .body(data)
+    }
+}
@@ -0,0 +1,33 @@
+use uuid::Uuid;
+
+use crate::{error::AppError, utils::db::DbPool};
+
+use super::entities::{CreateUpload, Upload};
+
+pub trait UploadRepository: Send + Sync + 'static {
+    fn create_upload(&self, record: CreateUpload) -> Result<Upload, AppError>;
+    fn fetch_upload(&self, id: &Uuid) -> Result<Upload, AppError>;
+}
+
+#[derive(Clone)]
+pub struct UploadRepositoryImpl {
+    pool: DbPool,
+}
+
+impl UploadRepositoryImpl {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl UploadRepository for UploadRepositoryImpl {
+    fn create_upload(&self, record: CreateUpload) -> Result<Upload, AppError> {
+        let conn = &mut self.pool.get()?;
+        Upload::create(conn, &record)
+    }
+
+    fn fetch_upload(&self, id: &Uuid) -> Result<Upload, AppError> {
+        let conn = &mut self.pool.get()?;
+        Upload::find(conn, id)
+    }
+}
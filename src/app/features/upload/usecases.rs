@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpResponse};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{app::features::user::entities::User, error::AppError, utils::blob_store::BlobStore};
+
+use super::{
+    entities::{CreateUpload, ProcessedUpload, StoredFile, Upload, UploadPurpose, UploadedFile},
+    presenters::UploadPresenter,
+    repositories::UploadRepository,
+};
+
+#[derive(Clone)]
+pub struct UploadUsecase {
+    upload_repository: Arc<dyn UploadRepository>,
+    upload_presenter: Arc<dyn UploadPresenter>,
+    blob_store: Arc<dyn BlobStore>,
+    public_url: String,
+}
+
+impl UploadUsecase {
+    pub fn new(
+        upload_repository: Arc<dyn UploadRepository>,
+        upload_presenter: Arc<dyn UploadPresenter>,
+        blob_store: Arc<dyn BlobStore>,
+        public_url: String,
+    ) -> Self {
+        Self {
+            upload_repository,
+            upload_presenter,
+            blob_store,
+            public_url,
+        }
+    }
+
+    // Resizing images and writing to the blob store block, so they run on the
+    // blocking thread pool rather than on the worker.
+    pub async fn create_upload(
+        &self,
+        current_user: User,
+        file: UploadedFile,
+        purpose: UploadPurpose,
+    ) -> Result<HttpResponse, AppError> {
+        current_user.ensure_not_held()?;
+        let usecase = self.clone();
+        let upload =
+            web::block(move || usecase.store_upload(&current_user, file, purpose)).await??;
+        let res = self
+            .upload_presenter
+            .to_single_json(upload, &self.public_url);
+        Ok(res)
+    }
+
+    fn store_upload(
+        &self,
+        current_user: &User,
+        file: UploadedFile,
+        purpose: UploadPurpose,
+    ) -> Result<Upload, AppError> {
+        let processed = ProcessedUpload::process(file, purpose)?;
+        let mut stored = vec![];
+        let result = self.store_files(&processed, &mut stored).and_then(|_| {
+            self.upload_repository.create_upload(CreateUpload {
+                id: processed.id,
+                user_id: current_user.id,
+                file_name: processed.file_name.clone(),
+                content_type: processed.content_type.clone(),
+                byte_size: processed.data.len() as i64,
+                width: processed.width,
+                height: processed.height,
+                variants: json!(processed
+                    .variants
+                    .iter()
+                    .map(|(name, _)| name)
+                    .collect::<Vec<_>>()),
+            })
+        });
+        let upload = match result {
+            Ok(upload) => upload,
+            Err(err) => {
+                // Don't leave orphaned blobs behind when the upload can't be recorded.
+                for file in stored {
+                    if let Err(err) = self.blob_store.delete(&file.key) {
+                        log::warn!("failed to remove blob {}: {}", file.key, err);
+                    }
+                }
+                return Err(err);
+            }
+        };
+        Ok(upload)
+    }
+
+    fn store_files(
+        &self,
+        processed: &ProcessedUpload,
+        stored: &mut Vec<StoredFile>,
+    ) -> Result<(), AppError> {
+        let original = processed.original();
+        original.save(self.blob_store.as_ref(), &processed.data)?;
+        stored.push(original);
+        for (name, data) in processed.variants.iter() {
+            let variant = processed.variant(name);
+            variant.save(self.blob_store.as_ref(), data)?;
+            stored.push(variant);
+        }
+        Ok(())
+    }
+
+    pub async fn fetch_file(
+        &self,
+        upload_id: Uuid,
+        variant: Option<String>,
+    ) -> Result<HttpResponse, AppError> {
+        let usecase = self.clone();
+        let (upload, file, data) =
+            web::block(move || usecase.load_file(&upload_id, variant.as_deref())).await??;
+        let res = self.upload_presenter.to_file(upload, file, data);
+        Ok(res)
+    }
+
+    fn load_file(
+        &self,
+        upload_id: &Uuid,
+        variant: Option<&str>,
+    ) -> Result<(Upload, StoredFile, Vec<u8>), AppError> {
+        let upload = self.upload_repository.fetch_upload(upload_id)?;
+        let file = upload.stored_file(variant).ok_or_else(|| {
+            AppError::NotFound(json!({"error": "requested variant was not found"}))
+        })?;
+        let data = self.blob_store.get(&file.key)?;
+        Ok((upload, file, data))
+    }
+}
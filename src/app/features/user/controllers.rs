@@ -2,7 +2,7 @@ use actix_web::{web, HttpRequest};
 
 use crate::{
     app::drivers::middlewares::{auth, state::AppState},
-    utils::api::ApiResponse,
+    utils::{api::ApiResponse, etag::Preconditions},
 };
 
 use super::requests;
@@ -24,7 +24,10 @@ pub async fn signup(state: web::Data<AppState>, form: web::Json<requests::Signup
 
 pub async fn me(state: web::Data<AppState>, req: HttpRequest) -> ApiResponse {
     let current_user = auth::get_current_user(&req)?;
-    state.di_container.user_usecase.get_token(&current_user)
+    state
+        .di_container
+        .user_usecase
+        .get_token(&current_user, Preconditions::from_request(&req))
 }
 
 pub async fn update(
@@ -34,7 +37,7 @@ pub async fn update(
 ) -> ApiResponse {
     let current_user = auth::get_current_user(&req)?;
     state.di_container.user_usecase.update_user(
-        current_user.id,
+        &current_user,
         super::entities::UpdateUser {
             email: form.user.email.clone(),
             username: form.user.username.clone(),
@@ -42,5 +45,6 @@ pub async fn update(
             image: form.user.image.clone(),
             bio: form.user.bio.clone(),
         },
+        Preconditions::from_request(&req),
     )
 }
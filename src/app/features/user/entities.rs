@@ -1,8 +1,10 @@
+use crate::app::features::block::entities::Block;
 use crate::app::features::favorite::entities::Favorite;
 use crate::app::features::follow::entities::Follow;
+use crate::app::features::mute::entities::Mute;
 use crate::app::features::profile::entities::Profile;
 use crate::schema::favorites;
-use crate::utils::{hasher, token};
+use crate::utils::{etag, hasher, token};
 use crate::{error::AppError, schema::users};
 use chrono::prelude::*;
 use chrono::NaiveDateTime;
@@ -15,6 +17,8 @@ use diesel::{
     ExpressionMethods, PgConnection, QueryDsl, Selectable, SelectableHelper,
 };
 use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 type Token = String;
@@ -36,6 +40,10 @@ pub struct User {
     pub image: Option<String>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub version: i32,
+    pub is_moderator: bool,
+    // Set while the spam filter holds the account for a moderator.
+    pub held_at: Option<NaiveDateTime>,
 }
 
 impl User {
@@ -85,12 +93,14 @@ impl User {
         email: &'a str,
         username: &'a str,
         naive_password: &'a str,
+        held: bool,
     ) -> Result<(User, Token), AppError> {
         let hashed_passowrd = hasher::hash_password(naive_password)?;
         let record = SignupUser {
             email,
             username,
             password: &hashed_passowrd,
+            held_at: held.then(|| Utc::now().naive_utc()),
         };
         let user = diesel::insert_into(users::table)
             .values(&record)
@@ -106,11 +116,91 @@ impl User {
     ) -> Result<Self, AppError> {
         let target = users::table.find(user_id);
         let user = diesel::update(target)
-            .set(changeset)
+            .set((changeset, users::version.eq(users::version + 1)))
             .get_result::<User>(conn)?;
         Ok(user)
     }
 
+    // Loads the user, holding the row until the transaction ends.
+    pub fn lock_by_id(conn: &mut PgConnection, user_id: &Uuid) -> Result<Self, AppError> {
+        let user = users::table
+            .find(user_id)
+            .for_update()
+            .first::<User>(conn)?;
+        Ok(user)
+    }
+
+    // Locks both users in the order of their ids, so transactions that lock the
+    // same pair can't deadlock each other.
+    pub fn lock_pair(
+        conn: &mut PgConnection,
+        user_id: &Uuid,
+        other_id: &Uuid,
+    ) -> Result<(), AppError> {
+        users::table
+            .filter(users::id.eq_any([user_id, other_id]))
+            .order(users::id)
+            .select(users::id)
+            .for_update()
+            .load::<Uuid>(conn)?;
+        Ok(())
+    }
+
+    // Takes the profile down on behalf of a moderator and holds the account, so
+    // it can't publish again until it is released.
+    pub fn remove(conn: &mut PgConnection, user_id: &Uuid) -> Result<(), AppError> {
+        diesel::update(users::table.find(user_id))
+            .set((
+                users::bio.eq(None::<String>),
+                users::image.eq(None::<String>),
+                users::held_at.eq(Some(Utc::now().naive_utc())),
+                users::version.eq(users::version + 1),
+            ))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    // Lets a held account take part again.
+    pub fn release(conn: &mut PgConnection, user_id: &Uuid) -> Result<(), AppError> {
+        diesel::update(users::table.find(user_id))
+            .set((
+                users::held_at.eq(None::<NaiveDateTime>),
+                users::version.eq(users::version + 1),
+            ))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    // Accounts created since `since` whose e-mail address is at `domain`.
+    pub fn count_signups_by_email_domain(
+        conn: &mut PgConnection,
+        domain: &str,
+        since: NaiveDateTime,
+    ) -> Result<i64, AppError> {
+        // The domain is matched literally, wildcards in it included.
+        let pattern = domain
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+        let count = users::table
+            .filter(users::email.ilike(format!("%@{}", pattern)))
+            .filter(users::created_at.ge(since))
+            .count()
+            .get_result::<i64>(conn)?;
+        Ok(count)
+    }
+
+    pub fn fetch_usernames(
+        conn: &mut PgConnection,
+        user_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, String>, AppError> {
+        let list = users::table
+            .filter(users::id.eq_any(user_ids))
+            .select((users::id, users::username))
+            .load::<(Uuid, String)>(conn)?;
+        Ok(list.into_iter().collect())
+    }
+
     pub fn find_by_username(conn: &mut PgConnection, username: &str) -> Result<Self, AppError> {
         let t = Self::by_username(username).limit(1);
         let user = t.first::<User>(conn)?;
@@ -135,6 +225,24 @@ impl User {
         Ok(token)
     }
 
+    pub fn entity_tag(&self) -> String {
+        etag::from_version(&self.id, self.version)
+    }
+
+    pub fn is_held(&self) -> bool {
+        self.held_at.is_some()
+    }
+
+    // Held accounts can't publish until a moderator releases them.
+    pub fn ensure_not_held(&self) -> Result<(), AppError> {
+        if self.is_held() {
+            return Err(AppError::Forbidden(
+                json!({"error": "Your account is awaiting moderation."}),
+            ));
+        }
+        Ok(())
+    }
+
     pub fn fetch_profile(
         &self,
         conn: &mut PgConnection,
@@ -150,6 +258,17 @@ impl User {
         Ok(profile)
     }
 
+    // Users whose content stays out of this user's feed and comment lists: the
+    // ones they mute, and the ones on either side of a block with them.
+    pub fn fetch_hidden_user_ids(
+        &self,
+        conn: &mut PgConnection,
+    ) -> Result<HashSet<Uuid>, AppError> {
+        let mut ids = Mute::fetch_muted_ids(conn, &self.id)?;
+        ids.extend(Block::fetch_related_ids(conn, &self.id)?);
+        Ok(ids)
+    }
+
     pub fn fetch_favorited_article_ids(
         &self,
         conn: &mut PgConnection,
@@ -182,6 +301,7 @@ pub struct SignupUser<'a> {
     pub email: &'a str,
     pub username: &'a str,
     pub password: &'a str,
+    pub held_at: Option<NaiveDateTime>,
 }
 
 #[derive(AsChangeset, Debug, Deserialize, Clone)]
@@ -1,4 +1,4 @@
-use actix_web::HttpResponse;
+use actix_web::{http::header, HttpResponse};
 use serde::{Deserialize, Serialize};
 
 use super::entities::User;
@@ -17,6 +17,7 @@ impl From<(User, String)> for UserResponse {
                 username: user.username,
                 bio: user.bio,
                 image: user.image,
+                held: user.held_at.is_some(),
             },
         }
     }
@@ -29,10 +30,12 @@ pub struct AuthUser {
     pub username: String,
     pub bio: Option<String>,
     pub image: Option<String>,
+    pub held: bool,
 }
 
 pub trait UserPresenter: Send + Sync + 'static {
     fn to_json(&self, user: User, token: String) -> HttpResponse;
+    fn to_not_modified(&self, entity_tag: String) -> HttpResponse;
 }
 
 #[derive(Clone)]
@@ -45,7 +48,16 @@ impl UserPresenterImpl {
 }
 impl UserPresenter for UserPresenterImpl {
     fn to_json(&self, user: User, token: String) -> HttpResponse {
+        let entity_tag = user.entity_tag();
         let resp_model = UserResponse::from((user, token));
-        HttpResponse::Ok().json(resp_model)
+        HttpResponse::Ok()
+            .insert_header((header::ETAG, entity_tag))
+            .json(resp_model)
+    }
+
+    fn to_not_modified(&self, entity_tag: String) -> HttpResponse {
+        HttpResponse::NotModified()
+            .insert_header((header::ETAG, entity_tag))
+            .finish()
     }
 }
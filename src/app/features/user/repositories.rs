@@ -1,33 +1,57 @@
 use super::entities::{UpdateUser, User};
 use crate::{
     app::features::{
+        block::entities::{Block, CreateBlock},
         follow::entities::{CreateFollow, DeleteFollow, Follow},
+        mute::entities::{CreateMute, Mute},
         profile::entities::Profile,
+        report::entities::{CreateReport, Report, ReportReason, ReportTarget},
     },
     error::AppError,
-    utils::db::DbPool,
+    utils::{db::DbPool, etag::Preconditions},
 };
 use diesel::prelude::*;
+use serde_json::json;
 use uuid::Uuid;
 
 type Token = String;
 
 pub trait UserRepository: Send + Sync + 'static {
     fn signin(&self, email: &str, naive_password: &str) -> Result<(User, Token), AppError>;
+    // With `hold_details` the account is held and a report filed for the moderators.
     fn signup(
         &self,
         email: &str,
         user_name: &str,
         naive_password: &str,
+        hold_details: Option<String>,
     ) -> Result<(User, Token), AppError>;
 
-    fn update(&self, user_id: Uuid, changeset: UpdateUser) -> Result<(User, Token), AppError>;
+    fn update(
+        &self,
+        user_id: Uuid,
+        changeset: UpdateUser,
+        preconditions: &Preconditions,
+    ) -> Result<(User, Token), AppError>;
     fn follow_user(&self, current_user: &User, target_username: &str) -> Result<Profile, AppError>;
     fn unfollow_user(
         &self,
         current_user: &User,
         target_username: &str,
     ) -> Result<Profile, AppError>;
+    // Blocking also ends any follow between the two users.
+    fn set_blocked(
+        &self,
+        current_user: &User,
+        target_username: &str,
+        blocked: bool,
+    ) -> Result<(), AppError>;
+    fn set_muted(
+        &self,
+        current_user: &User,
+        target_username: &str,
+        muted: bool,
+    ) -> Result<(), AppError>;
 }
 
 #[derive(Clone)]
@@ -52,13 +76,39 @@ impl UserRepository for UserRepositoryImpl {
         email: &str,
         username: &str,
         naive_password: &str,
+        hold_details: Option<String>,
     ) -> Result<(User, Token), AppError> {
         let conn = &mut self.pool.get()?;
-        User::signup(conn, email, username, naive_password)
+        conn.transaction(|conn| {
+            let held = hold_details.is_some();
+            let (user, token) = User::signup(conn, email, username, naive_password, held)?;
+            if let Some(details) = hold_details {
+                Report::create(
+                    conn,
+                    &CreateReport {
+                        reporter_id: None,
+                        target_type: ReportTarget::User.as_str(),
+                        target_id: user.id,
+                        reason: ReportReason::Spam.as_str(),
+                        details: Some(details),
+                    },
+                )?;
+            }
+            Ok((user, token))
+        })
     }
-    fn update(&self, user_id: Uuid, changeset: UpdateUser) -> Result<(User, Token), AppError> {
+    fn update(
+        &self,
+        user_id: Uuid,
+        changeset: UpdateUser,
+        preconditions: &Preconditions,
+    ) -> Result<(User, Token), AppError> {
         let conn = &mut self.pool.get()?;
-        let new_user = User::update(conn, user_id, changeset)?;
+        let new_user = conn.transaction(|conn| {
+            let user = User::lock_by_id(conn, &user_id)?;
+            preconditions.check_if_match(&user.entity_tag())?;
+            User::update(conn, user_id, changeset)
+        })?;
         let token = &new_user.generate_token()?;
         Ok((new_user, token.clone()))
     }
@@ -69,17 +119,32 @@ impl UserRepository for UserRepositoryImpl {
             use diesel::prelude::*;
             t.first::<User>(conn)?
         };
-        Follow::create(
-            conn,
-            &CreateFollow {
-                follower_id: current_user.id,
-                followee_id: followee.id,
-            },
-        )?;
+        // Holding both users keeps a block from landing between the check and
+        // the follow.
+        conn.transaction(|conn| {
+            User::lock_pair(conn, &current_user.id, &followee.id)?;
+            if Block::is_blocked_by_any(conn, &[followee.id], &current_user.id)? {
+                return Err(AppError::Forbidden(
+                    json!({"error": "You can't follow a user who blocked you."}),
+                ));
+            }
+            if Block::is_blocked_by_any(conn, &[current_user.id], &followee.id)? {
+                return Err(AppError::Forbidden(
+                    json!({"error": "You can't follow a user you blocked."}),
+                ));
+            }
+            Follow::create(
+                conn,
+                &CreateFollow {
+                    follower_id: current_user.id,
+                    followee_id: followee.id,
+                },
+            )
+        })?;
         Ok(Profile {
-            username: current_user.username.clone(),
-            bio: current_user.bio.clone(),
-            image: current_user.image.clone(),
+            username: followee.username,
+            bio: followee.bio,
+            image: followee.image,
             following: true,
         })
     }
@@ -101,10 +166,78 @@ impl UserRepository for UserRepositoryImpl {
         )?;
 
         Ok(Profile {
-            username: current_user.username.clone(),
-            bio: current_user.bio.clone(),
-            image: current_user.image.clone(),
+            username: followee.username,
+            bio: followee.bio,
+            image: followee.image,
             following: false,
         })
     }
+
+    fn set_blocked(
+        &self,
+        current_user: &User,
+        target_username: &str,
+        blocked: bool,
+    ) -> Result<(), AppError> {
+        let conn = &mut self.pool.get()?;
+        let target = User::find_by_username(conn, target_username)?;
+        if target.id == current_user.id {
+            return Err(AppError::UnprocessableEntity(
+                json!({"error": "You can't block yourself."}),
+            ));
+        }
+        if !blocked {
+            return Block::delete(conn, &current_user.id, &target.id);
+        }
+        conn.transaction(|conn| {
+            User::lock_pair(conn, &current_user.id, &target.id)?;
+            Block::create(
+                conn,
+                &CreateBlock {
+                    blocker_id: current_user.id,
+                    blocked_id: target.id,
+                },
+            )?;
+            Follow::delete(
+                conn,
+                &DeleteFollow {
+                    followee_id: current_user.id,
+                    follower_id: target.id,
+                },
+            )?;
+            Follow::delete(
+                conn,
+                &DeleteFollow {
+                    followee_id: target.id,
+                    follower_id: current_user.id,
+                },
+            )
+        })
+    }
+
+    fn set_muted(
+        &self,
+        current_user: &User,
+        target_username: &str,
+        muted: bool,
+    ) -> Result<(), AppError> {
+        let conn = &mut self.pool.get()?;
+        let target = User::find_by_username(conn, target_username)?;
+        if target.id == current_user.id {
+            return Err(AppError::UnprocessableEntity(
+                json!({"error": "You can't mute yourself."}),
+            ));
+        }
+        if muted {
+            Mute::create(
+                conn,
+                &CreateMute {
+                    muter_id: current_user.id,
+                    muted_id: target.id,
+                },
+            )
+        } else {
+            Mute::delete(conn, &current_user.id, &target.id)
+        }
+    }
 }
@@ -1,9 +1,12 @@
 use std::sync::Arc;
 
 use actix_web::HttpResponse;
-use uuid::Uuid;
 
-use crate::error::AppError;
+use crate::{
+    app::features::spam::{classifiers::SpamClassifier, entities::SpamSubject},
+    error::AppError,
+    utils::etag::Preconditions,
+};
 
 use super::{
     entities::{UpdateUser, User},
@@ -15,15 +18,18 @@ use super::{
 pub struct UserUsecase {
     user_repository: Arc<dyn UserRepository>,
     user_presenter: Arc<dyn UserPresenter>,
+    spam_classifier: Arc<dyn SpamClassifier>,
 }
 impl UserUsecase {
     pub fn new(
         user_repository: Arc<dyn UserRepository>,
         user_presenter: Arc<dyn UserPresenter>,
+        spam_classifier: Arc<dyn SpamClassifier>,
     ) -> Self {
         Self {
             user_repository,
             user_presenter,
+            spam_classifier,
         }
     }
 
@@ -39,12 +45,27 @@ impl UserUsecase {
         username: &str,
         password: &str,
     ) -> Result<HttpResponse, AppError> {
-        let (user, token) = self.user_repository.signup(email, username, password)?;
+        // A flagged account is still created, but held for a moderator.
+        let verdict = self
+            .spam_classifier
+            .classify(&SpamSubject::Signup { email })?;
+        let hold_details = verdict.is_spam().then(|| verdict.details());
+        let (user, token) = self
+            .user_repository
+            .signup(email, username, password, hold_details)?;
         let res = self.user_presenter.to_json(user, token);
         Ok(res)
     }
 
-    pub fn get_token(&self, current_user: &User) -> Result<HttpResponse, AppError> {
+    pub fn get_token(
+        &self,
+        current_user: &User,
+        preconditions: Preconditions,
+    ) -> Result<HttpResponse, AppError> {
+        let entity_tag = current_user.entity_tag();
+        if preconditions.is_not_modified(&entity_tag) {
+            return Ok(self.user_presenter.to_not_modified(entity_tag));
+        }
         let token = current_user.generate_token()?;
         let res = self.user_presenter.to_json(current_user.clone(), token);
         Ok(res)
@@ -52,10 +73,14 @@ impl UserUsecase {
 
     pub fn update_user(
         &self,
-        user_id: Uuid,
+        current_user: &User,
         changeset: UpdateUser,
+        preconditions: Preconditions,
     ) -> Result<HttpResponse, AppError> {
-        let (new_user, token) = self.user_repository.update(user_id, changeset)?;
+        current_user.ensure_not_held()?;
+        let (new_user, token) =
+            self.user_repository
+                .update(current_user.id, changeset, &preconditions)?;
         let res = self.user_presenter.to_json(new_user, token);
         Ok(res)
     }
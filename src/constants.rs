@@ -1,5 +1,57 @@
 pub const BIND: &str = "0.0.0.0:8080";
+pub const WORDS_PER_MINUTE: i32 = 200;
+pub const VIEW_DEDUP_WINDOW_MINUTES: i64 = 30;
+pub const RELATED_ARTICLES_LIMIT: usize = 20;
+pub const BOOKMARKS_PAGE_LIMIT: i64 = 100;
+pub const COMMENTS_PAGE_LIMIT: i64 = 100;
+pub const DEFAULT_COMMENT_MAX_DEPTH: i32 = 5;
+pub const DEFAULT_REACTIONS: &str = "👍,❤️,🎉,😂,🤔";
+pub const MENTIONS_PER_TEXT_LIMIT: usize = 20;
+pub const NOTIFICATIONS_PAGE_LIMIT: i64 = 100;
+pub const FOLLOWS_PAGE_LIMIT: i64 = 100;
+pub const REPORTS_PAGE_LIMIT: i64 = 100;
+pub const REPORT_DETAILS_MAX_CHARS: usize = 1000;
+pub const SPAM_LINK_DENSITY_MAX: f64 = 0.2;
+pub const SPAM_LINKS_MIN: usize = 2;
+pub const SPAM_REPEATED_COMMENTS_LIMIT: i64 = 3;
+pub const SPAM_REPEAT_WINDOW_HOURS: i64 = 24;
+pub const SPAM_NEW_ACCOUNT_HOURS: i64 = 24;
+pub const SPAM_NEW_ACCOUNT_COMMENTS_LIMIT: i64 = 5;
+pub const SPAM_VELOCITY_WINDOW_MINUTES: i64 = 10;
+pub const SPAM_SIGNUPS_PER_DOMAIN_LIMIT: i64 = 20;
+pub const SPAM_SIGNUP_WINDOW_MINUTES: i64 = 60;
+pub const SPAM_BAYES_THRESHOLD: f64 = 0.95;
+pub const SPAM_BAYES_MIN_DOCS: usize = 20;
+pub const SPAM_BAYES_TRAINING_LIMIT: i64 = 2000;
+pub const SPAM_MODEL_TTL_SECS: u64 = 60 * 60;
+pub const DEFAULT_TRASH_RETENTION_DAYS: i64 = 30;
+pub const TRASH_PURGE_INTERVAL_SECS: u64 = 60 * 60;
+pub const IMPORT_MAX_FILES: usize = 100;
+pub const IMPORT_MAX_FILE_BYTES: usize = 1024 * 1024;
+pub const IMPORT_MAX_UPLOAD_BYTES: usize = 20 * 1024 * 1024;
+// Shared by every archive of an import, counting directories and other files too.
+pub const IMPORT_MAX_ARCHIVE_ENTRIES: usize = 4 * IMPORT_MAX_FILES;
+pub const IMPORT_MAX_UNPACKED_BYTES: usize = 20 * 1024 * 1024;
+pub const UPLOAD_MAX_BYTES: usize = 10 * 1024 * 1024;
+// Bounds on decoding, as a small compressed image may declare huge dimensions.
+pub const UPLOAD_MAX_IMAGE_DIMENSION: u32 = 8192;
+pub const UPLOAD_MAX_IMAGE_ALLOC_BYTES: u64 = 256 * 1024 * 1024;
+pub const DEFAULT_UPLOAD_DIR: &str = "./uploads";
 pub mod env_key {
     pub const DATABASE_URL: &str = "DATABASE_URL";
     pub const SECRET_KEY: &str = "SECRET_KEY";
+    pub const TRASH_RETENTION_DAYS: &str = "TRASH_RETENTION_DAYS";
+    pub const COMMENT_MAX_DEPTH: &str = "COMMENT_MAX_DEPTH";
+    pub const COMMENT_EDIT_WINDOW_MINUTES: &str = "COMMENT_EDIT_WINDOW_MINUTES";
+    pub const REACTIONS: &str = "REACTIONS";
+    pub const PUBLIC_URL: &str = "PUBLIC_URL";
+    pub const FRONTEND_ORIGIN: &str = "FRONTEND_ORIGIN";
+    pub const TRUSTED_PROXIES: &str = "TRUSTED_PROXIES";
+    pub const BLOB_STORE: &str = "BLOB_STORE";
+    pub const UPLOAD_DIR: &str = "UPLOAD_DIR";
+    pub const S3_ENDPOINT: &str = "S3_ENDPOINT";
+    pub const S3_BUCKET: &str = "S3_BUCKET";
+    pub const S3_REGION: &str = "S3_REGION";
+    pub const S3_ACCESS_KEY_ID: &str = "S3_ACCESS_KEY_ID";
+    pub const S3_SECRET_ACCESS_KEY: &str = "S3_SECRET_ACCESS_KEY";
 }
@@ -1,3 +1,5 @@
+use actix_multipart::MultipartError;
+use actix_web::error::BlockingError;
 use actix_web::http::StatusCode;
 use actix_web::HttpResponse;
 use bcrypt::BcryptError;
@@ -8,6 +10,8 @@ use serde_json::{json, Value as JsonValue};
 use thiserror::Error;
 use uuid::Error as UuidError;
 
+use crate::utils::blob_store::BlobStoreError;
+
 #[derive(Error, Debug)]
 pub enum AppError {
     // 401
@@ -22,6 +26,10 @@ pub enum AppError {
     #[error("Not Found: {}", _0)]
     NotFound(JsonValue),
 
+    // 412
+    #[error("Precondition Failed: {}", _0)]
+    PreconditionFailed(JsonValue),
+
     // 422
     #[error("Unprocessable Entity: {}", _0)]
     UnprocessableEntity(JsonValue),
@@ -37,6 +45,7 @@ impl actix_web::error::ResponseError for AppError {
             AppError::Unauthorized(msg) => HttpResponse::Unauthorized().json(msg),
             AppError::Forbidden(msg) => HttpResponse::Forbidden().json(msg),
             AppError::NotFound(msg) => HttpResponse::NotFound().json(msg),
+            AppError::PreconditionFailed(msg) => HttpResponse::PreconditionFailed().json(msg),
             AppError::UnprocessableEntity(msg) => HttpResponse::UnprocessableEntity().json(msg),
             AppError::InternalServerError => {
                 HttpResponse::InternalServerError().json("Internal Server Error")
@@ -48,6 +57,7 @@ impl actix_web::error::ResponseError for AppError {
             AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
             AppError::Forbidden(_) => StatusCode::FORBIDDEN,
             AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::PreconditionFailed(_) => StatusCode::PRECONDITION_FAILED,
             AppError::UnprocessableEntity(_) => StatusCode::UNPROCESSABLE_ENTITY,
             AppError::InternalServerError => StatusCode::INTERNAL_SERVER_ERROR,
         }
@@ -106,8 +116,35 @@ impl From<JwtError> for AppError {
     }
 }
 
+impl From<MultipartError> for AppError {
+    fn from(err: MultipartError) -> Self {
+        AppError::UnprocessableEntity(json!({ "error": err.to_string() }))
+    }
+}
+
 impl From<UuidError> for AppError {
     fn from(_err: UuidError) -> Self {
         AppError::NotFound(json!({"error":"Uuid is invalid."}))
     }
 }
+
+impl From<BlockingError> for AppError {
+    fn from(err: BlockingError) -> Self {
+        log::error!("{}", err);
+        AppError::InternalServerError
+    }
+}
+
+impl From<BlobStoreError> for AppError {
+    fn from(err: BlobStoreError) -> Self {
+        match err {
+            BlobStoreError::NotFound(_) => {
+                AppError::NotFound(json!({"error": "requested file was not found"}))
+            }
+            _ => {
+                log::error!("{}", err);
+                AppError::InternalServerError
+            }
+        }
+    }
+}
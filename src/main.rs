@@ -16,6 +16,7 @@ async fn main() -> std::io::Result<()> {
         use app::drivers::middlewares::state::AppState;
         AppState::new(pool)
     };
+    app::drivers::jobs::spawn_trash_purge(state.clone());
     HttpServer::new(move || {
         App::new()
             .app_data(actix_web::web::Data::new(state.clone()))
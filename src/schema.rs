@@ -1,5 +1,58 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    article_authors (article_id, user_id) {
+        article_id -> Uuid,
+        user_id -> Uuid,
+        role -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    article_daily_views (article_id, day) {
+        article_id -> Uuid,
+        day -> Date,
+        views -> Int4,
+        unique_readers -> Int4,
+    }
+}
+
+diesel::table! {
+    article_reactions (article_id, user_id, emoji) {
+        article_id -> Uuid,
+        user_id -> Uuid,
+        emoji -> Text,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    article_translations (id) {
+        id -> Uuid,
+        article_id -> Uuid,
+        locale -> Text,
+        slug -> Text,
+        title -> Text,
+        description -> Text,
+        body -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    article_views (id) {
+        id -> Uuid,
+        article_id -> Uuid,
+        viewer_id -> Nullable<Uuid>,
+        reader_key -> Text,
+        viewed_at -> Timestamp,
+        bucket -> Int8,
+    }
+}
+
 diesel::table! {
     articles (id) {
         id -> Uuid,
@@ -10,6 +63,53 @@ diesel::table! {
         body -> Text,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        word_count -> Int4,
+        reading_minutes -> Int4,
+        table_of_contents -> Jsonb,
+        deleted_at -> Nullable<Timestamp>,
+        meta_title -> Nullable<Text>,
+        meta_description -> Nullable<Text>,
+        canonical_url -> Nullable<Text>,
+        cover_image -> Nullable<Text>,
+        version -> Int4,
+        comments_locked -> Bool,
+    }
+}
+
+diesel::table! {
+    blocks (blocker_id, blocked_id) {
+        blocker_id -> Uuid,
+        blocked_id -> Uuid,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    bookmarks (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        article_id -> Uuid,
+        folder -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    comment_edits (id) {
+        id -> Uuid,
+        comment_id -> Uuid,
+        body -> Text,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    comment_reactions (comment_id, user_id, emoji) {
+        comment_id -> Uuid,
+        user_id -> Uuid,
+        emoji -> Text,
+        created_at -> Timestamp,
     }
 }
 
@@ -21,6 +121,11 @@ diesel::table! {
         body -> Text,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        deleted_at -> Nullable<Timestamp>,
+        parent_id -> Nullable<Uuid>,
+        depth -> Int4,
+        edited_at -> Nullable<Timestamp>,
+        held_at -> Nullable<Timestamp>,
     }
 }
 
@@ -43,6 +148,86 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    mentions (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        article_id -> Uuid,
+        comment_id -> Nullable<Uuid>,
+        created_at -> Timestamp,
+        removed_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    moderation_actions (id) {
+        id -> Uuid,
+        report_id -> Uuid,
+        moderator_id -> Nullable<Uuid>,
+        action -> Text,
+        note -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mutes (muter_id, muted_id) {
+        muter_id -> Uuid,
+        muted_id -> Uuid,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    notifications (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        actor_id -> Uuid,
+        kind -> Text,
+        article_id -> Nullable<Uuid>,
+        comment_id -> Nullable<Uuid>,
+        read_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    reports (id) {
+        id -> Uuid,
+        reporter_id -> Nullable<Uuid>,
+        target_type -> Text,
+        target_id -> Uuid,
+        reason -> Text,
+        details -> Nullable<Text>,
+        status -> Text,
+        moderator_id -> Nullable<Uuid>,
+        resolution -> Nullable<Text>,
+        resolved_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    series (id) {
+        id -> Uuid,
+        author_id -> Uuid,
+        slug -> Text,
+        title -> Text,
+        description -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    series_articles (series_id, article_id) {
+        series_id -> Uuid,
+        article_id -> Uuid,
+        position -> Int4,
+    }
+}
+
 diesel::table! {
     tags (id) {
         id -> Uuid,
@@ -53,6 +238,21 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    uploads (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        file_name -> Text,
+        content_type -> Text,
+        byte_size -> Int8,
+        width -> Nullable<Int4>,
+        height -> Nullable<Int4>,
+        variants -> Jsonb,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     users (id) {
         id -> Uuid,
@@ -63,21 +263,64 @@ diesel::table! {
         image -> Nullable<Text>,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        version -> Int4,
+        is_moderator -> Bool,
+        held_at -> Nullable<Timestamp>,
     }
 }
 
+diesel::joinable!(article_authors -> articles (article_id));
+diesel::joinable!(article_authors -> users (user_id));
+diesel::joinable!(article_daily_views -> articles (article_id));
+diesel::joinable!(article_reactions -> articles (article_id));
+diesel::joinable!(article_reactions -> users (user_id));
+diesel::joinable!(article_translations -> articles (article_id));
+diesel::joinable!(article_views -> articles (article_id));
+diesel::joinable!(article_views -> users (viewer_id));
 diesel::joinable!(articles -> users (author_id));
+diesel::joinable!(bookmarks -> articles (article_id));
+diesel::joinable!(bookmarks -> users (user_id));
+diesel::joinable!(comment_edits -> comments (comment_id));
+diesel::joinable!(comment_reactions -> comments (comment_id));
+diesel::joinable!(comment_reactions -> users (user_id));
 diesel::joinable!(comments -> articles (article_id));
 diesel::joinable!(comments -> users (author_id));
 diesel::joinable!(favorites -> articles (article_id));
 diesel::joinable!(favorites -> users (user_id));
+diesel::joinable!(mentions -> articles (article_id));
+diesel::joinable!(mentions -> comments (comment_id));
+diesel::joinable!(mentions -> users (user_id));
+diesel::joinable!(moderation_actions -> reports (report_id));
+diesel::joinable!(notifications -> articles (article_id));
+diesel::joinable!(notifications -> comments (comment_id));
+diesel::joinable!(series -> users (author_id));
+diesel::joinable!(series_articles -> articles (article_id));
+diesel::joinable!(series_articles -> series (series_id));
 diesel::joinable!(tags -> articles (article_id));
+diesel::joinable!(uploads -> users (user_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    article_authors,
+    article_daily_views,
+    article_reactions,
+    article_translations,
+    article_views,
     articles,
+    blocks,
+    bookmarks,
+    comment_edits,
+    comment_reactions,
     comments,
     favorites,
     follows,
+    mentions,
+    moderation_actions,
+    mutes,
+    notifications,
+    reports,
+    series,
+    series_articles,
     tags,
+    uploads,
     users,
 );
@@ -0,0 +1,152 @@
+use std::{
+    fmt,
+    io::{Cursor, Read, Write},
+};
+
+use zip::{result::ZipError, write::FileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+pub struct ArchiveEntry {
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+pub struct ZipLimits {
+    pub max_entries: usize,
+    pub max_entry_bytes: usize,
+    pub max_total_bytes: usize,
+}
+
+#[derive(Debug)]
+pub enum ReadZipError {
+    Invalid(ZipError),
+    TooManyEntries(usize),
+    TooLarge(usize),
+}
+
+impl fmt::Display for ReadZipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadZipError::Invalid(err) => write!(f, "not a valid ZIP archive: {}", err),
+            ReadZipError::TooManyEntries(max) => {
+                write!(f, "the archive holds more than {} entries.", max)
+            }
+            ReadZipError::TooLarge(max) => {
+                write!(f, "the archive unpacks to more than {} bytes.", max)
+            }
+        }
+    }
+}
+
+impl From<ZipError> for ReadZipError {
+    fn from(err: ZipError) -> Self {
+        ReadZipError::Invalid(err)
+    }
+}
+
+impl From<std::io::Error> for ReadZipError {
+    fn from(err: std::io::Error) -> Self {
+        ReadZipError::Invalid(ZipError::Io(err))
+    }
+}
+
+// Reads every file of a ZIP archive. The number of entries is checked before
+// anything is inflated, each entry is read up to `max_entry_bytes + 1` bytes so
+// callers can reject oversized files, and reading stops once the archive
+// unpacks to more than `max_total_bytes`.
+pub fn read_zip(bytes: &[u8], limits: &ZipLimits) -> Result<Vec<ArchiveEntry>, ReadZipError> {
+    let mut archive = ZipArchive::new(Cursor::new(bytes))?;
+    if archive.len() > limits.max_entries {
+        return Err(ReadZipError::TooManyEntries(limits.max_entries));
+    }
+    let mut entries = vec![];
+    let mut total_bytes = 0;
+    for index in 0..archive.len() {
+        let entry = archive.by_index(index)?;
+        if entry.is_dir() {
+            continue;
+        }
+        let name = entry.name().to_owned();
+        let remaining = limits.max_total_bytes - total_bytes;
+        let mut data = vec![];
+        entry
+            .take(limits.max_entry_bytes.min(remaining) as u64 + 1)
+            .read_to_end(&mut data)?;
+        total_bytes += data.len();
+        if total_bytes > limits.max_total_bytes {
+            return Err(ReadZipError::TooLarge(limits.max_total_bytes));
+        }
+        entries.push(ArchiveEntry { name, data });
+    }
+    Ok(entries)
+}
+
+pub fn write_zip(entries: &[ArchiveEntry]) -> Result<Vec<u8>, ZipError> {
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+    for entry in entries {
+        writer.start_file(entry.name.as_str(), options)?;
+        writer.write_all(&entry.data)?;
+    }
+    Ok(writer.finish()?.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMITS: ZipLimits = ZipLimits {
+        max_entries: 3,
+        max_entry_bytes: 10,
+        max_total_bytes: 25,
+    };
+
+    fn zip_of(sizes: &[usize]) -> Vec<u8> {
+        let entries = sizes
+            .iter()
+            .enumerate()
+            .map(|(index, size)| ArchiveEntry {
+                name: format!("{}.md", index),
+                data: vec![b'a'; *size],
+            })
+            .collect::<Vec<_>>();
+        write_zip(&entries).unwrap()
+    }
+
+    #[test]
+    fn reads_entries_within_the_limits() {
+        let entries = read_zip(&zip_of(&[10, 10, 5]), &LIMITS).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].name, "0.md");
+        assert_eq!(entries[2].data.len(), 5);
+    }
+
+    #[test]
+    fn reads_oversized_entries_one_byte_past_the_limit() {
+        let entries = read_zip(&zip_of(&[1000]), &LIMITS).unwrap();
+        assert_eq!(entries[0].data.len(), LIMITS.max_entry_bytes + 1);
+    }
+
+    #[test]
+    fn rejects_too_many_entries() {
+        assert!(matches!(
+            read_zip(&zip_of(&[1, 1, 1, 1]), &LIMITS),
+            Err(ReadZipError::TooManyEntries(3))
+        ));
+    }
+
+    #[test]
+    fn rejects_archives_that_unpack_too_large() {
+        assert!(matches!(
+            read_zip(&zip_of(&[10, 10, 10]), &LIMITS),
+            Err(ReadZipError::TooLarge(25))
+        ));
+    }
+
+    #[test]
+    fn rejects_data_that_is_not_a_zip() {
+        assert!(matches!(
+            read_zip(b"not a zip", &LIMITS),
+            Err(ReadZipError::Invalid(_))
+        ));
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use super::similarity::word_set;
+
+// A naive Bayes text classifier over the words of each document, told apart by
+// whether they appear in it at all.
+pub struct NaiveBayes {
+    spam_docs: usize,
+    ham_docs: usize,
+    // How many spam and ham documents each word appears in.
+    words: HashMap<String, (usize, usize)>,
+}
+
+impl NaiveBayes {
+    pub fn train<S: AsRef<str>>(spam: &[S], ham: &[S]) -> Self {
+        let mut words = HashMap::<String, (usize, usize)>::new();
+        for doc in spam {
+            for word in word_set(doc.as_ref()) {
+                words.entry(word).or_default().0 += 1;
+            }
+        }
+        for doc in ham {
+            for word in word_set(doc.as_ref()) {
+                words.entry(word).or_default().1 += 1;
+            }
+        }
+        Self {
+            spam_docs: spam.len(),
+            ham_docs: ham.len(),
+            words,
+        }
+    }
+
+    pub fn spam_docs(&self) -> usize {
+        self.spam_docs
+    }
+
+    pub fn ham_docs(&self) -> usize {
+        self.ham_docs
+    }
+
+    // Probability that the text is spam. Words never seen in training don't
+    // count, the others are smoothed so that no single word decides alone.
+    pub fn spam_probability(&self, text: &str) -> f64 {
+        if self.spam_docs == 0 || self.ham_docs == 0 {
+            return 0.0;
+        }
+        let spam_docs = self.spam_docs as f64;
+        let ham_docs = self.ham_docs as f64;
+        let total = spam_docs + ham_docs;
+        let mut spam_log = (spam_docs / total).ln();
+        let mut ham_log = (ham_docs / total).ln();
+        let known = word_set(text)
+            .into_iter()
+            .filter_map(|word| self.words.get(&word))
+            .collect::<Vec<_>>();
+        for (in_spam, in_ham) in known {
+            spam_log += ((*in_spam as f64 + 1.0) / (spam_docs + 2.0)).ln();
+            ham_log += ((*in_ham as f64 + 1.0) / (ham_docs + 2.0)).ln();
+        }
+        // Same as spam / (spam + ham) without leaving log space too early.
+        1.0 / (1.0 + (ham_log - spam_log).exp())
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::PathBuf,
+    sync::Arc,
+};
+
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::constants::{env_key, DEFAULT_UPLOAD_DIR};
+
+#[derive(Error, Debug)]
+pub enum BlobStoreError {
+    #[error("blob {0} was not found")]
+    NotFound(String),
+
+    #[error("blob store I/O failed: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("blob store request failed: {0}")]
+    Http(String),
+}
+
+// Where uploaded files live. Keys are `/` separated paths such as `uploads/<id>/original.png`.
+pub trait BlobStore: Send + Sync + 'static {
+    fn put(&self, key: &str, data: &[u8], content_type: &str) -> Result<(), BlobStoreError>;
+    fn get(&self, key: &str) -> Result<Vec<u8>, BlobStoreError>;
+    fn delete(&self, key: &str) -> Result<(), BlobStoreError>;
+}
+
+#[derive(Clone)]
+pub struct LocalBlobStore {
+    root: PathBuf,
+}
+
+impl LocalBlobStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path(&self, key: &str) -> Result<PathBuf, BlobStoreError> {
+        let mut path = self.root.clone();
+        for segment in key.split('/') {
+            if segment.is_empty() || segment == "." || segment == ".." {
+                return Err(BlobStoreError::NotFound(key.to_owned()));
+            }
+            path.push(segment);
+        }
+        Ok(path)
+    }
+}
+
+impl BlobStore for LocalBlobStore {
+    fn put(&self, key: &str, data: &[u8], _content_type: &str) -> Result<(), BlobStoreError> {
+        let path = self.path(key)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, data)?;
+        Ok(())
+    }
+
+    fn get(&self, key: &str) -> Result<Vec<u8>, BlobStoreError> {
+        match fs::read(self.path(key)?) {
+            Ok(data) => Ok(data),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                Err(BlobStoreError::NotFound(key.to_owned()))
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn delete(&self, key: &str) -> Result<(), BlobStoreError> {
+        match fs::remove_file(self.path(key)?) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct S3Config {
+    // e.g. `https://s3.eu-west-1.amazonaws.com` or `http://localhost:9000` for MinIO.
+    pub endpoint: String,
+    pub bucket: String,
+    pub region: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+// Talks to any S3-compatible service using path-style URLs and Signature Version 4.
+#[derive(Clone)]
+pub struct S3BlobStore {
+    config: S3Config,
+    agent: ureq::Agent,
+}
+
+impl S3BlobStore {
+    pub fn new(config: S3Config) -> Self {
+        Self {
+            config: S3Config {
+                endpoint: config.endpoint.trim_end_matches('/').to_owned(),
+                ..config
+            },
+            agent: ureq::Agent::new(),
+        }
+    }
+
+    fn request(&self, method: &str, key: &str, payload: &[u8]) -> ureq::Request {
+        let path = format!("/{}/{}", self.config.bucket, uri_encode(key));
+        let host = self
+            .config
+            .endpoint
+            .split("://")
+            .last()
+            .unwrap_or_default()
+            .to_owned();
+        let now = Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+        let payload_hash = hex::encode(Sha256::digest(payload));
+
+        let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+        let canonical_request = format!(
+            "{}\n{}\n\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\n{}\n{}",
+            method, path, host, payload_hash, amz_date, signed_headers, payload_hash
+        );
+        let scope = format!("{}/{}/s3/aws4_request", date, self.config.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+        let signing_key = [self.config.region.as_str(), "s3", "aws4_request"]
+            .iter()
+            .fold(
+                hmac_sha256(
+                    format!("AWS4{}", self.config.secret_access_key).as_bytes(),
+                    date.as_bytes(),
+                ),
+                |key, part| hmac_sha256(&key, part.as_bytes()),
+            );
+        let signature = hex::encode(hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+        let authorization = format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.config.access_key_id, scope, signed_headers, signature
+        );
+
+        self.agent
+            .request(method, &format!("{}{}", self.config.endpoint, path))
+            .set("x-amz-content-sha256", &payload_hash)
+            .set("x-amz-date", &amz_date)
+            .set("Authorization", &authorization)
+    }
+}
+
+impl BlobStore for S3BlobStore {
+    fn put(&self, key: &str, data: &[u8], content_type: &str) -> Result<(), BlobStoreError> {
+        self.request("PUT", key, data)
+            .set("Content-Type", content_type)
+            .send_bytes(data)
+            .map_err(|err| BlobStoreError::Http(err.to_string()))?;
+        Ok(())
+    }
+
+    fn get(&self, key: &str) -> Result<Vec<u8>, BlobStoreError> {
+        let response = match self.request("GET", key, &[]).call() {
+            Ok(response) => response,
+            Err(ureq::Error::Status(404, _)) => {
+                return Err(BlobStoreError::NotFound(key.to_owned()))
+            }
+            Err(err) => return Err(BlobStoreError::Http(err.to_string())),
+        };
+        let mut data = vec![];
+        response.into_reader().read_to_end(&mut data)?;
+        Ok(data)
+    }
+
+    fn delete(&self, key: &str) -> Result<(), BlobStoreError> {
+        match self.request("DELETE", key, &[]).call() {
+            Ok(_) | Err(ureq::Error::Status(404, _)) => Ok(()),
+            Err(err) => Err(BlobStoreError::Http(err.to_string())),
+        }
+    }
+}
+
+// Picks the store named by `BLOB_STORE`, falling back to the local filesystem.
+pub fn establish_blob_store() -> Arc<dyn BlobStore> {
+    match env::var(env_key::BLOB_STORE).as_deref() {
+        Ok("s3") => {
+            let var = |key: &str| env::var(key).unwrap_or_else(|_| panic!("{} must be set", key));
+            Arc::new(S3BlobStore::new(S3Config {
+                endpoint: var(env_key::S3_ENDPOINT),
+                bucket: var(env_key::S3_BUCKET),
+                region: var(env_key::S3_REGION),
+                access_key_id: var(env_key::S3_ACCESS_KEY_ID),
+                secret_access_key: var(env_key::S3_SECRET_ACCESS_KEY),
+            }))
+        }
+        Ok("local") | Err(_) => Arc::new(LocalBlobStore::new(
+            env::var(env_key::UPLOAD_DIR).unwrap_or_else(|_| DEFAULT_UPLOAD_DIR.to_owned()),
+        )),
+        Ok(other) => panic!("BLOB_STORE must be local or s3, got {}", other),
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+// Percent-encodes a key the way Signature Version 4 expects, keeping `/` as is.
+fn uri_encode(key: &str) -> String {
+    key.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
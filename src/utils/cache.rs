@@ -0,0 +1,41 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::{Arc, RwLock},
+};
+
+#[derive(Clone)]
+pub struct MemoryCache<K, V> {
+    entries: Arc<RwLock<HashMap<K, V>>>,
+}
+
+impl<K, V> MemoryCache<K, V>
+where
+    K: Eq + Hash,
+    V: Clone,
+{
+    pub fn new() -> Self {
+        Self {
+            entries: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.entries
+            .read()
+            .ok()
+            .and_then(|entries| entries.get(key).cloned())
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        if let Ok(mut entries) = self.entries.write() {
+            entries.insert(key, value);
+        }
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.write() {
+            entries.clear();
+        }
+    }
+}
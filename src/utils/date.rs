@@ -1,3 +1,4 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize, Serializer};
 
 #[derive(Debug, PartialEq, Deserialize)]
@@ -9,3 +10,18 @@ impl Serialize for Iso8601 {
         serializer.serialize_str(&s.to_string())
     }
 }
+
+// Reads an RFC 3339 timestamp, a timestamp without offset taken as UTC, or a
+// plain date taken as midnight UTC.
+pub fn parse_date_time(value: &str) -> Option<NaiveDateTime> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|date| date.naive_utc())
+        .ok()
+        .or_else(|| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S").ok())
+        .or_else(|| NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+}
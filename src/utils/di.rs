@@ -1,29 +1,79 @@
 use std::sync::Arc;
 
 use crate::app::features::{
+    analytics::{
+        presenters::AnalyticsPresenterImpl, repositories::AnalyticsRepositoryImpl,
+        usecases::AnalyticsUsecase,
+    },
     article::{
-        presenters::ArticlePresenterImpl, repositories::ArticleRepositoryImpl,
-        usecases::ArticleUsecase,
+        presenters::ArticlePresenterImpl,
+        repositories::ArticleRepositoryImpl,
+        usecases::{ArticleUsecase, RelatedArticlesCache},
+    },
+    bookmark::{
+        presenters::BookmarkPresenterImpl, repositories::BookmarkRepositoryImpl,
+        usecases::BookmarkUsecase,
+    },
+    coauthor::{
+        presenters::CoauthorPresenterImpl, repositories::CoauthorRepositoryImpl,
+        usecases::CoauthorUsecase,
     },
     comment::{
         presenters::CommentPresenterImpl, repositories::CommentRepositoryImpl,
         usecases::CommentUsecase,
     },
+    export::{
+        presenters::ExportPresenterImpl, repositories::ExportRepositoryImpl,
+        usecases::ExportUsecase,
+    },
     favorite::{
         presentres::FavoritePresenterImpl, repository::FavoriteRepositoryImpl,
         usecases::FavoriteUsecase,
     },
+    import::{presenters::ImportPresenterImpl, usecases::ImportUsecase},
+    notification::{
+        presenters::NotificationPresenterImpl, repositories::NotificationRepositoryImpl,
+        usecases::NotificationUsecase,
+    },
     profile::{
         presenters::ProfilePresenterImpl, repositories::ProfileRepositoryImpl,
         usecases::ProfileUsecase,
     },
+    reaction::{
+        presenters::ReactionPresenterImpl, repositories::ReactionRepositoryImpl,
+        usecases::ReactionUsecase,
+    },
+    report::{
+        presenters::ReportPresenterImpl, repositories::ReportRepositoryImpl,
+        usecases::ReportUsecase,
+    },
+    seo::{presenters::SeoPresenterImpl, usecases::SeoUsecase},
+    series::{
+        presenters::SeriesPresenterImpl, repositories::SeriesRepositoryImpl,
+        usecases::SeriesUsecase,
+    },
+    spam::classifiers::{HeuristicSpamClassifier, SpamModelCache},
     tag::{presenters::TagPresenterImpl, repositories::TagRepositoryImpl, usecases::TagUsecase},
+    translation::{
+        presenters::TranslationPresenterImpl, repositories::TranslationRepositoryImpl,
+        usecases::TranslationUsecase,
+    },
+    trash::{
+        presenters::TrashPresenterImpl, repositories::TrashRepositoryImpl, usecases::TrashUsecase,
+    },
+    upload::{
+        presenters::UploadPresenterImpl, repositories::UploadRepositoryImpl,
+        usecases::UploadUsecase,
+    },
     user::{
         presenters::UserPresenterImpl, repositories::UserRepositoryImpl, usercases::UserUsecase,
     },
 };
 
-use super::db::DbPool;
+use super::{blob_store, cache::MemoryCache, db::DbPool};
+use crate::constants::{
+    env_key, DEFAULT_COMMENT_MAX_DEPTH, DEFAULT_REACTIONS, DEFAULT_TRASH_RETENTION_DAYS,
+};
 
 #[derive(Clone)]
 pub struct DiContainer {
@@ -61,6 +111,45 @@ pub struct DiContainer {
     pub comment_repository: CommentRepositoryImpl,
     pub comment_presenter: CommentPresenterImpl,
     pub comment_usecase: CommentUsecase,
+
+    // Analytics
+    pub analytics_usecase: AnalyticsUsecase,
+
+    // Series
+    pub series_usecase: SeriesUsecase,
+
+    // Coauthor
+    pub coauthor_usecase: CoauthorUsecase,
+
+    // Bookmark
+    pub bookmark_usecase: BookmarkUsecase,
+
+    // Trash
+    pub trash_usecase: TrashUsecase,
+
+    // Import
+    pub import_usecase: ImportUsecase,
+
+    // Export
+    pub export_usecase: ExportUsecase,
+
+    // Upload
+    pub upload_usecase: UploadUsecase,
+
+    // Translation
+    pub translation_usecase: TranslationUsecase,
+
+    // Seo
+    pub seo_usecase: SeoUsecase,
+
+    // Report
+    pub report_usecase: ReportUsecase,
+
+    // Reaction
+    pub reaction_usecase: ReactionUsecase,
+
+    // Notification
+    pub notification_usecase: NotificationUsecase,
 }
 
 impl DiContainer {
@@ -72,6 +161,17 @@ impl DiContainer {
         let article_repository = ArticleRepositoryImpl::new(pool.clone());
         let favorite_repository = FavoriteRepositoryImpl::new(pool.clone());
         let comment_repository = CommentRepositoryImpl::new(pool.clone());
+        let analytics_repository = AnalyticsRepositoryImpl::new(pool.clone());
+        let series_repository = SeriesRepositoryImpl::new(pool.clone());
+        let coauthor_repository = CoauthorRepositoryImpl::new(pool.clone());
+        let bookmark_repository = BookmarkRepositoryImpl::new(pool.clone());
+        let trash_repository = TrashRepositoryImpl::new(pool.clone());
+        let export_repository = ExportRepositoryImpl::new(pool.clone());
+        let upload_repository = UploadRepositoryImpl::new(pool.clone());
+        let translation_repository = TranslationRepositoryImpl::new(pool.clone());
+        let report_repository = ReportRepositoryImpl::new(pool.clone());
+        let reaction_repository = ReactionRepositoryImpl::new(pool.clone());
+        let notification_repository = NotificationRepositoryImpl::new(pool.clone());
 
         // Presenter
         let tag_presenter = TagPresenterImpl::new();
@@ -80,6 +180,57 @@ impl DiContainer {
         let article_presenter = ArticlePresenterImpl::new();
         let favorite_presenter = FavoritePresenterImpl::new();
         let comment_presenter = CommentPresenterImpl::new();
+        let analytics_presenter = AnalyticsPresenterImpl::new();
+        let series_presenter = SeriesPresenterImpl::new();
+        let coauthor_presenter = CoauthorPresenterImpl::new();
+        let bookmark_presenter = BookmarkPresenterImpl::new();
+        let trash_presenter = TrashPresenterImpl::new();
+        let import_presenter = ImportPresenterImpl::new();
+        let export_presenter = ExportPresenterImpl::new();
+        let upload_presenter = UploadPresenterImpl::new();
+        let translation_presenter = TranslationPresenterImpl::new();
+        let seo_presenter = SeoPresenterImpl::new();
+        let report_presenter = ReportPresenterImpl::new();
+        let reaction_presenter = ReactionPresenterImpl::new();
+        let notification_presenter = NotificationPresenterImpl::new();
+
+        // Cache
+        let related_articles_cache: RelatedArticlesCache = MemoryCache::new();
+        let spam_model_cache: SpamModelCache = MemoryCache::new();
+
+        // Classifier
+        let spam_classifier = HeuristicSpamClassifier::new(pool.clone(), spam_model_cache);
+
+        // Config
+        let trash_retention_days = std::env::var(env_key::TRASH_RETENTION_DAYS)
+            .ok()
+            .and_then(|days| days.parse::<i64>().ok())
+            .unwrap_or(DEFAULT_TRASH_RETENTION_DAYS);
+        let comment_max_depth = std::env::var(env_key::COMMENT_MAX_DEPTH)
+            .ok()
+            .and_then(|depth| depth.parse::<i32>().ok())
+            .unwrap_or(DEFAULT_COMMENT_MAX_DEPTH);
+        let comment_edit_window = std::env::var(env_key::COMMENT_EDIT_WINDOW_MINUTES)
+            .ok()
+            .and_then(|minutes| minutes.parse::<i64>().ok())
+            .filter(|minutes| *minutes > 0)
+            .map(chrono::Duration::minutes);
+        let reactions = std::env::var(env_key::REACTIONS)
+            .ok()
+            .filter(|reactions| !reactions.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_REACTIONS.to_owned())
+            .split(',')
+            .map(|emoji| emoji.trim().to_owned())
+            .filter(|emoji| !emoji.is_empty())
+            .collect::<Vec<_>>();
+        let public_url = std::env::var(env_key::PUBLIC_URL).unwrap_or_default();
+        let frontend_origin = std::env::var(env_key::FRONTEND_ORIGIN).unwrap_or_default();
+        let trusted_proxies = std::env::var(env_key::TRUSTED_PROXIES)
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|proxy| proxy.trim().parse::<std::net::IpAddr>().ok())
+            .collect::<Vec<_>>();
+        let blob_store = blob_store::establish_blob_store();
 
         // Usecase
         let tag_usecase = TagUsecase::new(
@@ -89,6 +240,7 @@ impl DiContainer {
         let user_usecase = UserUsecase::new(
             Arc::new(user_repository.clone()),
             Arc::new(user_presenter.clone()),
+            Arc::new(spam_classifier.clone()),
         );
         let profile_usecase = ProfileUsecase::new(
             Arc::new(profile_repository.clone()),
@@ -98,16 +250,89 @@ impl DiContainer {
         let article_usecase = ArticleUsecase::new(
             Arc::new(article_repository.clone()),
             Arc::new(article_presenter.clone()),
+            Arc::new(analytics_repository.clone()),
+            Arc::new(series_repository.clone()),
+            Arc::new(translation_repository.clone()),
+            related_articles_cache.clone(),
+            trusted_proxies,
         );
 
         let favorite_usecase = FavoriteUsecase::new(
             Arc::new(favorite_repository.clone()),
             Arc::new(favorite_presenter.clone()),
             Arc::new(article_repository.clone()),
+            related_articles_cache.clone(),
         );
         let comment_usecase = CommentUsecase::new(
             Arc::new(comment_repository.clone()),
             Arc::new(comment_presenter.clone()),
+            Arc::new(spam_classifier.clone()),
+            comment_max_depth,
+            comment_edit_window,
+        );
+        let analytics_usecase = AnalyticsUsecase::new(
+            Arc::new(analytics_repository.clone()),
+            Arc::new(analytics_presenter.clone()),
+        );
+        let series_usecase = SeriesUsecase::new(
+            Arc::new(series_repository.clone()),
+            Arc::new(series_presenter.clone()),
+        );
+        let coauthor_usecase = CoauthorUsecase::new(
+            Arc::new(coauthor_repository.clone()),
+            Arc::new(coauthor_presenter.clone()),
+        );
+        let bookmark_usecase = BookmarkUsecase::new(
+            Arc::new(bookmark_repository.clone()),
+            Arc::new(bookmark_presenter.clone()),
+            Arc::new(article_repository.clone()),
+        );
+        let trash_usecase = TrashUsecase::new(
+            Arc::new(trash_repository.clone()),
+            Arc::new(trash_presenter.clone()),
+            Arc::new(article_repository.clone()),
+            Arc::new(article_presenter.clone()),
+            related_articles_cache.clone(),
+            chrono::Duration::days(trash_retention_days),
+        );
+        let import_usecase = ImportUsecase::new(
+            Arc::new(article_repository.clone()),
+            Arc::new(import_presenter.clone()),
+            related_articles_cache,
+        );
+        let export_usecase = ExportUsecase::new(
+            Arc::new(export_repository.clone()),
+            Arc::new(export_presenter.clone()),
+            frontend_origin.clone(),
+        );
+        let upload_usecase = UploadUsecase::new(
+            Arc::new(upload_repository.clone()),
+            Arc::new(upload_presenter.clone()),
+            blob_store,
+            public_url.clone(),
+        );
+        let translation_usecase = TranslationUsecase::new(
+            Arc::new(translation_repository.clone()),
+            Arc::new(translation_presenter.clone()),
+        );
+        let seo_usecase = SeoUsecase::new(
+            Arc::new(article_repository.clone()),
+            Arc::new(seo_presenter.clone()),
+            frontend_origin,
+            public_url,
+        );
+        let report_usecase = ReportUsecase::new(
+            Arc::new(report_repository.clone()),
+            Arc::new(report_presenter.clone()),
+        );
+        let reaction_usecase = ReactionUsecase::new(
+            Arc::new(reaction_repository.clone()),
+            Arc::new(reaction_presenter.clone()),
+            reactions,
+        );
+        let notification_usecase = NotificationUsecase::new(
+            Arc::new(notification_repository.clone()),
+            Arc::new(notification_presenter.clone()),
         );
 
         Self {
@@ -140,6 +365,45 @@ impl DiContainer {
             comment_repository,
             comment_presenter,
             comment_usecase,
+
+            // Analytics
+            analytics_usecase,
+
+            // Series
+            series_usecase,
+
+            // Coauthor
+            coauthor_usecase,
+
+            // Bookmark
+            bookmark_usecase,
+
+            // Trash
+            trash_usecase,
+
+            // Import
+            import_usecase,
+
+            // Export
+            export_usecase,
+
+            // Upload
+            upload_usecase,
+
+            // Translation
+            translation_usecase,
+
+            // Seo
+            seo_usecase,
+
+            // Report
+            report_usecase,
+
+            // Reaction
+            reaction_usecase,
+
+            // Notification
+            notification_usecase,
         }
     }
 }
@@ -0,0 +1,197 @@
+use actix_web::{
+    http::header::{self, HeaderName},
+    HttpRequest, HttpResponse,
+};
+use serde::Serialize;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::error::AppError;
+
+// Strong entity tag of a row whose `version` goes up on every update.
+pub fn from_version(id: &Uuid, version: i32) -> String {
+    format!("\"{}-{}\"", id.simple(), version)
+}
+
+// Strong entity tag of one representation of a versioned row: the version tag
+// with a digest of the response body, which also changes with whatever the
+// version doesn't track, e.g. counts, the flags of the viewer or the locale.
+pub fn for_representation(version_tag: &str, body: &[u8]) -> String {
+    let digest = hex::encode(Sha256::digest(body));
+    format!("{}.{}\"", version_tag.trim_end_matches('"'), &digest[..16])
+}
+
+// A JSON response tagged by its representation, answered with 304 Not Modified
+// when `If-None-Match` already lists that tag.
+pub fn respond_json<T: Serialize>(
+    version_tag: &str,
+    value: &T,
+    preconditions: &Preconditions,
+    vary: &str,
+) -> HttpResponse {
+    let body = match serde_json::to_vec(value) {
+        Ok(body) => body,
+        Err(err) => {
+            log::error!("failed to serialize response: {}", err);
+            return HttpResponse::InternalServerError().finish();
+        }
+    };
+    let entity_tag = for_representation(version_tag, &body);
+    if preconditions.is_not_modified(&entity_tag) {
+        return HttpResponse::NotModified()
+            .insert_header((header::ETAG, entity_tag))
+            .insert_header((header::VARY, vary))
+            .finish();
+    }
+    HttpResponse::Ok()
+        .insert_header((header::ETAG, entity_tag))
+        .insert_header((header::VARY, vary))
+        .insert_header(header::ContentType::json())
+        .body(body)
+}
+
+// The conditional headers of a request, kept as sent.
+#[derive(Default, Clone)]
+pub struct Preconditions {
+    pub if_match: Option<String>,
+    pub if_none_match: Option<String>,
+}
+
+impl Preconditions {
+    pub fn from_request(req: &HttpRequest) -> Self {
+        let value = |name: HeaderName| {
+            req.headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_owned)
+        };
+        Self {
+            if_match: value(header::IF_MATCH),
+            if_none_match: value(header::IF_NONE_MATCH),
+        }
+    }
+
+    // `If-Match` lets a write through when absent, `*` or listing the current
+    // tag. Weak tags never match, as the comparison is the strong one. Only the
+    // version counts, so the tag of any representation of the row will do.
+    pub fn check_if_match(&self, current: &str) -> Result<(), AppError> {
+        match &self.if_match {
+            Some(value) if !lists(value, current, false) => {
+                Err(AppError::PreconditionFailed(json!({
+                    "error": "The resource was changed since it was read, fetch it again."
+                })))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // Whether `If-None-Match` already lists the current tag, so a read can be
+    // answered with 304 Not Modified.
+    pub fn is_not_modified(&self, current: &str) -> bool {
+        self.if_none_match
+            .as_deref()
+            .is_some_and(|value| lists(value, current, true))
+    }
+}
+
+fn lists(value: &str, current: &str, weak: bool) -> bool {
+    value.split(',').map(str::trim).any(|tag| {
+        if tag == "*" {
+            return true;
+        }
+        match tag.strip_prefix("W/") {
+            Some(tag) => weak && tag == current,
+            None => tag == current || (!weak && version_tag(tag) == current),
+        }
+    })
+}
+
+// The version tag a representation tag was made from.
+fn version_tag(tag: &str) -> String {
+    match tag.trim_end_matches('"').split_once('.') {
+        Some((version, _)) => format!("{}\"", version),
+        None => tag.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn if_match(value: &str) -> Preconditions {
+        Preconditions {
+            if_match: Some(value.to_owned()),
+            if_none_match: None,
+        }
+    }
+
+    fn if_none_match(value: &str) -> Preconditions {
+        Preconditions {
+            if_match: None,
+            if_none_match: Some(value.to_owned()),
+        }
+    }
+
+    #[test]
+    fn representation_tag_keeps_the_version_tag() {
+        let version = from_version(&Uuid::nil(), 3);
+        let tag = for_representation(&version, b"{}");
+        assert!(tag.starts_with(&version[..version.len() - 1]));
+        assert!(tag.ends_with('"'));
+        assert_eq!(version_tag(&tag), version);
+        assert_ne!(tag, for_representation(&version, b"[]"));
+    }
+
+    #[test]
+    fn if_match_passes_when_absent_any_or_listed() {
+        let current = from_version(&Uuid::nil(), 1);
+        assert!(Preconditions::default().check_if_match(&current).is_ok());
+        assert!(if_match("*").check_if_match(&current).is_ok());
+        assert!(if_match(&current).check_if_match(&current).is_ok());
+        let list = format!("\"other\", {}", current);
+        assert!(if_match(&list).check_if_match(&current).is_ok());
+    }
+
+    #[test]
+    fn if_match_accepts_any_representation_of_the_version() {
+        let current = from_version(&Uuid::nil(), 1);
+        let tag = for_representation(&current, b"{}");
+        assert!(if_match(&tag).check_if_match(&current).is_ok());
+    }
+
+    #[test]
+    fn if_match_fails_on_stale_or_weak_tags() {
+        let current = from_version(&Uuid::nil(), 2);
+        let stale = from_version(&Uuid::nil(), 1);
+        assert!(matches!(
+            if_match(&stale).check_if_match(&current),
+            Err(AppError::PreconditionFailed(_))
+        ));
+        let weak = format!("W/{}", current);
+        assert!(matches!(
+            if_match(&weak).check_if_match(&current),
+            Err(AppError::PreconditionFailed(_))
+        ));
+    }
+
+    #[test]
+    fn if_none_match_uses_the_weak_comparison() {
+        let current = for_representation(&from_version(&Uuid::nil(), 1), b"{}");
+        assert!(!Preconditions::default().is_not_modified(&current));
+        assert!(if_none_match("*").is_not_modified(&current));
+        assert!(if_none_match(&current).is_not_modified(&current));
+        assert!(if_none_match(&format!("W/{}", current)).is_not_modified(&current));
+        let list = format!("\"other\",{}", current);
+        assert!(if_none_match(&list).is_not_modified(&current));
+    }
+
+    #[test]
+    fn if_none_match_needs_the_same_representation() {
+        let version = from_version(&Uuid::nil(), 1);
+        let current = for_representation(&version, b"{}");
+        let other = for_representation(&version, b"[]");
+        assert!(!if_none_match(&version).is_not_modified(&current));
+        assert!(!if_none_match(&other).is_not_modified(&current));
+    }
+}
@@ -0,0 +1,87 @@
+use serde::Serialize;
+
+const DELIMITER: &str = "---";
+
+// Splits a Markdown document into its YAML front matter and the body that follows it.
+// Documents without a leading `---` line have no front matter.
+pub fn split(document: &str) -> (Option<&str>, &str) {
+    let document = document.trim_start_matches('\u{feff}');
+    let rest = match document.strip_prefix(DELIMITER) {
+        Some(rest) if rest.starts_with('\n') || rest.starts_with("\r\n") => rest,
+        _ => return (None, document),
+    };
+    let rest = rest.trim_start_matches('\r').trim_start_matches('\n');
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        if trimmed == DELIMITER || trimmed == "..." {
+            let yaml = &rest[..offset];
+            let body = rest[offset + line.len()..].trim_start_matches(['\r', '\n']);
+            return (Some(yaml), body);
+        }
+        offset += line.len();
+    }
+    (None, document)
+}
+
+// Writes a Markdown document with the given metadata as YAML front matter.
+pub fn render<T: Serialize>(front_matter: &T, body: &str) -> Result<String, serde_yaml::Error> {
+    let yaml = serde_yaml::to_string(front_matter)?;
+    Ok(format!(
+        "{}\n{}{}\n\n{}\n",
+        DELIMITER,
+        yaml,
+        DELIMITER,
+        body.trim_end()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_front_matter_from_the_body() {
+        let (yaml, body) = split("---\ntitle: Hello\n---\n\n# Hello\n");
+        assert_eq!(yaml, Some("title: Hello\n"));
+        assert_eq!(body, "# Hello\n");
+    }
+
+    #[test]
+    fn splits_crlf_documents() {
+        let (yaml, body) = split("---\r\ntitle: Hello\r\ntags: [a]\r\n---\r\n\r\nBody\r\n");
+        assert_eq!(yaml, Some("title: Hello\r\ntags: [a]\r\n"));
+        assert_eq!(body, "Body\r\n");
+    }
+
+    #[test]
+    fn accepts_a_byte_order_mark_and_the_yaml_end_marker() {
+        let (yaml, body) = split("\u{feff}---\ntitle: Hello\n...\nBody");
+        assert_eq!(yaml, Some("title: Hello\n"));
+        assert_eq!(body, "Body");
+    }
+
+    #[test]
+    fn documents_without_front_matter_are_all_body() {
+        assert_eq!(split("# Hello\n---\n"), (None, "# Hello\n---\n"));
+        assert_eq!(split("---title\n---\n"), (None, "---title\n---\n"));
+    }
+
+    #[test]
+    fn unterminated_front_matter_is_body() {
+        let document = "---\ntitle: Hello\n# Hello\n";
+        assert_eq!(split(document), (None, document));
+    }
+
+    #[test]
+    fn render_round_trips_through_split() {
+        #[derive(Serialize)]
+        struct Meta {
+            title: &'static str,
+        }
+        let document = render(&Meta { title: "Hello" }, "Body\n\n").unwrap();
+        let (yaml, body) = split(&document);
+        assert_eq!(yaml, Some("title: Hello\n"));
+        assert_eq!(body, "Body\n");
+    }
+}
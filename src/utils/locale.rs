@@ -0,0 +1,145 @@
+// Normalizes a BCP 47 style language tag such as `pt_br` to `pt-BR`.
+pub fn normalize(tag: &str) -> Option<String> {
+    let mut parts = tag.trim().split(['-', '_']);
+    let language = parts.next()?;
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let mut normalized = language.to_ascii_lowercase();
+    for part in parts {
+        if !(2..=8).contains(&part.len()) || !part.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return None;
+        }
+        normalized.push('-');
+        if part.len() == 2 {
+            normalized.push_str(&part.to_ascii_uppercase());
+        } else {
+            normalized.push_str(&part.to_ascii_lowercase());
+        }
+    }
+    Some(normalized)
+}
+
+// Languages of an `Accept-Language` header, most preferred first. `*` and
+// entries with `q=0` are dropped.
+pub fn parse_accept_language(header: &str) -> Vec<String> {
+    let mut weighted = header
+        .split(',')
+        .enumerate()
+        .filter_map(|(index, entry)| {
+            let mut params = entry.split(';');
+            let tag = normalize(params.next()?)?;
+            let quality = params
+                .filter_map(|param| param.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            (quality > 0.0).then_some((tag, quality, index))
+        })
+        .collect::<Vec<_>>();
+    weighted.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.2.cmp(&b.2)));
+    weighted.into_iter().map(|(tag, _, _)| tag).collect()
+}
+
+// Picks the first preferred locale that is available, matching `pt` with `pt-BR`
+// and the other way around when there is no exact match.
+pub fn best_match<'a>(preferred: &[String], available: &'a [String]) -> Option<&'a String> {
+    let language = |tag: &str| tag.split('-').next().unwrap_or_default().to_owned();
+    preferred.iter().find_map(|wanted| {
+        available
+            .iter()
+            .find(|locale| *locale == wanted)
+            .or_else(|| {
+                available
+                    .iter()
+                    .find(|locale| language(locale) == language(wanted))
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(list: &[&str]) -> Vec<String> {
+        list.iter().map(|tag| tag.to_string()).collect()
+    }
+
+    #[test]
+    fn normalize_fixes_case_and_separators() {
+        assert_eq!(normalize("pt_br").as_deref(), Some("pt-BR"));
+        assert_eq!(normalize(" EN-us ").as_deref(), Some("en-US"));
+        assert_eq!(normalize("zh-Hant-TW").as_deref(), Some("zh-hant-TW"));
+        assert_eq!(normalize("de").as_deref(), Some("de"));
+    }
+
+    #[test]
+    fn normalize_rejects_malformed_tags() {
+        assert_eq!(normalize(""), None);
+        assert_eq!(normalize("*"), None);
+        assert_eq!(normalize("e"), None);
+        assert_eq!(normalize("english"), None);
+        assert_eq!(normalize("en-"), None);
+        assert_eq!(normalize("en-a"), None);
+        assert_eq!(normalize("../etc"), None);
+    }
+
+    #[test]
+    fn accept_language_is_ordered_by_quality() {
+        assert_eq!(
+            parse_accept_language("fr;q=0.5, de;q=0.9, en-gb"),
+            tags(&["en-GB", "de", "fr"])
+        );
+    }
+
+    #[test]
+    fn accept_language_keeps_header_order_on_equal_quality() {
+        assert_eq!(
+            parse_accept_language("es;q=0.8, it;q=0.8, nl"),
+            tags(&["nl", "es", "it"])
+        );
+    }
+
+    #[test]
+    fn accept_language_drops_wildcards_and_refused_languages() {
+        assert_eq!(
+            parse_accept_language("*, ja;q=0, ko; q=0.3, ??"),
+            tags(&["ko"])
+        );
+        assert!(parse_accept_language("").is_empty());
+    }
+
+    #[test]
+    fn best_match_prefers_exact_locales() {
+        let available = tags(&["pt", "pt-BR", "en"]);
+        let preferred = tags(&["pt-BR"]);
+        assert_eq!(
+            best_match(&preferred, &available).map(String::as_str),
+            Some("pt-BR")
+        );
+    }
+
+    #[test]
+    fn best_match_falls_back_to_the_language() {
+        let available = tags(&["pt-BR", "en-US"]);
+        assert_eq!(
+            best_match(&tags(&["pt"]), &available).map(String::as_str),
+            Some("pt-BR")
+        );
+        let available = tags(&["en"]);
+        assert_eq!(
+            best_match(&tags(&["en-GB"]), &available).map(String::as_str),
+            Some("en")
+        );
+    }
+
+    #[test]
+    fn best_match_follows_the_preference_order() {
+        let available = tags(&["de", "fr"]);
+        let preferred = tags(&["ja", "fr", "de"]);
+        assert_eq!(
+            best_match(&preferred, &available).map(String::as_str),
+            Some("fr")
+        );
+        assert_eq!(best_match(&tags(&["ja"]), &available), None);
+    }
+}
@@ -0,0 +1,60 @@
+use pulldown_cmark::Options;
+
+pub struct Heading {
+    pub level: u8,
+    pub text: String,
+}
+
+pub fn count_words(text: &str) -> usize {
+    text.split_whitespace()
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+// ATX headings ("# Title" .. "###### Title") outside of fenced code blocks.
+pub fn headings(text: &str) -> Vec<Heading> {
+    let mut in_fence = false;
+    text.lines()
+        .filter_map(|line| {
+            let line = line.trim_start();
+            if line.starts_with("```") || line.starts_with("~~~") {
+                in_fence = !in_fence;
+                return None;
+            }
+            if in_fence {
+                return None;
+            }
+            let level = line.chars().take_while(|c| *c == '#').count();
+            if level == 0 || level > 6 {
+                return None;
+            }
+            let rest = &line[level..];
+            if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+                return None;
+            }
+            let text = rest.trim().trim_end_matches('#').trim_end().to_string();
+            if text.is_empty() {
+                return None;
+            }
+            Some(Heading {
+                level: level as u8,
+                text,
+            })
+        })
+        .collect()
+}
+
+pub fn options() -> Options {
+    Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_TASKLISTS
+}
+
+// Escapes text for use inside HTML elements and quoted attributes.
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::new();
+    // Writing into a String can't fail.
+    let _ = pulldown_cmark::escape::escape_html(&mut escaped, text);
+    escaped
+}
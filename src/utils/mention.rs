@@ -0,0 +1,214 @@
+use pulldown_cmark::{CowStr, Event, Parser, Tag};
+use std::{collections::HashSet, ops::Range};
+
+use super::markdown::{self, escape_html};
+use crate::constants::MENTIONS_PER_TEXT_LIMIT;
+
+// `@username` mentions in markdown text, in order of first appearance and without
+// duplicates. Code and the text of links are skipped, as are e-mail addresses.
+pub fn usernames(text: &str) -> Vec<String> {
+    let mut found = vec![];
+    map_text(text, |run| {
+        for (_, name) in scan(&run) {
+            if !found.iter().any(|known| known == name) {
+                found.push(name.to_owned());
+            }
+        }
+        vec![Event::Text(CowStr::from(run))]
+    });
+    found.truncate(MENTIONS_PER_TEXT_LIMIT);
+    found
+}
+
+// Renders the markdown to HTML with each mention of a user in `known` linked to
+// the URL `profile_url` gives for the username.
+pub fn to_html(
+    text: &str,
+    known: &HashSet<String>,
+    profile_url: impl Fn(&str) -> String,
+) -> String {
+    let events = map_text(text, |run| {
+        let mut events = vec![];
+        let mut start = 0;
+        for (range, name) in scan(&run) {
+            if !known.contains(name) {
+                continue;
+            }
+            if start < range.start {
+                events.push(Event::Text(CowStr::from(
+                    run[start..range.start].to_owned(),
+                )));
+            }
+            events.push(Event::Html(CowStr::from(format!(
+                "<a class=\"mention\" href=\"{}\">@{}</a>",
+                escape_html(&profile_url(name)),
+                escape_html(name)
+            ))));
+            start = range.end;
+        }
+        if start < run.len() {
+            events.push(Event::Text(CowStr::from(run[start..].to_owned())));
+        }
+        events
+    });
+    let mut html = String::new();
+    pulldown_cmark::html::push_html(&mut html, events.into_iter());
+    html
+}
+
+// Parses the markdown, handing each run of plain text that can hold a mention to
+// `on_text` and putting the events it returns in its place. The parser may split
+// text in several events, so consecutive ones are joined first. Raw HTML of the
+// text is kept as plain text and scriptable link targets are dropped, so the
+// rendered HTML only carries the markup the markdown itself stands for.
+fn map_text<'a>(
+    text: &'a str,
+    mut on_text: impl FnMut(String) -> Vec<Event<'a>>,
+) -> Vec<Event<'a>> {
+    let mut events = vec![];
+    let mut run = String::new();
+    let mut in_code = 0;
+    let mut in_link = 0;
+    for event in Parser::new_ext(text, markdown::options()) {
+        let event = match event {
+            Event::Html(html) => Event::Text(html),
+            Event::Start(Tag::Link(kind, url, title)) if is_scriptable(&url) => {
+                Event::Start(Tag::Link(kind, CowStr::from(""), title))
+            }
+            Event::Start(Tag::Image(kind, url, title)) if is_scriptable(&url) => {
+                Event::Start(Tag::Image(kind, CowStr::from(""), title))
+            }
+            event => event,
+        };
+        if let Event::Text(part) = &event {
+            if in_code == 0 && in_link == 0 {
+                run.push_str(part);
+                continue;
+            }
+        }
+        if !run.is_empty() {
+            events.extend(on_text(std::mem::take(&mut run)));
+        }
+        match &event {
+            Event::Start(Tag::CodeBlock(_)) => in_code += 1,
+            Event::End(Tag::CodeBlock(_)) => in_code -= 1,
+            Event::Start(Tag::Link(..) | Tag::Image(..)) => in_link += 1,
+            Event::End(Tag::Link(..) | Tag::Image(..)) => in_link -= 1,
+            _ => {}
+        }
+        events.push(event);
+    }
+    if !run.is_empty() {
+        events.extend(on_text(run));
+    }
+    events
+}
+
+fn is_scriptable(url: &str) -> bool {
+    let scheme = url
+        .trim_start()
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_ascii_control())
+        .take_while(|c| *c != ':')
+        .collect::<String>()
+        .to_ascii_lowercase();
+    url.contains(':') && matches!(scheme.as_str(), "javascript" | "vbscript" | "data")
+}
+
+// Where each `@username` sits in plain text, the range covering the `@` too.
+fn scan(text: &str) -> Vec<(Range<usize>, &str)> {
+    let mut found = vec![];
+    let mut previous: Option<char> = None;
+    for (index, c) in text.char_indices() {
+        let after_word = previous.is_some_and(|p| is_username_char(p) || p == '@');
+        previous = Some(c);
+        if c != '@' || after_word {
+            continue;
+        }
+        let rest = &text[index + 1..];
+        let end = rest
+            .char_indices()
+            .find(|(_, c)| !is_username_char(*c))
+            .map_or(rest.len(), |(end, _)| end);
+        // A sentence may end right after the username.
+        let name = rest[..end].trim_end_matches(['.', '-']);
+        if !name.is_empty() {
+            found.push((index..index + 1 + name.len(), name));
+        }
+    }
+    found
+}
+
+fn is_username_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn html(text: &str, known: &[&str]) -> String {
+        let known = known.iter().map(|name| name.to_string()).collect();
+        to_html(text, &known, |name| format!("/profiles/{}", name))
+    }
+
+    #[test]
+    fn scan_finds_mentions_with_their_ranges() {
+        let text = "hi @alice and @bob.";
+        let found = scan(text);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0], (3..9, "alice"));
+        assert_eq!(found[1], (14..18, "bob"));
+        assert_eq!(&text[found[1].0.clone()], "@bob");
+    }
+
+    #[test]
+    fn scan_skips_emails_and_bare_signs() {
+        assert!(scan("mail alice@example.com").is_empty());
+        assert!(scan("@ @@ @.").is_empty());
+        assert_eq!(scan("(@carol_1)")[0].1, "carol_1");
+    }
+
+    #[test]
+    fn usernames_skip_code_and_link_text_without_duplicates() {
+        let text = "@alice `@bob` [@carol](/x) @alice\n\n```\n@dave\n```\n@erin";
+        assert_eq!(usernames(text), vec!["alice", "erin"]);
+    }
+
+    #[test]
+    fn usernames_are_capped() {
+        let text = (0..MENTIONS_PER_TEXT_LIMIT + 5)
+            .map(|index| format!("@user{}", index))
+            .collect::<Vec<_>>()
+            .join(" ");
+        assert_eq!(usernames(&text).len(), MENTIONS_PER_TEXT_LIMIT);
+    }
+
+    #[test]
+    fn to_html_links_known_users_only() {
+        let rendered = html("hi @alice and @mallory", &["alice"]);
+        assert!(rendered.contains("<a class=\"mention\" href=\"/profiles/alice\">@alice</a>"));
+        assert!(rendered.contains("and @mallory"));
+    }
+
+    #[test]
+    fn to_html_escapes_raw_html() {
+        let rendered = html("<script>alert(1)</script>\n\nhi <b onclick=\"x\">", &[]);
+        assert!(!rendered.contains("<script>"));
+        assert!(!rendered.contains("<b "));
+        assert!(rendered.contains("&lt;script&gt;"));
+    }
+
+    #[test]
+    fn to_html_drops_scriptable_urls() {
+        let rendered = html(
+            "[a](javascript:alert(1)) [b](JaVaScript:alert(1)) ![c](data:text/html,x)",
+            &[],
+        );
+        assert_eq!(
+            rendered,
+            "<p><a href=\"\">a</a> <a href=\"\">b</a> <img src=\"\" alt=\"c\" /></p>\n"
+        );
+        assert!(html("[ok](https://example.com)", &[]).contains("href=\"https://example.com\""));
+    }
+}
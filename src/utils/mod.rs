@@ -1,8 +1,19 @@
 pub mod api;
+pub mod archive;
+pub mod bayes;
+pub mod blob_store;
+pub mod cache;
 pub mod converter;
 pub mod date;
 pub mod db;
 pub mod di;
+pub mod etag;
+pub mod front_matter;
 pub mod hasher;
+pub mod locale;
+pub mod markdown;
+pub mod mention;
+pub mod seo;
+pub mod similarity;
 pub mod token;
 pub mod uuid;
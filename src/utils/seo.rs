@@ -0,0 +1,40 @@
+use serde_json::json;
+
+use crate::error::AppError;
+
+// Trims the value, treating a blank string as no value.
+pub fn non_blank(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_owned())
+        .filter(|value| !value.is_empty())
+}
+
+// URLs must be absolute http(s) URLs. With `allow_relative` a path on this
+// host such as an upload URL is accepted too.
+pub fn validate_url(field: &str, url: Option<&str>, allow_relative: bool) -> Result<(), AppError> {
+    let url = match url {
+        Some(url) => url,
+        None => return Ok(()),
+    };
+    let absolute = ["http://", "https://"].iter().any(|scheme| {
+        url.strip_prefix(scheme)
+            .is_some_and(|rest| !rest.is_empty() && !rest.starts_with('/'))
+    });
+    let relative = allow_relative && url.starts_with('/') && !url.starts_with("//");
+    if absolute || relative {
+        Ok(())
+    } else {
+        Err(AppError::UnprocessableEntity(json!({
+            "error": format!("{} must be an http or https URL.", field)
+        })))
+    }
+}
+
+// Resolves a path on this host against the public base URL.
+pub fn absolute_url(base_url: &str, url: &str) -> String {
+    if url.starts_with('/') {
+        format!("{}{}", base_url.trim_end_matches('/'), url)
+    } else {
+        url.to_owned()
+    }
+}